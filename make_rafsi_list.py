@@ -118,9 +118,40 @@ with open("js/docs/rafsi.js", "w") as opf:
     opf.write("]);\n")
 
 # rs
+RS_HEADER = '''//! Contains [`RAFSI_LIST`], the words that have rafsi and their rafsi, and
+//! the static [`RAFSI`], the same list as a map.
+#[cfg(feature = "std")]
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Big giant rafsi list, sorted by word.
+pub const RAFSI_LIST: &[(&str, &[&str])] = &[
+'''
+RS_FOOTER = '''
+const _: () = assert!(crate::data::is_sorted_by_first(RAFSI_LIST), "RAFSI_LIST must be sorted");
+
+/// Big giant rafsi list, as a map. Only available with the `std` feature; the
+/// functions below work without it.
+#[cfg(feature = "std")]
+pub static RAFSI: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
+    LazyLock::new(|| RAFSI_LIST.iter().map(|&(valsi, rafsi)| (valsi, rafsi.to_vec())).collect());
+
+/// The rafsi of `valsi`, or `None` if it isn't in [`RAFSI_LIST`].
+#[must_use]
+pub fn rafsi_of(valsi: &str) -> Option<&'static [&'static str]> {
+    RAFSI_LIST.binary_search_by_key(&valsi, |&(v, _)| v).ok().map(|i| RAFSI_LIST[i].1)
+}
+
+/// The word in [`RAFSI_LIST`] that has `rafsi` as one of its rafsi.
+#[must_use]
+pub fn selrafsi_of(rafsi: &str) -> Option<&'static str> {
+    RAFSI_LIST.iter().find_map(|&(valsi, rl)| rl.contains(&rafsi).then_some(valsi))
+}
+'''
+
 with open("rs/src/rafsi.rs", "w") as opf:
-    opf.write("//! Contains the static RAFSI, a map from words to their affixes.")
-    opf.write("\nuse std::{" + "collections::HashMap, sync::LazyLock};\n/// Big giant rafsi list.\npub static RAFSI: LazyLock<HashMap<&'static str, Vec<&'static str>>> = LazyLock::new(|| {\n    HashMap::from([\n")
-    for selrafsi, rafsi in rafsi_list.items():
-        opf.write('        ("' + selrafsi + '", vec![' + ('"' if len(rafsi) else "") + '", "'.join(rafsi) + ('"' if len(rafsi) else "") + "]" + "),\n")
-    opf.write("    ])\n});\n")
+    opf.write(RS_HEADER)
+    # sorted so that it can be binary searched
+    for selrafsi, rafsi in sorted(rafsi_list.items()):
+        opf.write('    ("' + selrafsi + '", &[' + ('"' if len(rafsi) else "") + '", "'.join(rafsi) + ('"' if len(rafsi) else "") + "]" + "),\n")
+    opf.write("];\n")
+    opf.write(RS_FOOTER)
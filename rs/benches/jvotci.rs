@@ -0,0 +1,110 @@
+//! Benchmarks over the real words in `tests/jvs_words.json` and the tanru in
+//! `tests/jvozba_test_list.tsv`, plus long words to show how things scale.
+//! Run with `cargo bench`; `cargo bench -- scaling` runs just one group.
+
+use std::{fs, hint::black_box, time::Duration};
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use latkerlo_jvotci::{
+    Settings, analyze_brivla, get_lujvo, get_veljvo, jvozba::get_lujvo_from_list, katna::jvokaha2,
+    score_lujvo, tools::check_zihevla_or_rafsi,
+};
+
+fn read(file: &str) -> String {
+    fs::read_to_string(format!("{}/../tests/{file}", env!("CARGO_MANIFEST_DIR"))).unwrap()
+}
+
+/// Each list in `jvs_words.json` by its name (`GISMU`, `LUJVO`, `ZIhEVLA`, and
+/// `OTHER`).
+fn dictionary() -> Vec<(String, Vec<String>)> {
+    let all_words: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&read("jvs_words.json")).unwrap();
+    all_words
+        .into_iter()
+        .map(|(b_type, words)| (b_type, serde_json::from_value(words).unwrap()))
+        .collect()
+}
+
+/// The first column of `jvozba_test_list.tsv`.
+fn tanru() -> Vec<String> {
+    read("jvozba_test_list.tsv")
+        .lines()
+        .filter_map(|l| l.split('\t').next())
+        .filter(|t| !t.is_empty() && !t.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+fn bench_dictionary(c: &mut Criterion) {
+    let settings = Settings::default();
+    let dictionary = dictionary();
+    let mut group = c.benchmark_group("dictionary");
+    group.sample_size(10).measurement_time(Duration::from_secs(10));
+    for (b_type, words) in &dictionary {
+        group.throughput(Throughput::Elements(words.len() as u64));
+        group.bench_with_input(BenchmarkId::new("analyze_brivla", b_type), words, |b, words| {
+            b.iter(|| {
+                words.iter().filter(|w| analyze_brivla(black_box(w), &settings).is_ok()).count()
+            });
+        });
+    }
+    let lujvo = &dictionary.iter().find(|(b_type, _)| b_type == "LUJVO").unwrap().1;
+    group.throughput(Throughput::Elements(lujvo.len() as u64));
+    group.bench_function("get_veljvo", |b| {
+        b.iter(|| lujvo.iter().filter(|w| get_veljvo(black_box(w), &settings).is_ok()).count());
+    });
+    group.bench_function("score_lujvo", |b| {
+        b.iter(|| lujvo.iter().filter(|w| score_lujvo(black_box(w), &settings).is_ok()).count());
+    });
+    let tanru = tanru();
+    group.throughput(Throughput::Elements(tanru.len() as u64));
+    group.bench_function("get_lujvo", |b| {
+        b.iter(|| tanru.iter().filter(|t| get_lujvo(black_box(t), &settings).is_ok()).count());
+    });
+    group.finish();
+}
+
+fn bench_scaling(c: &mut Criterion) {
+    let settings = Settings::default();
+    let mut group = c.benchmark_group("scaling");
+    group.sample_size(20);
+    for n in [2, 5, 10, 20, 50, 100] {
+        let bloti = vec!["bloti".to_string(); n];
+        group.bench_with_input(
+            BenchmarkId::new("get_lujvo_from_list/bloti", n),
+            &bloti,
+            |b, bloti| {
+                b.iter(|| get_lujvo_from_list(black_box(bloti), &settings));
+            },
+        );
+        let tanru = bloti.join(" ");
+        group.bench_with_input(BenchmarkId::new("get_lujvo/bloti", n), &tanru, |b, tanru| {
+            b.iter(|| get_lujvo(black_box(tanru), &settings));
+        });
+        let lujvo = format!("{}bloti", "blo".repeat(n - 1));
+        group.bench_with_input(BenchmarkId::new("get_veljvo/bloti", n), &lujvo, |b, lujvo| {
+            b.iter(|| get_veljvo(black_box(lujvo), &settings));
+        });
+        group.bench_with_input(BenchmarkId::new("jvokaha2/bloti", n), &lujvo, |b, lujvo| {
+            b.iter(|| jvokaha2(black_box(lujvo), &settings));
+        });
+        group.bench_with_input(BenchmarkId::new("analyze_brivla/bloti", n), &lujvo, |b, lujvo| {
+            b.iter(|| analyze_brivla(black_box(lujvo), &settings));
+        });
+        let zihevla = format!("tci{}", "la".repeat(n));
+        group.bench_with_input(BenchmarkId::new("analyze_brivla/tcila", n), &zihevla, |b, z| {
+            b.iter(|| analyze_brivla(black_box(z), &settings));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("check_zihevla_or_rafsi/tcila", n),
+            &zihevla,
+            |b, z| {
+                b.iter(|| check_zihevla_or_rafsi(black_box(z), &settings, true));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dictionary, bench_scaling);
+criterion_main!(benches);
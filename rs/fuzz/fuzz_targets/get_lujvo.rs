@@ -0,0 +1,12 @@
+#![no_main]
+
+use latkerlo_jvotci::{get_lujvo, tarmi::SETTINGS_ITERATOR};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, &str)| {
+    let (i, tanru) = input;
+    let settings = SETTINGS_ITERATOR[i as usize % SETTINGS_ITERATOR.len()];
+    // which error comes first depends on the order of the words, so only
+    // check that it doesn't panic
+    let _ = get_lujvo(tanru, &settings);
+});
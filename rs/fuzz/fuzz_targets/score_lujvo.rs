@@ -0,0 +1,16 @@
+#![no_main]
+
+use latkerlo_jvotci::{
+    Jvonunfli::NonLojbanCharacterError, score_lujvo, normalize,
+    tarmi::{SETTINGS_ITERATOR, check_lojban_characters},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, &str)| {
+    let (i, word) = input;
+    let settings = SETTINGS_ITERATOR[i as usize % SETTINGS_ITERATOR.len()];
+    let res = score_lujvo(word, &settings);
+    if check_lojban_characters(&normalize(word)).is_err() {
+        assert!(matches!(res, Err(NonLojbanCharacterError(_))), "{word:?}: {res:?}");
+    }
+});
@@ -0,0 +1,249 @@
+//! A language server for Lojban text, built on [`lint`]. It shows diagnostics
+//! for invalid brivla, malformed lujvo, and lujvo with a better form, offers
+//! to replace the latter two, and describes words on hover.
+//!
+//! Settings are given as flags when starting the server (e.g.
+//! `jvotci-lsp -A`), or as `{ "flags": "A" }` in the initialization options or
+//! a configuration change.
+
+use std::{collections::HashMap, env, error::Error};
+
+use latkerlo_jvotci::{
+    Settings,
+    lint::{LintKind, hover, lint, tokenize},
+};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams, Hover,
+    HoverContents, HoverParams, HoverProviderCapability, InitializeParams, MarkupContent,
+    MarkupKind, NumberOrString, Position, PublishDiagnosticsParams, Range, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Uri, WorkspaceEdit,
+    notification::{
+        DidChangeConfiguration, DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as _, PublishDiagnostics,
+    },
+    request::{CodeActionRequest, HoverRequest, Request as _},
+};
+use serde_json::Value;
+
+type Res<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Converts a byte offset to a position in UTF-16 code units, which is what
+/// LSP uses by default.
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// Converts a position back to a byte offset, clamping it to the text.
+fn offset(text: &str, position: Position) -> usize {
+    let line_start = match position.line as usize {
+        0 => 0,
+        line => text.match_indices('\n').nth(line - 1).map_or(text.len(), |(i, _)| i + 1),
+    };
+    let mut units = 0;
+    for (i, c) in text[line_start..].char_indices() {
+        if units >= position.character as usize || c == '\n' {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+fn range(text: &str, start: usize, end: usize) -> Range {
+    Range { start: position(text, start), end: position(text, end) }
+}
+
+/// Reads settings from `{ "flags": "..." }`.
+fn settings_from(value: Option<&Value>) -> Option<Settings> {
+    let flags = value?.get("flags")?.as_str()?;
+    flags.strip_prefix('-').unwrap_or(flags).parse().ok()
+}
+
+fn diagnostics(text: &str, settings: &Settings) -> Vec<Diagnostic> {
+    lint(text, settings)
+        .into_iter()
+        .map(|l| {
+            let (severity, code) = match l.kind {
+                LintKind::InvalidBrivla => (DiagnosticSeverity::ERROR, "invalid-brivla"),
+                LintKind::MalformedLujvo => (DiagnosticSeverity::WARNING, "malformed-lujvo"),
+                LintKind::SuboptimalLujvo => (DiagnosticSeverity::INFORMATION, "suboptimal-lujvo"),
+            };
+            Diagnostic {
+                range: range(text, l.token.start, l.token.end),
+                severity: Some(severity),
+                code: Some(NumberOrString::String(code.to_string())),
+                source: Some("jvotci".to_string()),
+                message: l.message,
+                ..Diagnostic::default()
+            }
+        })
+        .collect()
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Uri, String>,
+    settings: Settings,
+}
+
+impl Server {
+    fn publish(&self, uri: &Uri) -> Res<()> {
+        let text = self.documents.get(uri).map_or("", String::as_str);
+        let params = PublishDiagnosticsParams {
+            uri: uri.clone(),
+            diagnostics: diagnostics(text, &self.settings),
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(notification.into())?;
+        Ok(())
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let doc = params.text_document_position_params;
+        let text = self.documents.get(&doc.text_document.uri)?;
+        let at = offset(text, doc.position);
+        let token = tokenize(text).into_iter().find(|t| t.start <= at && at <= t.end)?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: hover(&token.text, &self.settings)?,
+            }),
+            range: Some(range(text, token.start, token.end)),
+        })
+    }
+
+    fn code_actions(&self, params: CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = params.text_document.uri;
+        let Some(text) = self.documents.get(&uri) else {
+            return vec![];
+        };
+        let (start, end) = (offset(text, params.range.start), offset(text, params.range.end));
+        lint(text, &self.settings)
+            .into_iter()
+            .filter(|l| l.token.start <= end && start <= l.token.end)
+            .filter_map(|l| {
+                let new_text = l.replacement?;
+                let edit = TextEdit { range: range(text, l.token.start, l.token.end), new_text };
+                Some(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("replace with {{{}}}", edit.new_text),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                        ..WorkspaceEdit::default()
+                    }),
+                    is_preferred: Some(true),
+                    ..CodeAction::default()
+                }))
+            })
+            .collect()
+    }
+
+    fn handle_request(&self, request: Request) -> Res<()> {
+        let id = request.id;
+        let invalid = |e: serde_json::Error| {
+            Response::new_err(id.clone(), ErrorCode::InvalidParams as i32, e.to_string())
+        };
+        let response = match request.method.as_str() {
+            HoverRequest::METHOD => serde_json::from_value(request.params)
+                .map_or_else(invalid, |p| Response::new_ok(id.clone(), self.hover(p))),
+            CodeActionRequest::METHOD => serde_json::from_value(request.params)
+                .map_or_else(invalid, |p| Response::new_ok(id.clone(), self.code_actions(p))),
+            method => Response::new_err(
+                id.clone(),
+                ErrorCode::MethodNotFound as i32,
+                format!("no method {{{method}}}"),
+            ),
+        };
+        self.connection.sender.send(response.into())?;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Res<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let doc = params.text_document;
+                self.documents.insert(doc.uri.clone(), doc.text);
+                self.publish(&doc.uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                // with full syncing, the last change is the whole document
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    let uri = params.text_document.uri;
+                    self.documents.insert(uri.clone(), change.text);
+                    self.publish(&uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                self.publish(&params.text_document.uri)?;
+            }
+            DidChangeConfiguration::METHOD => {
+                let params: DidChangeConfigurationParams =
+                    serde_json::from_value(notification.params)?;
+                if let Some(settings) = settings_from(Some(&params.settings)) {
+                    self.settings = settings;
+                    for uri in self.documents.keys() {
+                        self.publish(uri)?;
+                    }
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Res<()> {
+    let flags = env::args().skip(1).filter_map(|a| a.strip_prefix('-').map(String::from));
+    let Ok(settings) = flags.collect::<String>().parse::<Settings>() else {
+        eprintln!("invalid flags, see jvotci -h");
+        std::process::exit(1);
+    };
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    let init: InitializeParams =
+        serde_json::from_value(connection.initialize(serde_json::to_value(capabilities)?)?)?;
+    let settings = settings_from(init.initialization_options.as_ref()).unwrap_or(settings);
+    let mut server = Server { connection, documents: HashMap::new(), settings };
+    while let Ok(message) = server.connection.receiver.recv() {
+        match message {
+            Message::Request(request) => {
+                if server.connection.handle_shutdown(&request)? {
+                    break;
+                }
+                server.handle_request(request)?;
+            }
+            Message::Notification(notification) => {
+                // a bad notification shouldn't stop the server
+                if let Err(e) = server.handle_notification(notification) {
+                    eprintln!("jvotci-lsp: {e}");
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+    // the writer thread stops once the connection is dropped
+    drop(server);
+    io_threads.join()?;
+    Ok(())
+}
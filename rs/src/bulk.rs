@@ -0,0 +1,98 @@
+//! Running a whole list of words at once, such as a dictionary. With the
+//! `rayon` feature the words are spread over a thread pool; either way the
+//! results come back in the same order as the input.
+//! ```
+//! use latkerlo_jvotci::{Settings, bulk::get_veljvo_many};
+//!
+//! let veljvo = get_veljvo_many(&["blazda", "latkerlo", "coi"], &Settings::default(), None);
+//! assert_eq!(veljvo[1].as_deref(), Ok(&["mlatu", "kerlo"].map(String::from)[..]));
+//! assert!(veljvo[2].is_err());
+//! ```
+//! Passing a [`Cache`] remembers and reuses results across the whole list,
+//! and on every thread:
+//! ```
+//! use latkerlo_jvotci::{Settings, bulk::analyze_many, cache::Cache};
+//!
+//! let mut cache = Cache::new(10_000);
+//! let words = ["blazda", "zdablanu", "blazda"];
+//! let res = analyze_many(&words, &Settings::default(), Some(&mut cache));
+//! assert!(res.iter().all(Result::is_ok));
+//! assert!(!cache.is_empty());
+//! ```
+//! [`with_trace`](crate::trace::with_trace) only sees calls made on its own
+//! thread, so it doesn't see the work done here when `rayon` is on.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    cache::{Cache, with_cache},
+    exceptions::Jvonunfli,
+    jvozba::get_lujvo,
+    katna::get_veljvo,
+    tarmi::{BrivlaType, Settings},
+    tools::analyze_brivla,
+};
+
+/// Runs [`analyze_brivla`] on each word.
+pub fn analyze_many<S: AsRef<str> + Sync>(
+    words: &[S],
+    settings: &Settings,
+    cache: Option<&mut Cache>,
+) -> Vec<Result<(BrivlaType, Vec<String>), Jvonunfli>> {
+    map_in_order(words, cache, |word| analyze_brivla(word.as_ref(), settings))
+}
+
+/// Runs [`get_lujvo`] on each tanru.
+pub fn get_lujvo_many<S: AsRef<str> + Sync>(
+    tanru: &[S],
+    settings: &Settings,
+    cache: Option<&mut Cache>,
+) -> Vec<Result<String, Jvonunfli>> {
+    map_in_order(tanru, cache, |tanru| get_lujvo(tanru.as_ref(), settings))
+}
+
+/// Runs [`get_veljvo`] on each lujvo.
+pub fn get_veljvo_many<S: AsRef<str> + Sync>(
+    lujvo: &[S],
+    settings: &Settings,
+    cache: Option<&mut Cache>,
+) -> Vec<Result<Vec<String>, Jvonunfli>> {
+    map_in_order(lujvo, cache, |lujvo| get_veljvo(lujvo.as_ref(), settings))
+}
+
+// the bounds are the same with or without rayon so that turning it on can't
+// break anything
+fn map_in_order<S: Sync, T: Send>(
+    items: &[S],
+    cache: Option<&mut Cache>,
+    f: impl Fn(&S) -> T + Sync + Send,
+) -> Vec<T> {
+    let Some(cache) = cache else {
+        #[cfg(feature = "rayon")]
+        return items.par_iter().map(f).collect();
+        #[cfg(not(feature = "rayon"))]
+        return items.iter().map(f).collect();
+    };
+    #[cfg(feature = "rayon")]
+    {
+        // each thread works on its own copy, and what they learn is merged
+        // back in afterwards
+        let size = items.len().div_ceil(rayon::current_num_threads()).max(1);
+        let base = &*cache;
+        let (forks, res): (Vec<_>, Vec<_>) = items
+            .par_chunks(size)
+            .map(|chunk| {
+                let mut fork = base.fork();
+                let res = with_cache(&mut fork, || chunk.iter().map(&f).collect::<Vec<_>>());
+                (fork, res)
+            })
+            .unzip();
+        for fork in forks {
+            cache.absorb(fork);
+        }
+        res.into_iter().flatten().collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    with_cache(cache, || items.iter().map(f).collect())
+}
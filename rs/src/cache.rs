@@ -0,0 +1,262 @@
+//! An opt-in, bounded memo of [`analyze_brivla`] and [`jvokaha`] results, for
+//! processing many words at once.
+//!
+//! Looking for tosmabru and slinku'i analyzes the same pieces of a word over
+//! and over, and the words of a dictionary share a lot of those pieces.
+//! Nothing is remembered unless the work is run inside [`with_cache`], and the
+//! same [`Cache`] can be passed in again later to keep what it learned.
+//! ```
+//! use latkerlo_jvotci::{
+//!     Settings, analyze_brivla,
+//!     cache::{Cache, with_cache},
+//! };
+//!
+//! let mut cache = Cache::new(10_000);
+//! let settings = Settings::default();
+//! for word in ["blazda", "zdablanu", "blazda"] {
+//!     let res = with_cache(&mut cache, || analyze_brivla(word, &settings));
+//!     assert!(res.is_ok());
+//! }
+//! assert!(cache.hits() > 0);
+//! ```
+//! Results are kept per word and per [`Settings`], so one cache can be used
+//! with any settings. Nothing is looked up or stored while a
+//! [`trace`](mod@crate::trace) is being recorded, since the trace would be
+//! missing the steps.
+//!
+//! [`analyze_brivla`]: crate::tools::analyze_brivla
+//! [`jvokaha`]: crate::katna::jvokaha
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    mem,
+};
+
+use crate::{
+    exceptions::Jvonunfli,
+    katna,
+    tarmi::{BrivlaType, Settings},
+    tools,
+    trace::is_tracing,
+};
+
+type Key = (String, Settings);
+type Analysis = Result<(BrivlaType, Vec<String>), Jvonunfli>;
+type Decomposition = Result<Vec<String>, Jvonunfli>;
+
+thread_local! {
+    static CACHE: RefCell<Option<Cache>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Analysis,
+    Decomposition,
+}
+
+#[derive(Debug, Clone)]
+/// Remembered results, up to a fixed number. Once full, the oldest result is
+/// forgotten to make room for each new one.
+pub struct Cache {
+    capacity: usize,
+    analyses: HashMap<Key, Analysis>,
+    decompositions: HashMap<Key, Decomposition>,
+    order: VecDeque<(Kind, Key)>,
+    hits: usize,
+    misses: usize,
+}
+
+impl Default for Cache {
+    /// Makes an empty cache that holds at most 100 000 results, which is
+    /// enough for a dictionary.
+    fn default() -> Self { Self::new(100_000) }
+}
+
+impl Cache {
+    /// Makes an empty cache that holds at most `capacity` results.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            analyses: HashMap::new(),
+            decompositions: HashMap::new(),
+            order: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The most results this can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize { self.capacity }
+
+    /// How many results are held.
+    #[must_use]
+    pub fn len(&self) -> usize { self.order.len() }
+
+    /// Returns `true` if no results are held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.order.is_empty() }
+
+    /// How many lookups found a result.
+    #[must_use]
+    pub fn hits(&self) -> usize { self.hits }
+
+    /// How many lookups didn't find a result.
+    #[must_use]
+    pub fn misses(&self) -> usize { self.misses }
+
+    /// Forgets every result and resets the counts.
+    pub fn clear(&mut self) { *self = Self::new(self.capacity); }
+
+    /// Runs [`analyze_brivla`](tools::analyze_brivla) using this cache.
+    pub fn analyze_brivla(&mut self, valsi: &str, settings: &Settings) -> Analysis {
+        with_cache(self, || tools::analyze_brivla(valsi, settings))
+    }
+
+    /// Runs [`jvokaha`](katna::jvokaha) using this cache.
+    pub fn jvokaha(&mut self, lujvo: &str, settings: &Settings) -> Decomposition {
+        with_cache(self, || katna::jvokaha(lujvo, settings))
+    }
+
+    /// Runs [`get_veljvo`](katna::get_veljvo) using this cache.
+    pub fn get_veljvo(&mut self, lujvo: &str, settings: &Settings) -> Decomposition {
+        with_cache(self, || katna::get_veljvo(lujvo, settings))
+    }
+
+    /// A copy to be used on another thread, with its counts at zero.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn fork(&self) -> Self { Self { hits: 0, misses: 0, ..self.clone() } }
+
+    /// Takes in the counts of a [`fork`](Self::fork) and the results it has
+    /// that this doesn't.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn absorb(&mut self, mut other: Self) {
+        self.hits += other.hits;
+        self.misses += other.misses;
+        for (kind, key) in other.order {
+            match kind {
+                Kind::Analysis => {
+                    if let Some(res) = other.analyses.remove(&key) {
+                        self.remember(kind, key, res, |c| &mut c.analyses);
+                    }
+                }
+                Kind::Decomposition => {
+                    if let Some(res) = other.decompositions.remove(&key) {
+                        self.remember(kind, key, res, |c| &mut c.decompositions);
+                    }
+                }
+            }
+        }
+    }
+
+    fn remember<T>(
+        &mut self,
+        kind: Kind,
+        key: Key,
+        res: T,
+        map: fn(&mut Self) -> &mut HashMap<Key, T>,
+    ) {
+        if self.capacity == 0 || map(self).contains_key(&key) {
+            return;
+        }
+        self.make_room();
+        self.order.push_back((kind, key.clone()));
+        map(self).insert(key, res);
+    }
+
+    fn make_room(&mut self) {
+        while self.order.len() >= self.capacity {
+            match self.order.pop_front() {
+                Some((Kind::Analysis, key)) => {
+                    self.analyses.remove(&key);
+                }
+                Some((Kind::Decomposition, key)) => {
+                    self.decompositions.remove(&key);
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+/// Runs `f`, remembering the results of [`analyze_brivla`] and [`jvokaha`]
+/// calls made on this thread in `cache` and reusing the ones already there.
+/// Caches can be nested; only the innermost one is used. The [`Cache`]
+/// methods and the [`bulk`](crate::bulk) functions take a cache directly
+/// instead.
+///
+/// [`analyze_brivla`]: crate::tools::analyze_brivla
+/// [`jvokaha`]: crate::katna::jvokaha
+pub fn with_cache<T>(cache: &mut Cache, f: impl FnOnce() -> T) -> T {
+    let outer = CACHE.with_borrow_mut(|c| c.replace(mem::replace(cache, Cache::new(0))));
+    let _restore = Restore { cache, outer };
+    f()
+}
+
+/// Gives the cache back to the caller of [`with_cache`] and puts back the
+/// outer one, even if `f` panics.
+struct Restore<'a> {
+    cache: &'a mut Cache,
+    outer: Option<Cache>,
+}
+
+impl Drop for Restore<'_> {
+    fn drop(&mut self) {
+        let outer = self.outer.take();
+        if let Some(inner) = CACHE.with_borrow_mut(|c| mem::replace(c, outer)) {
+            *self.cache = inner;
+        }
+    }
+}
+
+/// Looks up an [`analyze_brivla`](crate::tools::analyze_brivla) result, or
+/// runs `f` and remembers what it returns.
+pub(crate) fn analysis(valsi: &str, settings: &Settings, f: impl FnOnce() -> Analysis) -> Analysis {
+    memo(Kind::Analysis, valsi, settings, |c| &mut c.analyses, f)
+}
+
+/// Looks up a [`jvokaha`](crate::katna::jvokaha) result, or runs `f` and
+/// remembers what it returns.
+pub(crate) fn decomposition(
+    lujvo: &str,
+    settings: &Settings,
+    f: impl FnOnce() -> Decomposition,
+) -> Decomposition {
+    memo(Kind::Decomposition, lujvo, settings, |c| &mut c.decompositions, f)
+}
+
+fn memo<T: Clone>(
+    kind: Kind,
+    word: &str,
+    settings: &Settings,
+    map: fn(&mut Cache) -> &mut HashMap<Key, T>,
+    f: impl FnOnce() -> T,
+) -> T {
+    if is_tracing() || CACHE.with_borrow(Option::is_none) {
+        return f();
+    }
+    let key = (word.to_string(), *settings);
+    let found = CACHE.with_borrow_mut(|c| {
+        let c = c.as_mut()?;
+        let found = map(c).get(&key).cloned();
+        if found.is_some() {
+            c.hits += 1;
+        } else {
+            c.misses += 1;
+        }
+        found
+    });
+    if let Some(res) = found {
+        return res;
+    }
+    // the borrow is released while `f` runs, since it can come back here
+    let res = f();
+    CACHE.with_borrow_mut(|c| {
+        if let Some(c) = c {
+            c.remember(kind, key, res.clone(), map);
+        }
+    });
+    res
+}
@@ -32,6 +32,11 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         " (",
         BOLD,
         ITALIC,
+        "command",
+        RESET,
+        ") (",
+        BOLD,
+        ITALIC,
         "flags",
         RESET,
         ") (",
@@ -41,6 +46,56 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         RESET,
         "...)\n",
         CYAN,
+        "commands:",
+        RESET,
+        "\n    ",
+        BOLD,
+        "zba",
+        RESET,
+        "       make the best lujvo from a tanru\n    ",
+        BOLD,
+        "katna",
+        RESET,
+        "     analyze a word and find the best lujvo with the same tanru\n    ",
+        BOLD,
+        "score",
+        RESET,
+        "     score each lujvo\n    ",
+        BOLD,
+        "rafsi",
+        RESET,
+        "     find the rafsi of each word, or the word each rafsi is of\n    ",
+        BOLD,
+        "check",
+        RESET,
+        "     check whether each word is a valid brivla\n    ",
+        BOLD,
+        "settings",
+        RESET,
+        "  show the settings the flags select\n    ",
+        BOLD,
+        "matrix",
+        RESET,
+        "    try the input with every combination of settings and group the results\n    ",
+        BOLD,
+        "minimal",
+        RESET,
+        "   find the fewest changes to the default settings that make each word a lujvo\n    ",
+        BOLD,
+        "serve",
+        RESET,
+        "     answer JSON requests over HTTP on ",
+        BOLD,
+        "--port",
+        RESET,
+        " (default 8080), if built with the server feature\n    ",
+        BOLD,
+        "rpc",
+        RESET,
+        "       answer JSON-RPC requests, one per line of stdin\n  without a command, a single \
+         word is analyzed (with its rafsi shown) and several are made into a lujvo; a command \
+         that takes input but has none is analyzed as a word\n",
+        CYAN,
         "flags:",
         RESET,
         " (",
@@ -51,7 +106,31 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-h",
         RESET,
-        "  print this help text\n  ",
+        "  print this help text\n    ",
+        BOLD,
+        "--json",
+        RESET,
+        "  print one line of JSON per input (or ",
+        BOLD,
+        "-o json",
+        RESET,
+        "; see the readme for the format)\n    ",
+        BOLD,
+        "-o",
+        RESET,
+        " plain|ansi|html|markdown  choose how text output looks (the default, ",
+        BOLD,
+        "text",
+        RESET,
+        ", is ansi in a terminal unless NO_COLOR is set)\n    ",
+        BOLD,
+        "--batch",
+        RESET,
+        " [file]  process one input per line of a file or stdin\n    ",
+        BOLD,
+        "--explain",
+        RESET,
+        "  show the steps taken to analyze each word\n  ",
         PINK,
         "toggles:",
         RESET,
@@ -59,18 +138,34 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-c",
         RESET,
+        ", ",
+        BOLD,
+        "--cmevla",
+        RESET,
         "  generate a cmevla\n    ",
         BOLD,
         "-r",
         RESET,
+        ", ",
+        BOLD,
+        "--exp-rafsi",
+        RESET,
         "  allow any cmavo to be a rafsi\n    ",
         BOLD,
         "-g",
         RESET,
+        ", ",
+        BOLD,
+        "--glides",
+        RESET,
         "  treat glides as consonants\n    ",
         BOLD,
         "-z",
         RESET,
+        ", ",
+        BOLD,
+        "--allow-mz",
+        RESET,
         "  allow {mz}\n  ",
         PINK,
         "hyphens:",
@@ -79,6 +174,10 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-S",
         RESET,
+        ", ",
+        BOLD,
+        "--y-hyphens=standard",
+        RESET,
         GREEN,
         "*",
         RESET,
@@ -86,10 +185,18 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-A",
         RESET,
+        ", ",
+        BOLD,
+        "--y-hyphens=allow-y",
+        RESET,
         "  allow {'y} etc hyphens in place of {r} or {n}\n    ",
         BOLD,
         "-F",
         RESET,
+        ", ",
+        BOLD,
+        "--y-hyphens=force-y",
+        RESET,
         "  force {'y} and treat words with {r} or {n} as zi'evla\n  hyphens that are present when \
          they don't need to be are always permitted\n  ",
         PINK,
@@ -99,6 +206,10 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-C",
         RESET,
+        ", ",
+        BOLD,
+        "--consonants=cluster",
+        RESET,
         GREEN,
         "*",
         RESET,
@@ -106,18 +217,26 @@ pub static CLI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "-2",
         RESET,
+        ", ",
+        BOLD,
+        "--consonants=2",
+        RESET,
         "  minimum 2 consonants\n    ",
         BOLD,
         "-1",
         RESET,
-        "  minimum 1 consonant\nmultiple flags ",
-        ITALIC,
-        "must",
+        ", ",
+        BOLD,
+        "--consonants=1",
         RESET,
-        " be grouped together if present, and they can be set in any order, e.g. ",
+        "  minimum 1 consonant\nshort flags can be grouped together in any order, e.g. ",
         BOLD,
         "-gc1rA",
         RESET,
+        "\nlong options take precedence over them, and toggles can be turned off with e.g. ",
+        BOLD,
+        "--glides=false",
+        RESET,
         "\n",
         CYAN,
         "input:",
@@ -157,7 +276,11 @@ pub static TUI_INSTRUCTIONS: LazyLock<String> = LazyLock::new(|| {
         BOLD,
         "/q",
         RESET,
-        "  quit\n  ",
+        "  quit\n    ",
+        BOLD,
+        "/settings",
+        RESET,
+        "  show the current settings\n  ",
         PINK,
         "toggles:",
         RESET,
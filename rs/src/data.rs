@@ -1,72 +1,304 @@
 //! Various lists of things like consonant clusters. The rafsi list is stored in
 //! [`rafsi`][`crate::rafsi`] instead.
+//!
+//! Each list is a sorted [`List`] that works without `std`. The consonant
+//! clusters are also made into a [`PairTable`] or [`TripleTable`] at compile
+//! time, which is what the rest of the crate looks them up in. The `HashSet`
+//! statics with the old names are kept for code that uses them, and are only
+//! built if used.
 
+#[cfg(feature = "std")]
 use std::{collections::HashSet, sync::LazyLock};
 
+/// A sorted list of strings, searched by bisection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct List(&'static [&'static str]);
+
+impl List {
+    /// Wraps `items`, which must be sorted; this is checked when the list is
+    /// built, which for the lists here is at compile time.
+    #[must_use]
+    pub const fn new(items: &'static [&'static str]) -> Self {
+        let mut i = 1;
+        while i < items.len() {
+            assert!(precedes(items[i - 1], items[i]), "list must be sorted");
+            i += 1;
+        }
+        Self(items)
+    }
+
+    /// Returns `true` if `s` is in the list.
+    #[must_use]
+    pub fn contains(&self, s: &str) -> bool { self.0.binary_search(&s).is_ok() }
+
+    /// The items, in order.
+    pub fn iter(&self) -> core::slice::Iter<'static, &'static str> { self.0.iter() }
+
+    #[must_use]
+    pub const fn len(&self) -> usize { self.0.len() }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.0.is_empty() }
+}
+
+/// Returns `true` if `a` comes strictly before `b`, comparing bytes.
+pub(crate) const fn precedes(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// Returns `true` if the first items of `items` are sorted, like
+/// [`List::new`] checks.
+pub(crate) const fn is_sorted_by_first<T>(items: &[(&str, T)]) -> bool {
+    let mut i = 1;
+    while i < items.len() {
+        if !precedes(items[i - 1].0, items[i].0) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The consonants, in the order the rows and columns of a [`PairTable`] or
+/// [`TripleTable`] are in.
+pub const CONSONANTS: &str = "bcdfgjklmnprstvxz";
+
+/// Each ASCII character's position in [`CONSONANTS`], or `NOT_CONSONANT`.
+const CONSONANT_INDICES: [u8; 128] = {
+    let mut indices = [NOT_CONSONANT; 128];
+    let mut i = 0;
+    while i < CONSONANTS.len() {
+        indices[CONSONANTS.as_bytes()[i] as usize] = i as u8;
+        i += 1;
+    }
+    indices
+};
+const NOT_CONSONANT: u8 = u8::MAX;
+
+/// The position of `c` in [`CONSONANTS`], or `None` if it isn't a consonant.
+#[must_use]
+pub const fn consonant_index(c: char) -> Option<usize> {
+    if !c.is_ascii() {
+        return None;
+    }
+    match CONSONANT_INDICES[c as usize] {
+        NOT_CONSONANT => None,
+        i => Some(i as usize),
+    }
+}
+
+/// The positions of the letters of `s` in [`CONSONANTS`], if it's exactly `N`
+/// consonants long.
+const fn consonant_indices<const N: usize>(s: &str) -> Option<[usize; N]> {
+    let s = s.as_bytes();
+    if s.len() != N {
+        return None;
+    }
+    let mut res = [0; N];
+    let mut i = 0;
+    while i < N {
+        match consonant_index(s[i] as char) {
+            Some(c) => res[i] = c,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(res)
+}
+
+/// A set of consonant pairs, as one row of bits per first consonant, so that
+/// looking one up doesn't need a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairTable([u32; 17]);
+
+impl PairTable {
+    /// Makes a table of the pairs in `list`, which must all be two consonants.
+    #[must_use]
+    pub const fn new(list: List) -> Self {
+        let mut rows = [0; 17];
+        let mut i = 0;
+        while i < list.0.len() {
+            let Some([a, b]) = consonant_indices(list.0[i]) else {
+                panic!("every item must be two consonants");
+            };
+            rows[a] |= 1 << b;
+            i += 1;
+        }
+        Self(rows)
+    }
+
+    /// Returns `true` if *`a``b`* is in the table.
+    #[must_use]
+    pub const fn contains_pair(&self, a: char, b: char) -> bool {
+        match (consonant_index(a), consonant_index(b)) {
+            (Some(a), Some(b)) => self.0[a] & 1 << b != 0,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `s` is a pair in the table.
+    #[must_use]
+    pub const fn contains(&self, s: &str) -> bool {
+        match consonant_indices(s) {
+            Some([a, b]) => self.0[a] & 1 << b != 0,
+            None => false,
+        }
+    }
+}
+
+/// A set of consonant triples, as one row of bits per first two consonants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TripleTable([[u32; 17]; 17]);
+
+impl TripleTable {
+    /// Makes a table of the triples in `list`, which must all be three
+    /// consonants.
+    #[must_use]
+    pub const fn new(list: List) -> Self {
+        let mut rows = [[0; 17]; 17];
+        let mut i = 0;
+        while i < list.0.len() {
+            let Some([a, b, c]) = consonant_indices(list.0[i]) else {
+                panic!("every item must be three consonants");
+            };
+            rows[a][b] |= 1 << c;
+            i += 1;
+        }
+        Self(rows)
+    }
+
+    /// Returns `true` if *`a``b``c`* is in the table.
+    #[must_use]
+    pub const fn contains_triple(&self, a: char, b: char, c: char) -> bool {
+        match (consonant_index(a), consonant_index(b), consonant_index(c)) {
+            (Some(a), Some(b), Some(c)) => self.0[a][b] & 1 << c != 0,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `s` is a triple in the table.
+    #[must_use]
+    pub const fn contains(&self, s: &str) -> bool {
+        match consonant_indices(s) {
+            Some([a, b, c]) => self.0[a][b] & 1 << c != 0,
+            None => false,
+        }
+    }
+}
+
+/// Builds a `HashSet` view of a [`List`], for the `std` statics.
+#[cfg(feature = "std")]
+macro_rules! set_of {
+    ($list:expr) => {
+        LazyLock::new(|| $list.iter().copied().collect())
+    };
+}
+
 /// The consonant clusters permitted by CLL.
-pub static VALID: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    HashSet::from([
-        "bd", "bg", "bj", "bl", "bm", "bn", "br", "bv", "bz", "cf", "ck", "cl", "cm", "cn", "cp",
-        "cr", "ct", "db", "dg", "dj", "dl", "dm", "dn", "dr", "dv", "dz", "fc", "fk", "fl", "fm",
-        "fn", "fp", "fr", "fs", "ft", "fx", "gb", "gd", "gj", "gl", "gm", "gn", "gr", "gv", "gz",
-        "jb", "jd", "jg", "jl", "jm", "jn", "jr", "jv", "kc", "kf", "kl", "km", "kn", "kp", "kr",
-        "ks", "kt", "lb", "lc", "ld", "lf", "lg", "lj", "lk", "lm", "ln", "lp", "lr", "ls", "lt",
-        "lv", "lx", "lz", "mb", "mc", "md", "mf", "mg", "mj", "mk", "ml", "mn", "mp", "mr", "ms",
-        "mt", "mv", "mx", "nb", "nc", "nd", "nf", "ng", "nj", "nk", "nl", "nm", "np", "nr", "ns",
-        "nt", "nv", "nx", "nz", "pc", "pf", "pk", "pl", "pm", "pn", "pr", "ps", "pt", "px", "rb",
-        "rc", "rd", "rf", "rg", "rj", "rk", "rl", "rm", "rn", "rp", "rs", "rt", "rv", "rx", "rz",
-        "sf", "sk", "sl", "sm", "sn", "sp", "sr", "st", "sx", "tc", "tf", "tk", "tl", "tm", "tn",
-        "tp", "tr", "ts", "tx", "vb", "vd", "vg", "vj", "vl", "vm", "vn", "vr", "vz", "xf", "xl",
-        "xm", "xn", "xp", "xr", "xs", "xt", "zb", "zd", "zg", "zl", "zm", "zn", "zr", "zv",
-    ])
-});
+pub const VALID_LIST: List = List::new(&[
+    "bd", "bg", "bj", "bl", "bm", "bn", "br", "bv", "bz", "cf", "ck", "cl", "cm", "cn", "cp", "cr",
+    "ct", "db", "dg", "dj", "dl", "dm", "dn", "dr", "dv", "dz", "fc", "fk", "fl", "fm", "fn", "fp",
+    "fr", "fs", "ft", "fx", "gb", "gd", "gj", "gl", "gm", "gn", "gr", "gv", "gz", "jb", "jd", "jg",
+    "jl", "jm", "jn", "jr", "jv", "kc", "kf", "kl", "km", "kn", "kp", "kr", "ks", "kt", "lb", "lc",
+    "ld", "lf", "lg", "lj", "lk", "lm", "ln", "lp", "lr", "ls", "lt", "lv", "lx", "lz", "mb", "mc",
+    "md", "mf", "mg", "mj", "mk", "ml", "mn", "mp", "mr", "ms", "mt", "mv", "mx", "nb", "nc", "nd",
+    "nf", "ng", "nj", "nk", "nl", "nm", "np", "nr", "ns", "nt", "nv", "nx", "nz", "pc", "pf", "pk",
+    "pl", "pm", "pn", "pr", "ps", "pt", "px", "rb", "rc", "rd", "rf", "rg", "rj", "rk", "rl", "rm",
+    "rn", "rp", "rs", "rt", "rv", "rx", "rz", "sf", "sk", "sl", "sm", "sn", "sp", "sr", "st", "sx",
+    "tc", "tf", "tk", "tl", "tm", "tn", "tp", "tr", "ts", "tx", "vb", "vd", "vg", "vj", "vl", "vm",
+    "vn", "vr", "vz", "xf", "xl", "xm", "xn", "xp", "xr", "xs", "xt", "zb", "zd", "zg", "zl", "zm",
+    "zn", "zr", "zv",
+]);
+/// [`VALID_LIST`] as a table.
+pub const VALID_TABLE: PairTable = PairTable::new(VALID_LIST);
+/// [`VALID_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static VALID: LazyLock<HashSet<&'static str>> = set_of!(VALID_LIST);
+
+/// Returns `true` if `c` is in [`VALID_LIST`], or is *mz* and `allow_mz` is
+/// set.
+#[must_use]
+pub const fn is_valid_cluster(c: &str, allow_mz: bool) -> bool {
+    VALID_TABLE.contains(c) || allow_mz && matches!(c.as_bytes(), b"mz")
+}
+
+/// Returns `true` if *`a``b`* is in [`VALID_LIST`], or is *mz* and `allow_mz`
+/// is set.
+#[must_use]
+pub const fn is_valid_pair(a: char, b: char, allow_mz: bool) -> bool {
+    VALID_TABLE.contains_pair(a, b) || allow_mz && a == 'm' && b == 'z'
+}
 
 #[allow(clippy::too_long_first_doc_paragraph)]
 /// The CLL consonant clusters ([`VALID`]) + *mz*, which CLL forbids in order to
 /// spite the inventor of Loglan. You can control whether this list of clusters
-/// is used via `allow_mz` in [`Settings`][`crate::Settings`].
-pub static MZ_VALID: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    let mut clusters = VALID.iter().copied().collect::<HashSet<_>>();
-    clusters.insert("mz");
-    clusters
-});
+/// is used via `allow_mz` in [`Settings`][`crate::Settings`]; without `std`,
+/// use [`is_valid_cluster`].
+#[cfg(feature = "std")]
+pub static MZ_VALID: LazyLock<HashSet<&'static str>> =
+    LazyLock::new(|| VALID_LIST.iter().copied().chain(["mz"]).collect());
 
 /// The consonant clusters permitted *word-initially* by CLL.
-pub static INITIAL: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    HashSet::from([
-        "bl", "br", "cf", "ck", "cl", "cm", "cn", "cp", "cr", "ct", "dj", "dr", "dz", "fl", "fr",
-        "gl", "gr", "jb", "jd", "jg", "jm", "jv", "kl", "kr", "ml", "mr", "pl", "pr", "sf", "sk",
-        "sl", "sm", "sn", "sp", "sr", "st", "tc", "tr", "ts", "vl", "vr", "xl", "xr", "zb", "zd",
-        "zg", "zm", "zv",
-    ])
-});
-
-pub static ZIHEVLA_INITIAL: LazyLock<HashSet<&str>> = LazyLock::new(|| {
-    HashSet::from([
-        "bl", "br", "dr", "fl", "fr", "gl", "gr", "kl", "kr", "ml", "mr", "pl", "pr", "tr", "vl",
-        "vr",
-    ])
-});
+pub const INITIAL_LIST: List = List::new(&[
+    "bl", "br", "cf", "ck", "cl", "cm", "cn", "cp", "cr", "ct", "dj", "dr", "dz", "fl", "fr", "gl",
+    "gr", "jb", "jd", "jg", "jm", "jv", "kl", "kr", "ml", "mr", "pl", "pr", "sf", "sk", "sl", "sm",
+    "sn", "sp", "sr", "st", "tc", "tr", "ts", "vl", "vr", "xl", "xr", "zb", "zd", "zg", "zm", "zv",
+]);
+/// [`INITIAL_LIST`] as a table.
+pub const INITIAL_TABLE: PairTable = PairTable::new(INITIAL_LIST);
+/// [`INITIAL_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static INITIAL: LazyLock<HashSet<&'static str>> = set_of!(INITIAL_LIST);
+
+pub const ZIHEVLA_INITIAL_LIST: List = List::new(&[
+    "bl", "br", "dr", "fl", "fr", "gl", "gr", "kl", "kr", "ml", "mr", "pl", "pr", "tr", "vl", "vr",
+]);
+/// [`ZIHEVLA_INITIAL_LIST`] as a table.
+pub const ZIHEVLA_INITIAL_TABLE: PairTable = PairTable::new(ZIHEVLA_INITIAL_LIST);
+/// [`ZIHEVLA_INITIAL_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static ZIHEVLA_INITIAL: LazyLock<HashSet<&str>> = set_of!(ZIHEVLA_INITIAL_LIST);
+
 /// The set of consonant triples banned by CLL: *nts*, *ntc*, *ndz*, *ndj*.
 /// These are banned because they sound too similar to *ns*, *nc*, *nz*, *nj*.
-pub static BANNED_TRIPLES: LazyLock<HashSet<&'static str>> =
-    LazyLock::new(|| HashSet::from(["ndj", "ndz", "ntc", "nts"]));
+pub const BANNED_TRIPLES_LIST: List = List::new(&["ndj", "ndz", "ntc", "nts"]);
+/// [`BANNED_TRIPLES_LIST`] as a table.
+pub const BANNED_TRIPLES_TABLE: TripleTable = TripleTable::new(BANNED_TRIPLES_LIST);
+/// [`BANNED_TRIPLES_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static BANNED_TRIPLES: LazyLock<HashSet<&'static str>> = set_of!(BANNED_TRIPLES_LIST);
 
 /// Single vowels and falling diphthongs. These syllables always require a
 /// pronounced glottal stop before them.
+pub const START_VOWEL_CLUSTERS_LIST: List =
+    List::new(&["a", "ai", "au", "e", "ei", "i", "o", "oi", "u"]);
+/// [`START_VOWEL_CLUSTERS_LIST`] as a set.
+#[cfg(feature = "std")]
 pub static START_VOWEL_CLUSTERS: LazyLock<HashSet<&'static str>> =
-    LazyLock::new(|| HashSet::from(["a", "e", "i", "o", "u", "au", "ai", "ei", "oi"]));
+    set_of!(START_VOWEL_CLUSTERS_LIST);
 
 /// Syllables starting with glides (*i*/*u*). These syllables can sometimes not
 /// be preceded by a pronounced glottal stop.
-pub static FOLLOW_VOWEL_CLUSTERS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    HashSet::from([
-        "ia", "ie", "ii", "io", "iu", "iau", "iai", "iei", "ioi", "ua", "ue", "ui", "uo", "uu",
-        "uau", "uai", "uei", "uoi",
-    ])
-});
+pub const FOLLOW_VOWEL_CLUSTERS_LIST: List = List::new(&[
+    "ia", "iai", "iau", "ie", "iei", "ii", "io", "ioi", "iu", "ua", "uai", "uau", "ue", "uei",
+    "ui", "uo", "uoi", "uu",
+]);
+/// [`FOLLOW_VOWEL_CLUSTERS_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static FOLLOW_VOWEL_CLUSTERS: LazyLock<HashSet<&'static str>> =
+    set_of!(FOLLOW_VOWEL_CLUSTERS_LIST);
 
 /// The set of lujvo hyphens, used between zi'evla and to prevent cmavo-shaped
 /// rafsi from falling of the start of a lujvo.
-pub static HYPHENS: LazyLock<HashSet<&'static str>> =
-    LazyLock::new(|| HashSet::from(["r", "n", "y", "'y", "y'", "'y'"]));
+pub const HYPHENS_LIST: List = List::new(&["'y", "'y'", "n", "r", "y", "y'"]);
+/// [`HYPHENS_LIST`] as a set.
+#[cfg(feature = "std")]
+pub static HYPHENS: LazyLock<HashSet<&'static str>> = set_of!(HYPHENS_LIST);
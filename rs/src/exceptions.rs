@@ -1,8 +1,10 @@
 //! Categories of errors.
 
-use std::fmt::Display;
+use alloc::string::String;
+use core::fmt::{self, Display};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "kind", content = "message"))]
 /// An error. The specific variants/messages that are returned are discussed
 /// per-function.
 pub enum Jvonunfli {
@@ -26,6 +28,19 @@ use Jvonunfli::{
     NonLojbanCharacterError, NotBrivlaError, NotZihevlaError,
 };
 impl Jvonunfli {
+    /// The name of the variant, e.g. `"NotBrivlaError"`.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DecompositionError(_) => "DecompositionError",
+            InvalidClusterError(_) => "InvalidClusterError",
+            NoLujvoFoundError(_) => "NoLujvoFoundError",
+            NonLojbanCharacterError(_) => "NonLojbanCharacterError",
+            NotBrivlaError(_) => "NotBrivlaError",
+            NotZihevlaError(_) => "NotZihevlaError",
+            FakeTypeError(_) => "FakeTypeError",
+        }
+    }
     pub fn text(self) -> String {
         match self {
             DecompositionError(e)
@@ -39,7 +54,7 @@ impl Jvonunfli {
     }
 }
 impl Display for Jvonunfli {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.clone().text())
     }
 }
@@ -1,31 +1,37 @@
 //! Functions for creating a lujvo.
 
-use std::sync::LazyLock;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{mem, str};
 
-use indexmap::IndexMap;
 use itertools::Itertools as _;
-use regex::Regex;
 
 use crate::{
-    data::{BANNED_TRIPLES, INITIAL, MZ_VALID, VALID},
+    data::{BANNED_TRIPLES_TABLE, INITIAL_TABLE, is_valid_pair},
     exceptions::Jvonunfli::{
         self, DecompositionError, FakeTypeError, InvalidClusterError, NoLujvoFoundError,
         NonLojbanCharacterError, NotBrivlaError, NotZihevlaError,
     },
     extract,
     katna::jvokaha2,
-    rafsi::RAFSI,
-    strin, strsl,
+    rafsi::rafsi_of,
     tarmi::{
         BrivlaType::{Gismu, Rafsi, Zihevla},
         ConsonantSetting::{Cluster, OneConsonant},
         Settings,
-        Tarmi::{Ccv, Ccvc, Cvc, Cvcc, Cvccv, Cvhv, Cvv, OtherRafsi},
+        Tarmi::{self, Ccv, Ccvc, Cvc, Cvcc, Cvccv, Cvhv, Cvv, OtherRafsi},
         YHyphenSetting::{ForceY, Standard},
         contains_consonant, is_consonant, is_glide, is_only_lojban_characters, is_valid_rafsi,
         is_vowel, rafsi_tarmi, strip_hyphens, tarmi_ignoring_hyphen,
     },
-    tools::{analyze_brivla, check_zihevla_or_rafsi, is_slinkuhi, normalize, regex_replace_all},
+    tools::{
+        analyze_brivla, check_zihevla_or_rafsi, cut, drop_back, is_slinkuhi, letter, letter_back,
+        normalize,
+    },
 };
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -45,9 +51,8 @@ pub enum Tosytype {
 use Tosytype::{Tosmabru, Tosyhuhu, Tosynone};
 
 /// Calculates the score for a rafsi (possibly including a hyphen). Use
-/// [`score_lujvo`][`crate::score_lujvo`] to find the score of a lujvo.
-/// # Panics
-/// If the score does not fit into a `i32`.
+/// [`score_lujvo`][`crate::score_lujvo`] to find the score of a lujvo. Scores
+/// too big for an `i32` (over two million letters) become [`i32::MAX`].
 #[must_use]
 pub fn score(r: &str) -> i32 {
     let t = tarmi_ignoring_hyphen(r) as usize % 9;
@@ -56,14 +61,16 @@ pub fn score(r: &str) -> i32 {
             - 10 * t
             - r.chars().filter(|c| is_vowel(*c)).count(),
     )
-    .unwrap()
+    .unwrap_or(i32::MAX)
 }
 /// A tiebreak so lujvo with a CVV first rafsi and a CCV(C) or CVC(C) second
 /// rafsi are preferred whenever there are two lujvo candidates with the same
 /// score.
 pub(crate) fn tiebreak(lujvo: &str) -> i32 {
-    (rafsi_tarmi(strsl!(lujvo, 0..3)) == Cvv
-        && [Ccv, Ccvc, Cvc, Cvcc].contains(&rafsi_tarmi(strsl!(lujvo, 3..)))) as i32
+    let (Some(first), Some(rest)) = (lujvo.get(..3), lujvo.get(3..)) else {
+        return 0;
+    };
+    (rafsi_tarmi(first) == Cvv && [Ccv, Ccvc, Cvc, Cvcc].contains(&rafsi_tarmi(rest))) as i32
 }
 
 /// Cleans up and normalizes the given tanru.
@@ -84,7 +91,7 @@ pub fn get_rafsi_for_rafsi(
     settings: &Settings,
 ) -> Result<Vec<(String, i32)>, Jvonunfli> {
     let mut res = vec![];
-    let r = if !first && is_vowel(strin!(r, 0)) && !is_glide(r) {
+    let r = if !first && is_vowel(letter(r, 0)) && !is_glide(r) {
         format!("'{r}")
     } else {
         r.to_string()
@@ -93,7 +100,7 @@ pub fn get_rafsi_for_rafsi(
         "ShortBrivla" | "Ccvc" | "Cvcc" => {
             if !last {
                 res.push((format!("{r}y"), 2));
-            } else if !is_vowel(strin!(&r, -1)) {
+            } else if !is_vowel(letter_back(&r, 0)) {
                 res.push((r, 2));
             }
         }
@@ -106,7 +113,7 @@ pub fn get_rafsi_for_rafsi(
         }
         "ExperimentalRafsi" => {
             let num_consonants = (settings.consonants != Cluster
-                && (is_consonant(strin!(&r, 0)) || settings.glides && is_glide(&r)))
+                && (is_consonant(letter(&r, 0)) || settings.glides && is_glide(&r)))
                 as i32;
             if last {
                 res.push((r, num_consonants));
@@ -143,8 +150,6 @@ pub fn get_rafsi_for_rafsi(
     Ok(res)
 }
 
-static BOUNDARY_HYPHENS: LazyLock<Regex> = LazyLock::new(|| Regex::new("^-+|-+$").unwrap());
-
 #[allow(clippy::missing_panics_doc)] // .unwrap()
 /// Gets the rafsi list for each word.
 /// # Errors
@@ -176,16 +181,16 @@ pub fn get_rafsi_list_list(
         let mut rafsi_list = vec![];
         let first = i == 0;
         let last = i == valsi_list.len() - 1;
-        let hyphenless = regex_replace_all(&BOUNDARY_HYPHENS, valsi, "");
-        if strin!(valsi, -1) == '-' {
-            let is_short_brivla = strin!(valsi, 0) != '-';
+        let hyphenless = valsi.trim_matches('-').to_string();
+        if letter_back(valsi, 0) == '-' {
+            let is_short_brivla = letter(valsi, 0) != '-';
             valsi = &hyphenless;
             if !is_only_lojban_characters(valsi) {
                 return Err(NonLojbanCharacterError(format!(
                     "{{{valsi}}} contains a non-lojban character"
                 )));
             }
-            if strin!(valsi, -1) == '\'' {
+            if letter_back(valsi, 0) == '\'' {
                 return Err(NonLojbanCharacterError(format!("{{{valsi}}} ends in an apostrophe")));
             }
             if is_short_brivla {
@@ -207,7 +212,7 @@ pub fn get_rafsi_list_list(
                         "{{{valsi}a}} is not a gismu or zi'evla"
                     )));
                 }
-                if valsi.len() > 5 && is_consonant(strin!(valsi, -1)) {
+                if valsi.len() > 5 && is_consonant(letter_back(valsi, 0)) {
                     let mut decomposes = true;
                     if let Err(e) = jvokaha2(valsi, &extract!(settings; y_hyphens, allow_mz)) {
                         match e {
@@ -306,7 +311,7 @@ pub fn get_rafsi_list_list(
                     "{{{valsi}}} contains a non-lojban character"
                 )));
             }
-            let short_rafsi_list = RAFSI.get(valsi.as_str());
+            let short_rafsi_list = rafsi_of(valsi);
             if let Some(srl) = short_rafsi_list {
                 for r in srl {
                     let raftai = rafsi_tarmi(r);
@@ -332,7 +337,7 @@ pub fn get_rafsi_list_list(
                 let b_type = b_type.unwrap().0;
                 if b_type == Gismu {
                     rafsi_list.extend(get_rafsi_for_rafsi(
-                        strsl!(valsi, 0..-1),
+                        drop_back(valsi, 1),
                         "ShortBrivla",
                         first,
                         last,
@@ -360,42 +365,116 @@ pub fn grll(vl: &str, settings: &Settings) -> Result<Vec<Vec<(String, i32)>>, Jv
     get_rafsi_list_list(&process_tanru(vl), settings)
 }
 
-/// A potential lujvo-rafsi combination. The fields in order are `(`[tosmabru
-/// type][`Tosytype`], number of consonants, score of the resulting lujvo, the
-/// lujvo itself, indices of all the rafsi`)`.
-pub type Candidate = Option<(Tosytype, i32, i32, String, Vec<[usize; 2]>)>;
+/// The hyphens that can go between two rafsi.
+pub(crate) const HYPHEN_CHOICES: [&str; 5] = ["", "y", "'y", "n", "r"];
+/// The [`score`] of each of [`HYPHEN_CHOICES`].
+pub(crate) const HYPHEN_SCORES: [i32; 5] = [0, 1100, 2100, 1000, 1000];
+
+/// What [`add_rafsi`] needs to know about a rafsi, worked out once per tanru
+/// instead of once per candidate.
+struct RafsiEntry<'a> {
+    rafsi: &'a str,
+    consonants: i32,
+    first: char,
+    second: char,
+    last: char,
+    /// The last letter that isn't *y* or an apostrophe.
+    last_letter: Option<char>,
+    /// The shape ignoring hyphens.
+    tarmi: Tarmi,
+    /// Where the part the rafsi indices point to starts, and how long it is.
+    offset: usize,
+    indexed_len: usize,
+    /// The shape of that part.
+    indexed_tarmi: Tarmi,
+    score: i32,
+    only_vowels: bool,
+    is_glide: bool,
+    contains_consonant: bool,
+    /// Whether a consonant (or a glide with `glides`) follows the leading
+    /// apostrophes.
+    starts_with_consonant: bool,
+    /// Whether the third and fourth letters are an initial pair.
+    initial_middle: bool,
+}
+
+impl<'a> RafsiEntry<'a> {
+    fn new(rafsi: &'a str, consonants: i32, first_in_lujvo: bool, settings: &Settings) -> Self {
+        let indexed_len = strip_hyphens(rafsi).len();
+        let offset = (!first_in_lujvo && letter(rafsi, 0) == '\'') as usize;
+        let mut j = 0;
+        while letter(rafsi, j) == '\'' {
+            j += 1;
+        }
+        Self {
+            rafsi,
+            consonants,
+            first: letter(rafsi, 0),
+            second: letter(rafsi, 1),
+            last: letter_back(rafsi, 0),
+            last_letter: rafsi.chars().rev().find(|c| !"'y".contains(*c)),
+            tarmi: tarmi_ignoring_hyphen(rafsi),
+            offset,
+            indexed_len,
+            indexed_tarmi: rafsi_tarmi(rafsi.get(offset..offset + indexed_len).unwrap_or_default()),
+            score: score(rafsi),
+            only_vowels: rafsi.chars().all(|c| "aeiou'".contains(c)),
+            is_glide: is_glide(rafsi),
+            contains_consonant: contains_consonant(rafsi),
+            starts_with_consonant: is_consonant(letter(rafsi, j))
+                || settings.glides && is_glide(cut(rafsi, j..)),
+            initial_middle: INITIAL_TABLE.contains(cut(rafsi, 2..4)),
+        }
+    }
+}
+
+/// A lujvo being built. Only the first few letters are kept; the rest can be
+/// found by following `prev` back to the first rafsi.
+#[derive(Clone, Copy)]
+struct Partial {
+    /// The lujvo this was made from, as an index into the list of all of them.
+    prev: Option<usize>,
+    /// Which word and which of its rafsi was added last.
+    at: [usize; 2],
+    /// The hyphen before that rafsi, as an index into [`HYPHEN_CHOICES`].
+    hyphen: usize,
+    /// The indices of that rafsi.
+    indices: [usize; 2],
+    len: usize,
+    head: [u8; 8],
+    last: char,
+    last_letter: Option<char>,
+    last_tarmi: Tarmi,
+    consonants: i32,
+    score: i32,
+    tosmabru_type: Tosytype,
+}
+
+impl Partial {
+    /// The whole lujvo if it's at most 8 letters, or else its first 8.
+    fn head(&self) -> &str { str::from_utf8(&self.head[..self.len.min(8)]).unwrap_or_default() }
+}
 
 /// Tries to add a rafsi to a lujvo and calculate the score.
-#[allow(clippy::too_many_arguments)] // sorry!
-#[allow(clippy::missing_panics_doc)] // .unwrap()
-pub fn combine(
-    lujvo: &str,
-    rafsi: &str,
-    lujvo_c: i32,
-    rafsi_c: i32,
-    lujvo_score: i32,
-    indices: &[[usize; 2]],
-    mut tosmabru_type: Tosytype,
+fn add_rafsi(
+    lujvo: &Partial,
+    prev: usize,
+    rafsi: &RafsiEntry,
+    at: [usize; 2],
     tanru_len: usize,
     settings: &Settings,
-) -> Candidate {
-    let lujvo_f = strin!(lujvo, -1);
-    let rafsi_i = strin!(rafsi, 0);
-    let raftai0 = tarmi_ignoring_hyphen(lujvo);
-    let raftai1 = tarmi_ignoring_hyphen(rafsi);
-    let prulamrafsi = &lujvo[indices.last().unwrap()[0]..indices.last().unwrap()[1]];
+) -> Option<Partial> {
+    let lujvo_f = lujvo.last;
+    let rafsi_i = rafsi.first;
     if is_consonant(lujvo_f)
         && is_consonant(rafsi_i)
-        && !if settings.allow_mz { &MZ_VALID } else { &VALID }
-            .contains(&format!("{lujvo_f}{rafsi_i}").as_str())
-        || BANNED_TRIPLES.contains(&format!("{lujvo_f}{}", strsl!(rafsi, 0..2)).as_str())
-        || !"y'".contains(lujvo_f) && raftai1 == OtherRafsi
-        || [Cvv, Cvc].contains(&rafsi_tarmi(prulamrafsi))
-            && rafsi.chars().all(|c| "aeiou'".contains(c))
-            && (rafsi.starts_with('\'')
-                || is_glide(rafsi)
-                    && !settings.glides
-                    && settings.consonants == Cluster)
+        && !is_valid_pair(lujvo_f, rafsi_i, settings.allow_mz)
+        || BANNED_TRIPLES_TABLE.contains_triple(lujvo_f, rafsi_i, rafsi.second)
+        || !"y'".contains(lujvo_f) && rafsi.tarmi == OtherRafsi
+        || [Cvv, Cvc].contains(&lujvo.last_tarmi)
+            && rafsi.only_vowels
+            && (rafsi_i == '\''
+                || rafsi.is_glide && !settings.glides && settings.consonants == Cluster)
     {
         return None;
     }
@@ -407,12 +486,12 @@ pub fn combine(
             return None;
         }
     } else {
-        if lujvo.len() == 5 && rafsi_tarmi(strsl!(lujvo, 0..3)) == Ccv && strsl!(lujvo, 3..) == "'y"
-        {
+        let head = lujvo.head();
+        if lujvo.len == 5 && rafsi_tarmi(cut(head, 0..3)) == Ccv && cut(head, 3..) == "'y" {
             return None;
         }
-        if lujvo.len() <= 5 && !settings.generate_cmevla {
-            if [Cvhv, Cvv].contains(&raftai0) {
+        if lujvo.len <= 5 && !settings.generate_cmevla {
+            if [Cvhv, Cvv].contains(&tarmi_ignoring_hyphen(head)) {
                 hyphen = if settings.y_hyphens == ForceY {
                     "'y"
                 } else if rafsi_i == 'r' {
@@ -421,82 +500,184 @@ pub fn combine(
                     "r"
                 };
             }
-            if tanru_len == 2 && raftai1 == Ccv {
+            if tanru_len == 2 && rafsi.tarmi == Ccv {
                 hyphen = "";
             }
         }
     }
+    let mut tosmabru_type = lujvo.tosmabru_type;
     if tosmabru_type == Tosmabru {
-        if !INITIAL.contains(&format!("{lujvo_f}{rafsi_i}").as_str()) {
+        if !INITIAL_TABLE.contains_pair(lujvo_f, rafsi_i) {
             tosmabru_type = Tosynone;
-        } else if raftai1 == Cvccv {
-            if INITIAL.contains(strsl!(rafsi, 2..4)) {
+        } else if rafsi.tarmi == Cvccv {
+            if rafsi.initial_middle {
                 return None;
             }
             tosmabru_type = Tosynone;
-        } else if raftai1 == Cvc {
-            if strin!(rafsi, -1) == 'y' {
+        } else if rafsi.tarmi == Cvc {
+            if rafsi.last == 'y' {
                 return None;
             }
         } else {
             tosmabru_type = Tosynone;
         }
-    } else if tosmabru_type == Tosyhuhu && (rafsi_i != '\'' || contains_consonant(rafsi)) {
+    } else if tosmabru_type == Tosyhuhu && (rafsi_i != '\'' || rafsi.contains_consonant) {
         tosmabru_type = Tosynone;
     }
-    let rafsi_start = lujvo.len() + hyphen.len() + (strin!(rafsi, 0) == '\'') as usize;
-    let rafsi_end = rafsi_start + strip_hyphens(rafsi).len();
-    let indices = indices.iter().chain(&[[rafsi_start, rafsi_end]]).copied().collect_vec();
-    let mut new_c = rafsi_c;
+    let rafsi_start = lujvo.len + hyphen.len() + rafsi.offset;
+    let mut new_c = rafsi.consonants;
     if !hyphen.is_empty() && "nr".contains(hyphen) {
         new_c = 2;
-    } else if settings.consonants == Cluster && rafsi_c != 2 {
-        let mut i = lujvo.len() as isize - 1;
-        while "'y".contains(strin!(lujvo, i)) {
-            i -= 1;
-        }
-        let mut j = 0;
-        while strin!(rafsi, j) == '\'' {
-            j += 1;
-        }
-        new_c = (is_consonant(strin!(lujvo, i))
-            && (is_consonant(strin!(rafsi, j)) || settings.glides && is_glide(strsl!(rafsi, j..))))
-            as i32
-            * 2;
+    } else if settings.consonants == Cluster && rafsi.consonants != 2 {
+        new_c =
+            (lujvo.last_letter.is_some_and(is_consonant) && rafsi.starts_with_consonant) as i32 * 2;
     }
-    let mut total_c = 2.min(lujvo_c + new_c);
+    let mut total_c = 2.min(lujvo.consonants + new_c);
     if settings.consonants == OneConsonant && total_c > 0 {
         total_c = 2;
     }
-    let res = format!("{lujvo}{hyphen}{rafsi}");
-    let score = lujvo_score + score(hyphen) + score(rafsi) - tiebreak(&res);
-    Some((tosmabru_type, total_c, score, res, indices))
+    let hyphen_i = HYPHEN_CHOICES.iter().position(|h| *h == hyphen).unwrap_or_default();
+    let mut res = Partial {
+        prev: Some(prev),
+        at,
+        hyphen: hyphen_i,
+        indices: [rafsi_start, rafsi_start + rafsi.indexed_len],
+        len: lujvo.len + hyphen.len() + rafsi.rafsi.len(),
+        head: lujvo.head,
+        last: rafsi.last,
+        last_letter: rafsi
+            .last_letter
+            .or_else(|| hyphen.chars().rev().find(|c| !"'y".contains(*c)))
+            .or(lujvo.last_letter),
+        last_tarmi: rafsi.indexed_tarmi,
+        consonants: total_c,
+        score: 0,
+        tosmabru_type,
+    };
+    for (h, b) in res.head.iter_mut().skip(lujvo.len).zip(hyphen.bytes().chain(rafsi.rafsi.bytes()))
+    {
+        *h = b;
+    }
+    // the tiebreak only looks at lujvo of 6 or 7 letters
+    let tiebreak = if res.len <= 8 { tiebreak(res.head()) } else { 0 };
+    res.score = lujvo.score + HYPHEN_SCORES[hyphen_i] + rafsi.score - tiebreak;
+    Some(res)
 }
 
-type BestLujvoMap = IndexMap<char, (String, i32, Vec<[usize; 2]>)>;
+/// A potential lujvo-rafsi combination. The fields in order are `(`[tosmabru
+/// type][`Tosytype`], number of consonants, score of the resulting lujvo, the
+/// lujvo itself, indices of all the rafsi`)`.
+#[deprecated(note = "only used by `combine`")]
+pub type Candidate = Option<(Tosytype, i32, i32, String, Vec<[usize; 2]>)>;
+
+/// Tries to add a rafsi to a lujvo and calculate the score.
+/// [`get_lujvo_from_list`] no longer builds every candidate like this.
+#[deprecated(note = "use `get_lujvo_from_list`")]
+#[allow(deprecated)]
+#[allow(clippy::too_many_arguments)] // sorry!
+#[must_use]
+pub fn combine(
+    lujvo: &str,
+    rafsi: &str,
+    lujvo_c: i32,
+    rafsi_c: i32,
+    lujvo_score: i32,
+    indices: &[[usize; 2]],
+    tosmabru_type: Tosytype,
+    tanru_len: usize,
+    settings: &Settings,
+) -> Candidate {
+    let &[start, end] = indices.last()?;
+    let mut head = [0; 8];
+    for (h, b) in head.iter_mut().zip(lujvo.bytes()) {
+        *h = b;
+    }
+    let partial = Partial {
+        prev: None,
+        at: [0, 0],
+        hyphen: 0,
+        indices: [start, end],
+        len: lujvo.len(),
+        head,
+        last: lujvo.chars().last()?,
+        last_letter: lujvo.chars().rev().find(|c| !"'y".contains(*c)),
+        last_tarmi: rafsi_tarmi(lujvo.get(start..end).unwrap_or_default()),
+        consonants: lujvo_c,
+        score: lujvo_score,
+        tosmabru_type,
+    };
+    let entry = RafsiEntry::new(rafsi, rafsi_c, false, settings);
+    let res = add_rafsi(&partial, 0, &entry, [0, 0], tanru_len, settings)?;
+    let res_lujvo = format!("{lujvo}{}{rafsi}", HYPHEN_CHOICES[res.hyphen]);
+    let score = lujvo_score + HYPHEN_SCORES[res.hyphen] + entry.score - tiebreak(&res_lujvo);
+    let indices = indices.iter().copied().chain([res.indices]).collect();
+    Some((res.tosmabru_type, res.consonants, score, res_lujvo, indices))
+}
+
+#[cfg(feature = "std")]
+type CandidateMap = indexmap::IndexMap<char, (String, i32, Vec<[usize; 2]>)>;
 
 /// Adds a candidate to `current_best`.
-#[allow(clippy::missing_panics_doc)] // .unwrap()
+#[cfg(feature = "std")]
+#[deprecated(note = "use `get_lujvo_from_list`")]
+#[allow(deprecated)]
 #[must_use]
 pub fn update_current_best(
     candidate: Candidate,
-    mut current_best: [[BestLujvoMap; 3]; 3],
-) -> [[BestLujvoMap; 3]; 3] {
-    if candidate.is_none() {
+    mut current_best: [[CandidateMap; 3]; 3],
+) -> [[CandidateMap; 3]; 3] {
+    let Some((tosmabru_type, num_consonants, res_score, res_lujvo, res_indices)) = candidate else {
         return current_best;
-    }
-    let (tosmabru_type, num_consonants, res_score, res_lujvo, res_indices) = candidate.unwrap();
-    let lujvo_f = strin!(&res_lujvo, -1);
-    if !current_best[tosmabru_type as usize][num_consonants as usize].contains_key(&lujvo_f)
-        || current_best[tosmabru_type as usize][num_consonants as usize].get(&lujvo_f).unwrap().1
-            > res_score
-    {
-        current_best[tosmabru_type as usize][num_consonants as usize]
-            .insert(lujvo_f, (res_lujvo, res_score, res_indices));
+    };
+    let Some(lujvo_f) = res_lujvo.chars().last() else {
+        return current_best;
+    };
+    let best = &mut current_best[tosmabru_type as usize][num_consonants as usize];
+    if best.get(&lujvo_f).is_none_or(|(_, score, _)| *score > res_score) {
+        best.insert(lujvo_f, (res_lujvo, res_score, res_indices));
     }
     current_best
 }
 
+/// The best lujvo so far for each last letter, in the order the letters were
+/// first seen. There are never more than a couple dozen letters, so a list is
+/// faster than hashing.
+#[derive(Default)]
+struct BestLujvoMap(Vec<(char, usize)>);
+
+impl BestLujvoMap {
+    fn get(&self, last: char) -> Option<usize> {
+        self.0.iter().find(|(c, _)| *c == last).map(|&(_, i)| i)
+    }
+
+    /// Replaces the lujvo for `last`, keeping its place, or adds it at the end.
+    fn insert(&mut self, last: char, i: usize) {
+        match self.0.iter_mut().find(|(c, _)| *c == last) {
+            Some(entry) => entry.1 = i,
+            None => self.0.push((last, i)),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (char, usize)> + '_ { self.0.iter().copied() }
+
+    fn values(&self) -> impl Iterator<Item = usize> + '_ { self.0.iter().map(|&(_, i)| i) }
+}
+
+/// Adds a candidate to `current_best` if it's the best one yet with its
+/// tosmabru type, number of consonants, and last letter.
+fn keep_if_best(
+    candidate: Option<Partial>,
+    current_best: &mut [[BestLujvoMap; 3]; 3],
+    lujvo: &mut Vec<Partial>,
+) {
+    let Some(candidate) = candidate else { return };
+    let best = &mut current_best[candidate.tosmabru_type as usize][candidate.consonants as usize];
+    if best.get(candidate.last).is_none_or(|i| lujvo[i].score > candidate.score) {
+        best.insert(candidate.last, lujvo.len());
+        lujvo.push(candidate);
+    }
+}
+
 /// Creates the best lujvo for the tanru (list). It is recommended to use
 /// [`get_lujvo_with_analytics`] instead if you have a string.
 /// # Errors
@@ -516,95 +697,96 @@ pub fn get_lujvo_from_list(
     let rafsi_list_list = get_rafsi_list_list(
         valsi_list,
         &extract!(settings; y_hyphens, exp_rafsi, consonants, glides, allow_mz),
-    );
-    let mut current_best = [
-        [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-        [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-        [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-    ];
-    let rafsi_list_list = rafsi_list_list?;
+    )?;
     if rafsi_list_list.len() < 2 {
         return Err(FakeTypeError(format!("{{{}}} is less than 2 words", valsi_list.join(" "))));
     }
-    for rafsi0 in &rafsi_list_list[0] {
-        for rafsi1 in &rafsi_list_list[1] {
-            let tosmabru_type =
-                if tarmi_ignoring_hyphen(&rafsi0.0) == Cvc && !settings.generate_cmevla {
-                    if strin!(&rafsi0.0, -1) == 'y' { Tosyhuhu } else { Tosmabru }
-                } else {
-                    Tosynone
-                };
-            let res = combine(
-                &rafsi0.0,
-                &rafsi1.0,
-                rafsi0.1,
-                rafsi1.1,
-                score(&rafsi0.0),
-                &[[0, strip_hyphens(&rafsi0.0).len()]],
-                tosmabru_type,
-                rafsi_list_list.len(),
-                &extract!(
-                    settings;
-                    generate_cmevla,
-                    y_hyphens,
-                    consonants,
-                    glides,
-                    allow_mz
-                ),
-            );
-            current_best = update_current_best(res, current_best);
+    let combine_settings =
+        extract!(settings; generate_cmevla, y_hyphens, consonants, glides, allow_mz);
+    let tables = rafsi_list_list
+        .iter()
+        .enumerate()
+        .map(|(i, rafsi_list)| {
+            rafsi_list
+                .iter()
+                .map(|(r, c)| RafsiEntry::new(r, *c, i == 0, &combine_settings))
+                .collect_vec()
+        })
+        .collect_vec();
+    // every lujvo that was the best of its kind at some point, pointing back
+    // to the shorter lujvo they were made from
+    let mut lujvo = vec![];
+    let mut current_best: [[BestLujvoMap; 3]; 3] = Default::default();
+    for (i, rafsi0) in tables[0].iter().enumerate() {
+        let mut head = [0; 8];
+        for (h, b) in head.iter_mut().zip(rafsi0.rafsi.bytes()) {
+            *h = b;
+        }
+        let first = Partial {
+            prev: None,
+            at: [0, i],
+            hyphen: 0,
+            indices: [0, rafsi0.indexed_len],
+            len: rafsi0.rafsi.len(),
+            head,
+            last: rafsi0.last,
+            last_letter: rafsi0.last_letter,
+            last_tarmi: rafsi0.indexed_tarmi,
+            consonants: rafsi0.consonants,
+            score: rafsi0.score,
+            tosmabru_type: if rafsi0.tarmi == Cvc && !settings.generate_cmevla {
+                if rafsi0.last == 'y' { Tosyhuhu } else { Tosmabru }
+            } else {
+                Tosynone
+            },
+        };
+        lujvo.push(first);
+        let prev = lujvo.len() - 1;
+        for (j, rafsi1) in tables[1].iter().enumerate() {
+            let res = add_rafsi(&first, prev, rafsi1, [1, j], tables.len(), &combine_settings);
+            keep_if_best(res, &mut current_best, &mut lujvo);
         }
     }
-    let mut previous_best = current_best;
-    for rafsi_list in rafsi_list_list.iter().skip(2) {
-        current_best = [
-            [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-            [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-            [BestLujvoMap::new(), BestLujvoMap::new(), BestLujvoMap::new()],
-        ];
-        for rafsi in rafsi_list {
-            for tosmabru_type in [Tosynone, Tosmabru, Tosyhuhu] {
-                for (num_consonants, _) in previous_best.iter().enumerate() {
-                    for (_, lujvo_and_score) in
-                        &previous_best[tosmabru_type as usize][num_consonants]
-                    {
-                        let res = combine(
-                            &lujvo_and_score.0,
-                            &rafsi.0,
-                            num_consonants as i32,
-                            rafsi.1,
-                            lujvo_and_score.1,
-                            &lujvo_and_score.2,
-                            tosmabru_type,
-                            rafsi_list_list.len(),
-                            &extract!(
-                                settings;
-                                generate_cmevla,
-                                y_hyphens,
-                                consonants,
-                                glides,
-                                allow_mz
-                            ),
-                        );
-                        current_best = update_current_best(res, current_best);
-                    }
-                }
+    for (k, table) in tables.iter().enumerate().skip(2) {
+        let previous_best = mem::take(&mut current_best);
+        for (j, rafsi) in table.iter().enumerate() {
+            // by tosmabru type, then number of consonants
+            for prev in previous_best.iter().flatten().flat_map(BestLujvoMap::values) {
+                let res =
+                    add_rafsi(&lujvo[prev], prev, rafsi, [k, j], tables.len(), &combine_settings);
+                keep_if_best(res, &mut current_best, &mut lujvo);
             }
         }
-        previous_best = current_best;
     }
-    let (mut best_lujvo, mut best_score, mut best_indices) = (String::new(), i32::MAX, vec![]);
-    for (c, lujvo_and_score) in &previous_best[0][2] {
-        if (is_vowel(*c) && !settings.generate_cmevla
-            || is_consonant(*c) && settings.generate_cmevla)
-            && lujvo_and_score.1 < best_score
+    let (mut best, mut best_score) = (None, i32::MAX);
+    for (c, i) in current_best[0][2].iter() {
+        if (is_vowel(c) && !settings.generate_cmevla || is_consonant(c) && settings.generate_cmevla)
+            && lujvo[i].score < best_score
         {
-            (best_lujvo, best_score, best_indices) = lujvo_and_score.clone();
+            (best, best_score) = (Some(i), lujvo[i].score);
         }
     }
-    if best_lujvo.is_empty() {
-        Err(NoLujvoFoundError(format!("{{{}}} can't be turned into a lujvo", valsi_list.join(" "))))
-    } else if !settings.generate_cmevla
+    let Some(best) = best else {
+        return Err(NoLujvoFoundError(format!(
+            "{{{}}} can't be turned into a lujvo",
+            valsi_list.join(" ")
+        )));
+    };
+    // only now are the letters put together
+    let mut pieces = vec![];
+    let mut next = Some(best);
+    while let Some(i) = next {
+        pieces.push(lujvo[i]);
+        next = lujvo[i].prev;
+    }
+    let mut best_lujvo = String::with_capacity(lujvo[best].len);
+    let mut best_indices = Vec::with_capacity(pieces.len());
+    for piece in pieces.iter().rev() {
+        best_lujvo.push_str(HYPHEN_CHOICES[piece.hyphen]);
+        best_lujvo.push_str(tables[piece.at[0]][piece.at[1]].rafsi);
+        best_indices.push(piece.indices);
+    }
+    if !settings.generate_cmevla
         && is_slinkuhi(&best_lujvo, &extract!(settings; y_hyphens, allow_mz))?
     {
         Err(NoLujvoFoundError(format!(
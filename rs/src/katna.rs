@@ -1,24 +1,32 @@
 //! Functions for decomposing a lujvo.
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 use itertools::Itertools as _;
 
+#[cfg(feature = "std")]
+use crate::cache;
 use crate::{
-    data::{BANNED_TRIPLES, HYPHENS, INITIAL, MZ_VALID, VALID},
+    data::{BANNED_TRIPLES_TABLE, HYPHENS_LIST, INITIAL_TABLE, is_valid_cluster},
     exceptions::Jvonunfli::{
         self, DecompositionError, InvalidClusterError, NoLujvoFoundError, NotBrivlaError,
     },
     extract,
     jvozba::{get_lujvo_from_list, score, tiebreak},
-    rafsi::RAFSI,
-    strin, strsl,
+    rafsi::{rafsi_of, selrafsi_of},
     tarmi::{
         BrivlaType::{Cmevla, ExtendedLujvo, Lujvo},
         Settings,
         Tarmi::{Ccv, Ccvc, Ccvcv, Cvc, Cvcc, Cvccv, Cvhv, Cvv},
         YHyphenSetting::{ForceY, Standard},
-        is_consonant, is_vowel, rafsi_tarmi,
+        check_lojban_characters, is_consonant, is_vowel, rafsi_tarmi,
     },
-    tools::{analyze_brivla, is_brivla},
+    tools::{Cursor, analyze_brivla, is_brivla, letter, letter_back},
 };
 
 /// Returns the word with the given rafsi, if one exists.
@@ -27,10 +35,10 @@ pub fn search_selrafsi_from_rafsi(r: &str) -> Option<String> {
         "aeiou"
             .chars()
             .map(|c| format!("{r}{c}"))
-            .find(|gismu| RAFSI.contains_key(gismu.as_str()))
-            .or_else(|| RAFSI.iter().find_map(|(v, rl)| rl.contains(&r).then(|| (*v).to_string())))
+            .find(|gismu| rafsi_of(gismu).is_some())
+            .or_else(|| selrafsi_of(r).map(String::from))
     } else {
-        RAFSI.iter().find_map(|(v, rl)| rl.contains(&r).then(|| (*v).to_string()))
+        selrafsi_of(r).map(String::from)
     }
 }
 /// Creates a list of selrafsi (source words) and formatted unassigned rafsi.
@@ -46,7 +54,7 @@ pub fn selrafsi_list_from_rafsi_list(
 ) -> Result<Vec<String>, Jvonunfli> {
     let mut res = rafsi_list
         .iter()
-        .map(|r| if HYPHENS.contains(&r.as_str()) { String::new() } else { r.clone() })
+        .map(|r| if HYPHENS_LIST.contains(r) { String::new() } else { r.clone() })
         .collect_vec();
     let selrafsi_list = res.iter().map(|r| search_selrafsi_from_rafsi(r)).collect_vec();
     for (i, _) in res.clone().iter().enumerate() {
@@ -57,7 +65,7 @@ pub fn selrafsi_list_from_rafsi_list(
             res[i] = selrafsi_list[i].clone().unwrap();
         } else if rafsi_list.len() >= 2
             && i < rafsi_list.len() - 2
-            && strin!(&rafsi_list[i + 1], 0) == 'y'
+            && letter(&rafsi_list[i + 1], 0) == 'y'
             && is_brivla(&format!("{}a", res[i]), &extract!(settings; y_hyphens, allow_mz))
         {
             res[i] = format!("{}-", res[i]);
@@ -80,19 +88,19 @@ pub fn selrafsi_list_from_rafsi_list(
 pub fn compare_lujvo_pieces(corr: &[String], other: &[String]) -> bool {
     let mut i = 0;
     for part in corr {
-        if part == &other[i] {
+        if other.get(i) == Some(part) {
             i += 1;
             continue;
         }
         if 0 < i
-            && i < other.len() - 1
+            && i + 1 < other.len()
             && "rn".contains(&other[i])
             && [Cvv, Cvhv].contains(&rafsi_tarmi(&other[i - 1]))
             && (i > 1 || [Ccvcv, Ccvc, Ccv].contains(&rafsi_tarmi(&other[i + 1])))
         {
             i += 1;
         }
-        if part == &other[i] {
+        if other.get(i) == Some(part) {
             i += 1;
         } else {
             return false;
@@ -108,17 +116,28 @@ pub fn compare_lujvo_pieces(corr: &[String], other: &[String]) -> bool {
 /// [`get_lujvo_from_list`] (if there are issues when re-assembling it) are
 /// forwarded. A [`DecompositionError`] is returned if the lujvo has less than
 /// two words or a problem that is fixable (TODO: list).
-#[allow(clippy::missing_panics_doc)] // .unwrap()
+///
+/// Results can be remembered between calls with [`with_cache`].
+///
+/// [`with_cache`]: crate::cache::with_cache
 pub fn jvokaha(lujvo: &str, settings: &Settings) -> Result<Vec<String>, Jvonunfli> {
+    #[cfg(feature = "std")]
+    return cache::decomposition(lujvo, settings, || decompose(lujvo, settings));
+    #[cfg(not(feature = "std"))]
+    return decompose(lujvo, settings);
+}
+
+#[allow(clippy::missing_panics_doc)] // .unwrap()
+fn decompose(lujvo: &str, settings: &Settings) -> Result<Vec<String>, Jvonunfli> {
     let arr = jvokaha2(lujvo, &extract!(settings; y_hyphens, allow_mz))?;
     let rafsi_tanru = arr.iter().filter(|r| r.len() > 2).map(|r| format!("-{r}-")).collect_vec();
-    if rafsi_tanru.len() == 1 {
+    if rafsi_tanru.len() < 2 {
         // this was a FakeTypeError before but latkerlo made it be a DecompositionError
         // in python
         return Err(DecompositionError("not enough rafsi".to_string()));
     }
     let correct_lujvo = get_lujvo_from_list(&rafsi_tanru, &Settings {
-        generate_cmevla: is_consonant(strin!(&arr[arr.len() - 1], -1)),
+        generate_cmevla: arr.last().is_some_and(|r| is_consonant(letter_back(r, 0))),
         ..extract!(settings; y_hyphens, consonants, glides, allow_mz)
     });
     if let Err(e) = correct_lujvo {
@@ -153,97 +172,92 @@ pub fn jvokaha(lujvo: &str, settings: &Settings) -> Result<Vec<String>, Jvonunfl
 /// can't be a word since you could put a *ua* in front of it to get "another"
 /// lujvo.
 ///
+/// A [`NonLojbanCharacterError`](Jvonunfli::NonLojbanCharacterError) is
+/// returned if the lujvo has anything besides Lojban letters, *y*, and
+/// apostrophes (so it should be normalized first).
+///
 /// A [`DecompositionError`] is returned if something else goes wrong. This is
 /// not user-facing if you are using [`analyze_brivla`] or [`get_veljvo`].
 pub fn jvokaha2(lujvo: &str, settings: &Settings) -> Result<Vec<String>, Jvonunfli> {
+    check_lojban_characters(lujvo)?;
     let orig = lujvo;
-    let mut lujvo = lujvo;
+    let mut lujvo = Cursor::prefix(lujvo);
     let mut res: Vec<&str> = vec![];
     loop {
         if lujvo.is_empty() {
             return Ok(res.iter().copied().map(String::from).collect_vec());
         }
         if !res.is_empty() && res[res.len() - 1].len() != 1 {
-            if strin!(lujvo, 0) == 'y'
+            if lujvo.at(0) == 'y'
                 || settings.y_hyphens != ForceY
                     && [Cvv, Cvhv].contains(&rafsi_tarmi(res[res.len() - 1]))
-                    && (strsl!(lujvo, 0..2) == "nr"
-                        || strin!(lujvo, 0) == 'r'
-                            && is_consonant(strin!(lujvo, 1))
-                            && strin!(lujvo, 1) != 'r')
+                    && (lujvo.get(0..2) == "nr"
+                        || lujvo.at(0) == 'r' && is_consonant(lujvo.at(1)) && lujvo.at(1) != 'r')
             {
-                res.push(strsl!(lujvo, 0..1));
-                lujvo = strsl!(lujvo, 1..);
+                res.push(lujvo.take(1));
                 continue;
             }
-            if settings.y_hyphens != Standard && strsl!(lujvo, 0..2) == "'y" {
-                res.push(strsl!(lujvo, 0..2));
-                lujvo = strsl!(lujvo, 2..);
+            if settings.y_hyphens != Standard && lujvo.get(0..2) == "'y" {
+                res.push(lujvo.take(2));
                 continue;
             }
         }
-        if rafsi_tarmi(strsl!(lujvo, 0..3)) == Cvv
-            && ["ai", "ei", "oi", "au"].contains(&strsl!(lujvo, 1..3))
+        if rafsi_tarmi(lujvo.get(0..3)) == Cvv
+            && ["ai", "ei", "oi", "au"].contains(&lujvo.get(1..3))
         {
-            res.push(strsl!(lujvo, 0..3));
-            lujvo = strsl!(lujvo, 3..);
+            res.push(lujvo.take(3));
             continue;
         }
-        if rafsi_tarmi(strsl!(lujvo, 0..4)) == Cvhv {
-            res.push(strsl!(lujvo, 0..4));
-            lujvo = strsl!(lujvo, 4..);
+        if rafsi_tarmi(lujvo.get(0..4)) == Cvhv {
+            res.push(lujvo.take(4));
             continue;
         }
-        if [Cvcc, Ccvc].contains(&rafsi_tarmi(strsl!(lujvo, 0..4))) {
-            if is_vowel(strin!(lujvo, 1)) {
-                if !if settings.allow_mz { &MZ_VALID } else { &VALID }
-                    .contains(&strsl!(lujvo, 2..4))
-                {
+        if [Cvcc, Ccvc].contains(&rafsi_tarmi(lujvo.get(0..4))) {
+            if is_vowel(lujvo.at(1)) {
+                if !is_valid_cluster(lujvo.get(2..4), settings.allow_mz) {
                     return Err(InvalidClusterError(format!(
                         "{{{orig}}} contains an invalid cluster",
                     )));
                 }
-            } else if !INITIAL.contains(&strsl!(lujvo, 0..2)) {
+            } else if !INITIAL_TABLE.contains(lujvo.get(0..2)) {
                 return Err(InvalidClusterError(format!(
                     "{{{orig}}} starts with an invalid cluster",
                 )));
             }
-            if lujvo.len() == 4 || strin!(lujvo, 4) == 'y' {
-                res.push(strsl!(lujvo, 0..4));
-                if strin!(lujvo, 4) == 'y' {
-                    res.push("y");
+            if lujvo.rest().len() == 4 || lujvo.at(4) == 'y' {
+                res.push(lujvo.take(4));
+                if lujvo.at(0) == 'y' {
+                    res.push(lujvo.take(1));
                 }
-                lujvo = strsl!(lujvo, 5..);
                 continue;
             }
         }
-        if [Cvccv, Ccvcv].contains(&rafsi_tarmi(lujvo)) {
-            res.push(lujvo);
+        if [Cvccv, Ccvcv].contains(&rafsi_tarmi(lujvo.rest())) {
+            res.push(lujvo.rest());
             return Ok(res.iter().copied().map(String::from).collect_vec());
         }
-        if rafsi_tarmi(strsl!(lujvo, 0..3)) == Cvc {
-            if BANNED_TRIPLES.contains(strsl!(lujvo, 2..5)) {
+        if rafsi_tarmi(lujvo.get(0..3)) == Cvc {
+            if BANNED_TRIPLES_TABLE.contains(lujvo.get(2..5)) {
                 return Err(InvalidClusterError(format!(
-                    "{{{lujvo}}} contains a banned triple (nts/ntc/ndz/ndj)"
+                    "{{{}}} contains a banned triple (nts/ntc/ndz/ndj)",
+                    lujvo.rest()
                 )));
             }
-            res.push(strsl!(lujvo, 0..3));
-            lujvo = strsl!(lujvo, 3..);
+            res.push(lujvo.take(3));
             continue;
         }
-        if rafsi_tarmi(strsl!(lujvo, 0..3)) == Ccv {
-            if !INITIAL.contains(&strsl!(lujvo, 0..2)) {
+        if rafsi_tarmi(lujvo.get(0..3)) == Ccv {
+            if !INITIAL_TABLE.contains(lujvo.get(0..2)) {
                 return Err(InvalidClusterError(format!(
                     "{{{orig}}} starts with an invalid cluster",
                 )));
             }
-            if lujvo == orig && strsl!(lujvo, 3..5) == "'y" {
+            if lujvo.pos() == 0 && lujvo.get(3..5) == "'y" {
                 return Err(NotBrivlaError(format!(
                     "{{{orig}}} starts with CCV'y, making it a slinku'i"
                 )));
             }
-            res.push(strsl!(lujvo, 0..3));
-            lujvo = strsl!(lujvo, 3..);
+            res.push(lujvo.take(3));
             continue;
         }
         return Err(DecompositionError(format!("{{{orig}}} can't be decomposed")));
@@ -261,7 +275,7 @@ pub fn score_lujvo(lujvo: &str, settings: &Settings) -> Result<i32, Jvonunfli> {
         .map(|r| {
             if ["y", "n", "r", ""].contains(&r.as_str()) { 1100 * r.len() as i32 } else { score(r) }
         })
-        .sum::<i32>()
+        .fold(0, i32::saturating_add)
         - tiebreak(lujvo))
 }
 
@@ -0,0 +1,245 @@
+//! Functions for converting between the standard Latin alphabet and other
+//! ways of writing Lojban.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    exceptions::Jvonunfli::{self, NonLojbanCharacterError},
+    tarmi::{is_glide, is_vowel, split_vowel_cluster},
+};
+
+/// The Lojban Cyrillic alphabet and what each letter becomes.
+pub const CYRILLIC: [(char, char); 27] = [
+    ('а', 'a'),
+    ('б', 'b'),
+    ('ш', 'c'),
+    ('д', 'd'),
+    ('е', 'e'),
+    ('э', 'e'),
+    ('ф', 'f'),
+    ('г', 'g'),
+    ('и', 'i'),
+    ('й', 'i'),
+    ('ж', 'j'),
+    ('к', 'k'),
+    ('л', 'l'),
+    ('м', 'm'),
+    ('н', 'n'),
+    ('о', 'o'),
+    ('п', 'p'),
+    ('р', 'r'),
+    ('с', 's'),
+    ('т', 't'),
+    ('у', 'u'),
+    ('ў', 'u'),
+    ('в', 'v'),
+    ('х', 'x'),
+    ('ы', 'y'),
+    ('з', 'z'),
+    ('ъ', '\''),
+];
+
+/// Characters that are sometimes typed instead of an apostrophe.
+pub const APOSTROPHES: [char; 5] = ['’', 'ʼ', '‘', '′', '`'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+/// Which conversions [`transliterate`] does. All are on by default.
+pub struct LerfuSettings {
+    /// Whether Lojban Cyrillic letters are converted.
+    pub cyrillic: bool,
+    /// Whether uppercase letters in words that aren't all uppercase are
+    /// recorded as irregular stress.
+    pub stress_marks: bool,
+    /// Whether *ĭ*, *ŭ*, *й*, and *ў* (marked glides) are converted.
+    pub glide_marks: bool,
+    /// Whether typographic apostrophes ([`APOSTROPHES`]) are converted.
+    pub apostrophes: bool,
+}
+
+impl Default for LerfuSettings {
+    fn default() -> Self {
+        Self { cyrillic: true, stress_marks: true, glide_marks: true, apostrophes: true }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Something [`transliterate`] changed. The position is the index of the
+/// changed character in the output.
+pub enum Change {
+    /// A Cyrillic letter was replaced.
+    Cyrillic(usize, char),
+    /// An uppercase letter was lowercased, marking irregular stress.
+    Stress(usize),
+    /// A marked glide was replaced.
+    Glide(usize, char),
+    /// A typographic apostrophe was replaced.
+    Apostrophe(usize, char),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// The result of [`transliterate`].
+pub struct Transliteration {
+    pub text: String,
+    pub changes: Vec<Change>,
+}
+
+impl Transliteration {
+    /// Gets the positions of letters marked as stressed.
+    #[must_use]
+    pub fn stress(&self) -> Vec<usize> {
+        self.changes
+            .iter()
+            .filter_map(|c| if let Change::Stress(i) = c { Some(*i) } else { None })
+            .collect()
+    }
+}
+
+/// Converts text to lowercase Latin-alphabet Lojban, keeping track of what was
+/// changed. Characters that aren't affected by `settings` are only lowercased,
+/// so the output may still contain non-Lojban characters.
+#[must_use]
+pub fn transliterate(text: &str, settings: &LerfuSettings) -> Transliteration {
+    let mut res = Transliteration::default();
+    let mut pos = 0;
+    for word in text.split_inclusive(char::is_whitespace) {
+        let all_upper = !word.chars().any(char::is_lowercase);
+        for c in word.chars() {
+            if settings.stress_marks && !all_upper && c.is_uppercase() {
+                res.changes.push(Change::Stress(pos));
+            }
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            if settings.glide_marks && "ĭŭйў".contains(lower) {
+                res.changes.push(Change::Glide(pos, c));
+                res.text.push(if "ĭй".contains(lower) { 'i' } else { 'u' });
+            } else if settings.cyrillic
+                && let Some((_, latin)) = CYRILLIC.iter().find(|(cyr, _)| *cyr == lower)
+            {
+                res.changes.push(Change::Cyrillic(pos, c));
+                res.text.push(*latin);
+            } else if settings.apostrophes && APOSTROPHES.contains(&c) {
+                res.changes.push(Change::Apostrophe(pos, c));
+                res.text.push('\'');
+            } else {
+                res.text.extend(c.to_lowercase());
+                pos += c.to_lowercase().count() - 1;
+            }
+            pos += 1;
+        }
+    }
+    res
+}
+
+/// Zbalermorna consonants and punctuation as encoded in the UCSUR (U+ED80 to
+/// U+EDBF).
+pub const ZBALERMORNA_CONSONANTS: [(char, char); 22] = [
+    ('p', '\u{ed80}'),
+    ('t', '\u{ed81}'),
+    ('k', '\u{ed82}'),
+    ('f', '\u{ed83}'),
+    ('l', '\u{ed84}'),
+    ('s', '\u{ed85}'),
+    ('c', '\u{ed86}'),
+    ('m', '\u{ed87}'),
+    ('x', '\u{ed88}'),
+    ('.', '\u{ed89}'),
+    ('\'', '\u{ed8a}'),
+    ('ĭ', '\u{ed8b}'),
+    ('ŭ', '\u{ed8c}'),
+    ('b', '\u{ed90}'),
+    ('d', '\u{ed91}'),
+    ('g', '\u{ed92}'),
+    ('v', '\u{ed93}'),
+    ('r', '\u{ed94}'),
+    ('z', '\u{ed95}'),
+    ('j', '\u{ed96}'),
+    ('n', '\u{ed97}'),
+    (',', '\u{ed99}'),
+];
+
+/// Zbalermorna vowels, as `(vowel, attached form, full-size form)`. Attached
+/// vowels go on the preceding consonant; full-size vowels stand alone.
+pub const ZBALERMORNA_VOWELS: [(&str, char, char); 10] = [
+    ("a", '\u{eda1}', '\u{edb1}'),
+    ("e", '\u{eda2}', '\u{edb2}'),
+    ("i", '\u{eda3}', '\u{edb3}'),
+    ("o", '\u{eda4}', '\u{edb4}'),
+    ("u", '\u{eda5}', '\u{edb5}'),
+    ("y", '\u{eda6}', '\u{edb6}'),
+    ("ai", '\u{eda8}', '\u{edb8}'),
+    ("ei", '\u{eda9}', '\u{edb9}'),
+    ("oi", '\u{edaa}', '\u{edba}'),
+    ("au", '\u{edab}', '\u{edbb}'),
+];
+
+/// Writes text in zbalermorna. Vowels and diphthongs (found with
+/// [`split_vowel_cluster`]) attach to the preceding consonant, apostrophe, or
+/// glide; a vowel at the start of a word attaches to a period, and any other
+/// vowel is full-size. Whitespace is kept.
+/// # Errors
+/// A [`NonLojbanCharacterError`] is returned if the text contains anything
+/// other than Lojban letters, periods, commas, and whitespace.
+///
+/// Errors from `split_vowel_cluster` are forwarded.
+pub fn to_zbalermorna(text: &str) -> Result<String, Jvonunfli> {
+    let consonant = |c| ZBALERMORNA_CONSONANTS.iter().find(|(l, _)| *l == c).map(|(_, z)| *z);
+    let vowel = |v: &str, attached: bool| {
+        ZBALERMORNA_VOWELS
+            .iter()
+            .find(|(l, ..)| *l == v)
+            .map(|(_, a, f)| if attached { *a } else { *f })
+    };
+    let text = transliterate(text, &LerfuSettings::default()).text.replace('h', "'");
+    let mut res = String::new();
+    for word in text.split_inclusive(char::is_whitespace) {
+        let chars = word.chars().collect::<Vec<_>>();
+        // whether the last letter can have a vowel attached to it
+        let mut carrier = false;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if !is_vowel(c) && c != 'y' {
+                if c.is_whitespace() {
+                    res.push(c);
+                } else if let Some(z) = consonant(c) {
+                    res.push(z);
+                } else {
+                    return Err(NonLojbanCharacterError(format!(
+                        "{{{}}} contains a non-lojban character",
+                        word.trim_end()
+                    )));
+                }
+                carrier = c != ',';
+                i += 1;
+                continue;
+            }
+            let run = if c == 'y' {
+                "y".to_string()
+            } else {
+                chars[i..].iter().take_while(|c| is_vowel(**c)).collect::<String>()
+            };
+            let pieces = if c == 'y' { vec![run.clone()] } else { split_vowel_cluster(&run)? };
+            if i == 0 && !is_glide(&pieces[0]) {
+                res.push('\u{ed89}');
+                carrier = true;
+            }
+            for piece in &pieces {
+                let mut piece = piece.as_str();
+                if is_glide(piece) {
+                    res.extend(consonant(if piece.starts_with('i') { 'ĭ' } else { 'ŭ' }));
+                    piece = &piece[1..];
+                    carrier = true;
+                }
+                res.extend(vowel(piece, carrier));
+                carrier = false;
+            }
+            i += run.len();
+        }
+    }
+    Ok(res)
+}
@@ -27,21 +27,71 @@
 #![allow(clippy::cast_lossless)]
 #![allow(clippy::cast_sign_loss)]
 #![allow(clippy::reversed_empty_ranges)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+/// Records a step, formatted like [`format!`], if a trace is being
+/// collected.
+#[cfg(feature = "std")]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::is_tracing() {
+            $crate::trace::record(format!($($arg)*));
+        }
+    };
+}
+
+/// Without `std` there is nowhere to record a trace, so this does nothing,
+/// but the message still has to compile.
+#[cfg(not(feature = "std"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+pub mod bulk;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
 pub mod cli_docs;
 pub mod data;
 pub mod exceptions;
 pub mod jvozba;
 pub mod katna;
+pub mod lerfu;
+#[cfg(feature = "std")]
+pub mod lint;
+#[cfg(feature = "std")]
+pub mod matrix;
 pub mod rafsi;
+#[cfg(feature = "std")]
+pub mod render;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "serde")]
+pub mod rpc;
+pub mod sance;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod simsa;
 pub mod tarmi;
+#[cfg(feature = "std")]
 mod test_list;
 pub mod tools;
+#[cfg(feature = "std")]
+pub mod trace;
 
 pub use exceptions::Jvonunfli;
 pub use jvozba::{get_lujvo, get_lujvo_with_analytics, grll};
 pub use katna::{get_veljvo, score_lujvo};
+#[cfg(feature = "std")]
 pub use rafsi::RAFSI;
+pub use sance::syllabify;
 pub use tarmi::{
     ConsonantSetting::{self, *},
     SETTINGS_ITERATOR, Settings,
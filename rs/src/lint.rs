@@ -0,0 +1,158 @@
+//! Finding problems with the words in a Lojban text, for editors. The
+//! `jvotci-lsp` binary (with the `lsp` feature) is a language server built on
+//! this.
+//! ```
+//! use latkerlo_jvotci::{Settings, lint::{LintKind, lint}};
+//!
+//! let lints = lint("mi nelci lo bangygirzu", &Settings::default());
+//! assert_eq!(lints[0].kind, LintKind::SuboptimalLujvo);
+//! assert_eq!(lints[0].replacement.as_deref(), Some("baugri"));
+//! ```
+
+use crate::{
+    exceptions::Jvonunfli::{self, DecompositionError},
+    katna::jvokaha,
+    render::{Format, render_report},
+    report::describe_word,
+    tarmi::{
+        BrivlaType::{Cmevla, ExtendedLujvo, Lujvo},
+        Settings, check_lojban_characters, is_consonant, split_cmavo_compound,
+    },
+    tools::{analyze_brivla, normalize},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A word in a text.
+pub struct Token {
+    /// The byte offset of the start of the word.
+    pub start: usize,
+    /// The byte offset just after the word.
+    pub end: usize,
+    pub text: String,
+}
+
+/// Returns `true` for characters that can be part of a word. Periods are
+/// pauses, so they separate words.
+fn is_word_char(c: char) -> bool { c.is_alphanumeric() || "'’‘ʼ,".contains(c) }
+
+/// Splits a text into words at whitespace, periods, and other punctuation.
+/// Commas are kept inside words but not at their edges.
+#[must_use]
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, is_word_char(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                let word = &text[s..i];
+                let trimmed = word.trim_start_matches(',');
+                let s = s + word.len() - trimmed.len();
+                let trimmed = trimmed.trim_end_matches(',');
+                if !trimmed.is_empty() {
+                    tokens.push(Token { start: s, end: s + trimmed.len(), text: trimmed.into() });
+                }
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What's wrong with a word.
+pub enum LintKind {
+    /// The word looks like a brivla but [`analyze_brivla`] rejects it.
+    InvalidBrivla,
+    /// The word is a lujvo with wrong hyphens, as found by [`jvokaha`].
+    MalformedLujvo,
+    /// The word is a lujvo, but there is a better one with the same tanru.
+    SuboptimalLujvo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A problem with a word in a text.
+pub struct Lint {
+    pub token: Token,
+    pub kind: LintKind,
+    pub message: String,
+    /// What the word should be replaced with, if anything.
+    pub replacement: Option<String>,
+}
+
+/// Gets the lujvo that a "malformed" error from [`jvokaha`] says the word
+/// should be.
+fn malformed_correction(error: &Jvonunfli) -> Option<String> {
+    let DecompositionError(message) = error else {
+        return None;
+    };
+    let (_, correct) = message.split_once("} is malformed and should be {")?;
+    correct.strip_suffix('}').map(ToString::to_string)
+}
+
+/// Checks one word. Cmavo, cmevla, and words that aren't Lojban are never
+/// problems.
+#[must_use]
+pub fn lint_word(word: &str, settings: &Settings) -> Option<(LintKind, String, Option<String>)> {
+    let word = normalize(word);
+    if check_lojban_characters(&word).is_err() || split_cmavo_compound(&word).is_ok() {
+        return None;
+    }
+    let analysis = analyze_brivla(&word, settings);
+    // only lujvo, and words that failed because of their hyphens, can be
+    // malformed lujvo
+    let maybe_lujvo = match &analysis {
+        Ok((b_type, _)) => [Lujvo, ExtendedLujvo, Cmevla].contains(b_type),
+        Err(_) => true,
+    };
+    if maybe_lujvo
+        && let Err(e) = jvokaha(&word, settings)
+        && let Some(correct) = malformed_correction(&e)
+    {
+        return Some((LintKind::MalformedLujvo, e.to_string(), Some(correct)));
+    }
+    match analysis {
+        // anything ending in a consonant is a cmevla
+        Err(_) if word.chars().last().is_some_and(is_consonant) => None,
+        Err(e) => Some((LintKind::InvalidBrivla, e.to_string(), None)),
+        Ok((b_type, _)) if [Lujvo, ExtendedLujvo, Cmevla].contains(&b_type) => {
+            let report = describe_word(&word, settings);
+            let (best, score) = (report.best?, report.score?);
+            (best.lujvo != word && best.score < score).then(|| {
+                let message =
+                    format!("{{{word}}} ({score}) could be {{{}}} ({})", best.lujvo, best.score);
+                (LintKind::SuboptimalLujvo, message, Some(best.lujvo))
+            })
+        }
+        Ok(_) => None,
+    }
+}
+
+/// Checks every word in a text.
+#[must_use]
+pub fn lint(text: &str, settings: &Settings) -> Vec<Lint> {
+    tokenize(text)
+        .into_iter()
+        .filter_map(|token| {
+            let (kind, message, replacement) = lint_word(&token.text, settings)?;
+            Some(Lint { token, kind, message, replacement })
+        })
+        .collect()
+}
+
+/// Describes a word as Markdown, like the CLI does: its type, rafsi, score,
+/// source words, and best lujvo, or the word it's a rafsi of. Returns `None`
+/// if there's nothing to say.
+#[must_use]
+pub fn hover(word: &str, settings: &Settings) -> Option<String> {
+    let mut report = describe_word(word, settings);
+    if report.brivla_type.is_none() {
+        // e.g. a cmavo that is a rafsi, which isn't wrong here
+        report.error = None;
+    }
+    let has_rafsi =
+        report.rafsi.as_ref().is_some_and(|r| r.selrafsi.is_some() || !r.rafsi.is_empty());
+    (report.brivla_type.is_some() || has_rafsi)
+        .then(|| render_report(&report, Format::Markdown.renderer().as_ref()))
+}
@@ -1,204 +1,739 @@
 use std::{
+    borrow::Cow,
     env,
-    io::{Write as _, stdin, stdout},
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write as _, stdin, stdout},
+    path::PathBuf,
     process::exit,
+    sync::OnceLock,
 };
 
 use itertools::Itertools as _;
+#[cfg(feature = "server")]
+use latkerlo_jvotci::server::serve;
 use latkerlo_jvotci::{
     RAFSI, Settings, analyze_brivla,
-    cli_docs::{BOLD, CLI_INSTRUCTIONS, CYAN, GREEN, PINK, RED, RESET, TUI_INSTRUCTIONS},
-    data::HYPHENS,
-    get_lujvo, get_lujvo_with_analytics, get_veljvo,
-    katna::{search_selrafsi_from_rafsi, selrafsi_list_from_rafsi_list},
-    normalize, score_lujvo,
+    cache::{Cache, with_cache},
+    cli_docs::{CLI_INSTRUCTIONS, TUI_INSTRUCTIONS},
+    get_veljvo,
+    matrix::{minimal_settings, settings_matrix},
+    normalize,
+    render::{Format, Renderer, Tag, render_report, strip_ansi},
+    report::{Action, Report, describe, describe_tanru, describe_word, lookup_rafsi},
+    rpc, score_lujvo,
+    tarmi::{BrivlaType::*, ConsonantSetting, YHyphenSetting},
+    trace::with_trace,
 };
+use rustyline::{
+    Context, Editor, Helper,
+    completion::Completer,
+    error::ReadlineError,
+    highlight::{CmdKind, Highlighter},
+    hint::Hinter,
+    history::FileHistory,
+    validate::Validator,
+};
+use serde_json::json;
+
+/// A report from `--batch --json`, with where it came from.
+#[derive(serde::Serialize)]
+struct BatchLine<'a> {
+    line: usize,
+    id: Option<&'a str>,
+    #[serde(flatten)]
+    report: &'a Report,
+}
+
+/// Processes one input per line from a file (or stdin if there is no file or
+/// it is `-`). Lines may start with an ID and a tab. Blank lines and lines
+/// starting with `#` are skipped. Returns the exit code: 0 if everything
+/// worked, 1 if any line had an error, and 2 if the input couldn't be read.
+fn run_batch(files: &[&String], settings: &Settings, json: bool) -> i32 {
+    if files.len() > 1 {
+        eprintln!("{}", span(Tag::Error, "--batch takes at most one file"));
+        return 2;
+    }
+    let reader: Box<dyn BufRead> = match files.first().map(|f| f.as_str()) {
+        None | Some("-") => Box::new(stdin().lock()),
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("{}", span(Tag::Error, &format!("couldn't open {{{path}}}: {e}")));
+                return 2;
+            }
+        },
+    };
+    let mut out = BufWriter::new(stdout().lock());
+    if !json {
+        writeln!(out, "# line\tid\tinput\tkind\tresult\tscore").unwrap();
+    }
+    let (mut total, mut errors) = (0, 0);
+    // dictionaries repeat a lot of the same pieces
+    let mut cache = Cache::default();
+    for (n, line) in reader.lines().enumerate() {
+        let n = n + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("{}", span(Tag::Error, &format!("couldn't read line {n}: {e}")));
+                return 2;
+            }
+        };
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (id, input) = line.split_once('\t').unwrap_or(("", line));
+        let report = with_cache(&mut cache, || describe(input, settings));
+        total += 1;
+        if report.is_err() {
+            errors += 1;
+        }
+        if json {
+            let id = Some(id).filter(|id| !id.is_empty());
+            let value = serde_json::to_string(&BatchLine { line: n, id, report: &report }).unwrap();
+            writeln!(out, "{value}").unwrap();
+            continue;
+        }
+        let (kind, result, score) = if let Some(e) = &report.error {
+            (e.kind().to_string(), e.to_string(), None)
+        } else if let Some(best) = report.best.as_ref().filter(|_| report.action == Action::Zba) {
+            ("lujvo".to_string(), best.lujvo.clone(), Some(best.score))
+        } else {
+            (
+                serde_json::to_value(report.brivla_type)
+                    .unwrap()
+                    .as_str()
+                    .unwrap_or("")
+                    .to_string(),
+                report.selrafsi.join(" "),
+                report.score,
+            )
+        };
+        writeln!(
+            out,
+            "{n}\t{id}\t{}\t{kind}\t{result}\t{}",
+            report.input,
+            score.map(|s| s.to_string()).unwrap_or_default()
+        )
+        .unwrap();
+    }
+    out.flush().unwrap();
+    let tag = if errors == 0 { Tag::Result } else { Tag::Error };
+    eprintln!("{}", span(tag, &format!("{total} processed, {errors} with errors")));
+    i32::from(errors > 0)
+}
+
+/// The CLI's subcommands. Without one, a single word is analyzed and several
+/// words are made into a lujvo.
+const COMMANDS: [&str; 10] =
+    ["zba", "katna", "score", "rafsi", "check", "settings", "matrix", "minimal", "serve", "rpc"];
+
+/// The subcommands that don't take any input.
+const INPUTLESS_COMMANDS: [&str; 3] = ["settings", "serve", "rpc"];
+
+/// The error for `--port` without a port number.
+const PORT_ERROR: &str = "--port must be followed by a port number";
+
+/// Runs the HTTP server on `port` (8080 by default), with `settings` as the
+/// default for requests. Returns the exit code if it stops.
+#[cfg(feature = "server")]
+fn run_serve(port: Option<&str>, settings: &Settings) -> i32 {
+    let Ok(port) = port.unwrap_or("8080").parse::<u16>() else {
+        eprintln!("{}", span(Tag::Error, PORT_ERROR));
+        return 1;
+    };
+    eprintln!("{}", span(Tag::Info, &format!("serving on http://127.0.0.1:{port}")));
+    match serve(port, settings) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", span(Tag::Error, &format!("couldn't serve on port {port}: {e}")));
+            2
+        }
+    }
+}
+
+#[cfg(not(feature = "server"))]
+fn run_serve(_: Option<&str>, _: &Settings) -> i32 {
+    eprintln!("{}", span(Tag::Error, "jvotci was built without the server feature"));
+    1
+}
+
+/// How text output looks, set once from `-o` or by [`Format::for_terminal`].
+static FORMAT: OnceLock<Format> = OnceLock::new();
+
+/// Gets the renderer for [`FORMAT`].
+fn renderer() -> Box<dyn Renderer> { FORMAT.get().copied().unwrap_or(Format::Plain).renderer() }
+
+/// Renders text with a tag in the current format.
+fn span(tag: Tag, text: &str) -> String { renderer().span(tag, text) }
+
+/// Prints lines in the current format.
+fn print_lines(lines: &[String]) {
+    println!("{}", renderer().lines(lines));
+}
+
+/// Prints help text, without colors unless the output is ANSI.
+fn print_help(text: &str) {
+    if FORMAT.get() == Some(&Format::Ansi) {
+        println!("{text}");
+    } else {
+        println!("{}", strip_ansi(text));
+    }
+}
+
+/// Prints what [`lookup_rafsi`] finds. Returns `false` if it found nothing.
+fn print_rafsi(word: &str) -> bool {
+    let word = normalize(word);
+    let lookup = lookup_rafsi(&word);
+    let mut lines = vec![];
+    if let Some(selrafsi) = &lookup.selrafsi {
+        lines.push(span(Tag::Note, &format!("{{{word}}} is a rafsi of {{{selrafsi}}}")));
+    }
+    if !lookup.rafsi.is_empty() {
+        lines
+            .push(span(Tag::Note, &format!("{{{word}}} has rafsi {{{}}}", lookup.rafsi.join(" "))));
+    }
+    if lines.is_empty() {
+        print_lines(&[span(Tag::Error, &format!("{{{word}}} has no rafsi and isn't one"))]);
+        return false;
+    }
+    print_lines(&lines);
+    true
+}
+
+/// Prints the analysis of a word and the best lujvo with the same tanru, and
+/// the word's rafsi if `show_rafsi` is set. Returns `false` if there was an
+/// error, including the word not being a lujvo.
+fn print_katna(word: &str, settings: &Settings, show_rafsi: bool) -> bool {
+    let mut report = describe_word(word, settings);
+    if !show_rafsi {
+        report.rafsi = None;
+    }
+    if report.error.is_none() && !matches!(report.brivla_type, Some(Lujvo | ExtendedLujvo | Cmevla))
+    {
+        report.error = get_veljvo(&report.input, settings).err();
+    }
+    println!("{}", render_report(&report, renderer().as_ref()));
+    if let (Some(best), Some(score)) = (&report.best, report.score)
+        && best.lujvo != normalize(word)
+        && score < best.score
+    {
+        print_lines(&[span(
+            Tag::Error,
+            "hm, the 'best' lujvo actually has a higher score? that can't be good...",
+        )]);
+        exit(1);
+    }
+    !report.is_err()
+}
+
+/// Prints the steps [`analyze_brivla`] takes for a word.
+fn print_trace(word: &str, settings: &Settings) {
+    let (_, trace) = with_trace(|| analyze_brivla(word, settings));
+    print_lines(&trace.to_string().lines().map(|l| span(Tag::Dim, l)).collect_vec());
+}
+
+/// Prints the best lujvo for a tanru. Returns `false` if there was an error.
+fn print_zba(input: &str, settings: &Settings) -> bool {
+    let report = describe_tanru(input, settings);
+    println!("{}", render_report(&report, renderer().as_ref()));
+    !report.is_err()
+}
+
+/// Prints each field of `settings` as the long option that sets it.
+fn print_settings(settings: &Settings) {
+    let y_hyphens = match settings.y_hyphens {
+        YHyphenSetting::Standard => "standard",
+        YHyphenSetting::AllowY => "allow-y",
+        YHyphenSetting::ForceY => "force-y",
+    };
+    let consonants = match settings.consonants {
+        ConsonantSetting::Cluster => "cluster",
+        ConsonantSetting::TwoConsonants => "2",
+        ConsonantSetting::OneConsonant => "1",
+    };
+    let r = renderer();
+    let mut lines = vec![format!(
+        "{} {}",
+        r.span(Tag::Info, "flags:"),
+        r.span(Tag::Strong, &format!("-{settings}"))
+    )];
+    for (name, value) in [
+        ("cmevla", settings.generate_cmevla.to_string().as_str()),
+        ("y-hyphens", y_hyphens),
+        ("consonants", consonants),
+        ("exp-rafsi", settings.exp_rafsi.to_string().as_str()),
+        ("glides", settings.glides.to_string().as_str()),
+        ("allow-mz", settings.allow_mz.to_string().as_str()),
+    ] {
+        lines.push(format!("{}{}", r.span(Tag::Info, &format!("--{name}=")), r.text(value)));
+    }
+    print_lines(&lines);
+}
+
+/// Flag commands offered by tab completion in interactive mode.
+const SLASH_COMMANDS: [&str; 13] =
+    ["/h", "/q", "/settings", "/c", "/r", "/g", "/z", "/S", "/A", "/F", "/C", "/2", "/1"];
+
+/// Completes `/` commands at the start of the line and gismu and cmavo (the
+/// keys of [`RAFSI`]) anywhere else, and shows input in bold.
+struct JvotciHelper;
+
+impl Completer for JvotciHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos].rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let prefix = &line[start..pos];
+        let candidates = if start == 0 && prefix.starts_with('/') {
+            SLASH_COMMANDS
+                .iter()
+                .filter(|c| c.starts_with(prefix))
+                .map(ToString::to_string)
+                .collect()
+        } else if prefix.is_empty() {
+            vec![]
+        } else {
+            RAFSI
+                .keys()
+                .filter(|w| w.starts_with(prefix))
+                .sorted()
+                .map(ToString::to_string)
+                .collect()
+        };
+        Ok((start, candidates))
+    }
+}
+
+impl Highlighter for JvotciHelper {
+    fn highlight<'l>(&self, line: &'l str, _: usize) -> Cow<'l, str> {
+        if FORMAT.get() == Some(&Format::Ansi) {
+            Cow::Owned(span(Tag::Strong, line))
+        } else {
+            Cow::Borrowed(line)
+        }
+    }
+
+    fn highlight_char(&self, _: &str, _: usize, _: CmdKind) -> bool { true }
+}
+
+impl Hinter for JvotciHelper {
+    type Hint = String;
+}
+
+impl Validator for JvotciHelper {}
+
+impl Helper for JvotciHelper {}
+
+/// Where the interactive mode's history is kept: `$JVOTCI_HISTORY`, or
+/// `.jvotci_history` in the home directory.
+fn history_path() -> Option<PathBuf> {
+    env::var_os("JVOTCI_HISTORY")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".jvotci_history")))
+}
+
+/// Opens the line editor for the interactive mode and loads its history.
+/// Exits if there is no terminal to edit lines in.
+fn open_editor(history: Option<&PathBuf>) -> Editor<JvotciHelper, FileHistory> {
+    let mut editor = match Editor::new() {
+        Ok(editor) => editor,
+        Err(e) => {
+            eprintln!("{}", span(Tag::Error, &format!("couldn't open the terminal: {e}")));
+            exit(2);
+        }
+    };
+    editor.set_helper(Some(JvotciHelper));
+    if let Some(path) = history {
+        // there is no history the first time
+        let _ = editor.load_history(path);
+    }
+    editor
+}
+
+/// Runs a subcommand on the input from the command line and returns the exit
+/// code.
+fn run_command(
+    command: &str,
+    input: &[&String],
+    settings: &Settings,
+    json: bool,
+    explain: bool,
+) -> i32 {
+    let words = input.iter().flat_map(|w| w.split_whitespace()).collect_vec();
+    if command == "settings" {
+        if json {
+            println!("{}", serde_json::to_string(settings).unwrap());
+        } else {
+            print_settings(settings);
+        }
+        return 0;
+    }
+    if words.is_empty() {
+        print_lines(&[span(Tag::Error, &format!("{command} needs input, see -h"))]);
+        return 1;
+    }
+    if command == "zba" {
+        let ok = if json {
+            let report = describe_tanru(&words.join(" "), settings);
+            println!("{}", report.to_json());
+            !report.is_err()
+        } else {
+            print_zba(&words.join(" "), settings)
+        };
+        return i32::from(!ok);
+    }
+    if command == "matrix" {
+        let classes = settings_matrix(&words.join(" "));
+        for class in &classes {
+            if json {
+                let flags = class.settings.iter().map(ToString::to_string).collect_vec();
+                println!(
+                    "{}",
+                    json!({ "summary": class.summary(), "flags": flags, "outcome": class.outcome })
+                );
+                continue;
+            }
+            let mut lines = vec![format!(
+                "{} {}",
+                span(Tag::Info, &class.summary()),
+                span(Tag::Dim, &format!("({} settings)", class.settings.len()))
+            )];
+            let outcome = &class.outcome;
+            let indent = renderer().text("    ");
+            if let Some(e) = &outcome.error {
+                lines.push(indent + &span(Tag::Error, &e.to_string()));
+            } else if let Some(b_type) = outcome.brivla_type {
+                let best =
+                    outcome.best.as_ref().map_or_else(String::new, |b| format!(" best: {b}"));
+                lines.push(
+                    indent
+                        + &span(
+                            Tag::Result,
+                            &format!(
+                                "{} {{{}}}{best}",
+                                b_type.to_string().to_lowercase(),
+                                outcome.pieces.join(" ")
+                            ),
+                        ),
+                );
+            } else if let Some(best) = &outcome.best {
+                lines.push(indent + &span(Tag::Result, best));
+            }
+            print_lines(&lines);
+        }
+        // it's only a failure if nothing worked
+        return i32::from(classes.iter().all(|c| c.outcome.error.is_some()));
+    }
+    let mut failed = false;
+    for word in words {
+        let ok = match (command, json) {
+            ("katna", true) => {
+                let report = describe_word(word, settings);
+                println!("{}", report.to_json());
+                !report.is_err()
+            }
+            ("katna", false) => {
+                if explain {
+                    print_trace(word, settings);
+                }
+                print_katna(&normalize(word), settings, false)
+            }
+            ("score", _) => {
+                let word = normalize(word);
+                let score = score_lujvo(&word, settings);
+                if json {
+                    let (score, error) = (score.as_ref().ok(), score.as_ref().err());
+                    println!("{}", json!({ "input": word, "score": score, "error": error }));
+                } else {
+                    print_lines(&[match &score {
+                        Ok(score) => span(Tag::Result, &score.to_string()),
+                        Err(e) => span(Tag::Error, &e.to_string()),
+                    }]);
+                }
+                score.is_ok()
+            }
+            ("rafsi", true) => {
+                let word = normalize(word);
+                let lookup = lookup_rafsi(&word);
+                let ok = lookup.selrafsi.is_some() || !lookup.rafsi.is_empty();
+                println!("{}", json!({ "input": word, "rafsi": lookup }));
+                ok
+            }
+            ("rafsi", false) => print_rafsi(word),
+            ("minimal", _) => {
+                let word = normalize(word);
+                let res = minimal_settings(&word);
+                if json {
+                    let (settings, error) = (res.as_ref().ok(), res.as_ref().err());
+                    println!("{}", json!({ "input": word, "settings": settings, "error": error }));
+                } else {
+                    match &res {
+                        Ok(accepted) => {
+                            for a in accepted {
+                                let needs = match a.deciding_flag {
+                                    None => renderer().text("(the defaults)"),
+                                    Some(deciding) if a.flags.len() == 1 => {
+                                        span(Tag::Note, &format!("needs {deciding}"))
+                                    }
+                                    Some(deciding) => {
+                                        let others =
+                                            a.flags.iter().filter(|f| **f != deciding).join(", ");
+                                        span(Tag::Note, &format!("needs {deciding} (and {others})"))
+                                    }
+                                };
+                                let result = format!(
+                                    "{} {{{}}} {{{}}}",
+                                    a.brivla_type.to_string().to_lowercase(),
+                                    a.pieces.join(" "),
+                                    a.veljvo.join(" ")
+                                );
+                                print_lines(&[
+                                    format!(
+                                        "{} {needs}",
+                                        span(Tag::Info, &format!("-{}", a.settings))
+                                    ),
+                                    renderer().text("    ") + &span(Tag::Result, &result),
+                                ]);
+                            }
+                        }
+                        Err(e) => print_lines(&[span(Tag::Error, &e.to_string())]),
+                    }
+                }
+                res.is_ok()
+            }
+            ("check", _) => {
+                let word = normalize(word);
+                if explain && !json {
+                    print_trace(&word, settings);
+                }
+                let res = analyze_brivla(&word, settings);
+                if json {
+                    let brivla_type = res.as_ref().ok().map(|(b_type, _)| b_type);
+                    let error = res.as_ref().err();
+                    println!(
+                        "{}",
+                        json!({ "input": word, "brivla_type": brivla_type, "error": error })
+                    );
+                } else {
+                    print_lines(&[match &res {
+                        Ok((b_type, _)) => format!(
+                            "{} {}",
+                            span(Tag::Result, &word),
+                            span(Tag::Info, &b_type.to_string().to_lowercase())
+                        ),
+                        Err(e) => span(Tag::Error, &e.to_string()),
+                    }]);
+                }
+                res.is_ok()
+            }
+            _ => unreachable!(),
+        };
+        failed |= !ok;
+    }
+    i32::from(failed)
+}
 
 #[allow(clippy::too_many_lines)]
 fn main() {
-    let mut settings = Settings::default();
     let mut input = String::new();
     // args
     let mut used_cli = false;
-    let args = env::args().skip(1).collect_vec();
+    let mut json = false;
+    let mut format = None;
+    let mut args = env::args().skip(1).collect_vec();
+    // output options can go anywhere
+    let (mut batch, mut explain) = (false, false);
+    let mut port = None;
+    let mut error = None;
+    while let Some(i) = args.iter().position(|a| {
+        ["--json", "-o", "--batch", "--explain", "--port"].contains(&a.as_str())
+            || a.starts_with("--port=")
+    }) {
+        if let Some(p) = args[i].strip_prefix("--port=") {
+            port = Some(p.to_string());
+        } else if args[i] == "--port" {
+            if i + 1 < args.len() {
+                port = Some(args.remove(i + 1));
+            } else {
+                error = Some(PORT_ERROR);
+            }
+        } else if args[i] == "--batch" {
+            batch = true;
+        } else if args[i] == "--explain" {
+            explain = true;
+        } else if args[i] == "-o" {
+            match args.get(i + 1).map(String::as_str) {
+                Some("json") => json = true,
+                Some("text") => (json, format) = (false, None),
+                Some(f) if let Ok(f) = f.parse::<Format>() => (json, format) = (false, Some(f)),
+                _ => {
+                    error =
+                        Some("-o must be followed by json, text, plain, ansi, html, or markdown");
+                }
+            }
+            if i + 1 < args.len() {
+                args.remove(i + 1);
+            }
+        } else {
+            json = true;
+        }
+        args.remove(i);
+    }
+    FORMAT.set(format.unwrap_or_else(Format::for_terminal)).unwrap();
+    if let Some(error) = error {
+        eprintln!("{}", span(Tag::Error, error));
+        exit(1);
+    }
+    // the command is the first thing that isn't a flag. some are also words,
+    // so without any input after them they're analyzed instead (e.g.
+    // `jvotci katna`)
+    let command = args
+        .iter()
+        .position(|a| !a.starts_with('-'))
+        .filter(|&i| {
+            let command = args[i].as_str();
+            COMMANDS.contains(&command)
+                && (INPUTLESS_COMMANDS.contains(&command)
+                    || args[i + 1..].iter().any(|a| !a.starts_with('-')))
+        })
+        .map(|i| args.remove(i));
+    let mut flags = String::new();
+    let mut options = vec![];
     let mut arginput = vec![];
-    for (i, arg) in args.iter().enumerate() {
-        if i == 0 && arg.starts_with('-') {
-            if arg.contains('h') || arg == "--help" {
-                println!("{}", *CLI_INSTRUCTIONS);
+    for arg in &args {
+        if let Some(option) = arg.strip_prefix("--") {
+            if option == "help" {
+                print_help(&CLI_INSTRUCTIONS);
                 return;
             }
-            if arg.contains('-') {
-                // noöp
-            }
-            let flags = arg.chars().filter(|&c| !"h-".contains(c)).collect::<String>();
-            if let Ok(s) = flags.parse() {
-                settings = s;
-            } else {
-                println!("{RED}invalid flags, see {BOLD}-h{RESET}");
-                exit(1);
+            options.push(option.split_once('=').map_or((option, None), |(n, v)| (n, Some(v))));
+        } else if let Some(arg) = arg.strip_prefix('-') {
+            if arg.contains('h') {
+                print_help(&CLI_INSTRUCTIONS);
+                return;
             }
+            flags += arg;
         } else {
-            if arg.starts_with('/') {
-                println!(
-                    "{RED}flags starting with {BOLD}/{RESET}{RED} can only be used in interactive \
-                     mode{RESET}"
-                );
+            if !batch && arg.starts_with('/') {
+                print_lines(&[span(
+                    Tag::Error,
+                    "flags starting with / can only be used in interactive mode",
+                )]);
                 exit(1);
             }
-            if (i == 0 || i == 1 && args[0].starts_with('-')) && args[i..].len() == 1 {
-                let arg = normalize(arg);
-                if let Some(selrafsi) = search_selrafsi_from_rafsi(&arg) {
-                    println!("{PINK}{{{arg}}} is a rafsi of {{{selrafsi}}}{RESET}");
-                }
-                if let Some(rafsi) = RAFSI.iter().find(|(s, _)| *s == &arg).map(|(_, r)| r) {
-                    let rafsi = rafsi.iter().join(" ");
-                    println!("{PINK}{{{arg}}} has rafsi {{{rafsi}}}{RESET}");
-                }
-            }
             arginput.push(arg);
         }
     }
+    let Ok(mut settings) = flags.parse::<Settings>() else {
+        print_lines(&[span(Tag::Error, "invalid flags, see -h")]);
+        exit(1);
+    };
+    for (name, value) in options {
+        if settings.apply_option(name, value).is_none() {
+            print_lines(&[span(Tag::Error, &format!("invalid option --{name}, see -h"))]);
+            exit(1);
+        }
+    }
+    if command.as_deref() == Some("serve") {
+        exit(run_serve(port.as_deref(), &settings));
+    }
+    if command.as_deref() == Some("rpc") {
+        // only fails if stdin or stdout is closed
+        let res = rpc::run(stdin().lock(), stdout().lock(), &settings);
+        exit(i32::from(res.is_err()));
+    }
+    if batch {
+        exit(run_batch(&arginput, &settings, json));
+    }
+    if let Some(command) = command {
+        exit(run_command(&command, &arginput, &settings, json, explain));
+    }
     if !arginput.is_empty() {
         input = arginput.clone().into_iter().join(" ");
         used_cli = true;
     }
-    // interactive
+    // interactive, or one JSON object for each line of stdin
+    let history = history_path();
+    let mut editor = (!used_cli && !json).then(|| open_editor(history.as_ref()));
+    // only used for the exit code in JSON mode
+    let mut failed = false;
     loop {
-        if !used_cli {
+        if !used_cli && json {
             input.clear();
-            print!(
-                "\n{CYAN}{settings}{RESET}> {BOLD}{}",
-                if used_cli { input.clone() + "\n" } else { String::new() }
-            );
-            stdout().flush().unwrap();
-            stdin().read_line(&mut input).expect("failed to read stdin");
+            match stdin().read_line(&mut input) {
+                Ok(0) => exit(i32::from(failed)),
+                Ok(_) => {}
+                Err(e) => {
+                    eprintln!("{}", span(Tag::Error, &format!("couldn't read stdin: {e}")));
+                    exit(2);
+                }
+            }
+        } else if let Some(editor) = &mut editor {
+            println!();
+            match editor.readline(&format!("{}> ", span(Tag::Info, &settings.to_string()))) {
+                Ok(line) => input = line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => return,
+                Err(e) => {
+                    eprintln!("{}", span(Tag::Error, &format!("couldn't read input: {e}")));
+                    exit(2);
+                }
+            }
+            if !input.trim().is_empty() {
+                if let Err(e) = editor.add_history_entry(input.trim()) {
+                    eprintln!("{}", span(Tag::Error, &format!("couldn't add to history: {e}")));
+                    exit(2);
+                }
+                if let Some(path) = &history {
+                    // not being able to save history shouldn't stop anything
+                    let _ = editor.save_history(path);
+                }
+            }
         }
-        print!("{RESET}");
-        stdout().flush().unwrap();
         input = input.trim().to_string();
+        if json && !used_cli && input.is_empty() {
+            continue;
+        }
         let lanli = input.split_whitespace().count() == 1;
         if let Some(arg) = input.strip_prefix('/') {
+            if arg == "settings" {
+                print_settings(&settings);
+                continue;
+            }
             if arg.contains('q') {
-                return;
+                exit(i32::from(failed));
             }
             if arg.contains('h') {
-                println!("{}", *TUI_INSTRUCTIONS);
+                print_help(&TUI_INSTRUCTIONS);
                 continue;
             }
             if settings.apply_flags(arg).is_none() {
-                println!("{RED}invalid flags, see {BOLD}/h{RESET}");
+                print_lines(&[span(Tag::Error, "invalid flags, see /h")]);
             };
             continue;
         }
-        if lanli {
-            let input = normalize(&input);
-            if let Some(selrafsi) = search_selrafsi_from_rafsi(&input) {
-                println!("{PINK}{{{input}}} is a rafsi of {{{selrafsi}}}{RESET}");
-            }
-            if let Some(rafsi) = RAFSI.iter().find(|(s, _)| *s == &input).map(|(_, r)| r) {
-                let rafsi = rafsi.iter().join(" ");
-                println!("{PINK}{{{input}}} has rafsi {{{rafsi}}}{RESET}");
-            }
-            let res = analyze_brivla(&input, &settings);
-            if let Err(e) = res {
-                println!("{RED}{e}{RESET}");
-                if used_cli {
-                    exit(1);
-                }
-                continue;
-            }
-            let (analysis, hyphens) = res.unwrap();
-            println!(
-                "{CYAN}{}\n{}{}{GREEN}{}{RESET}",
-                analysis.to_string().to_lowercase().replace("dl", "d l"),
-                if hyphens.join(" ") == input { String::new() } else { hyphens.join(" ") + "\n" },
-                score_lujvo(&input, &settings)
-                    .map_or_else(|_| String::new(), |score| score.to_string() + "\n"),
-                selrafsi_list_from_rafsi_list(&hyphens, &settings).unwrap().into_iter().join(" ")
-            );
-            let veljvo = get_veljvo(&input, &settings);
-            if let Err(e) = veljvo {
-                println!("{RED}{e}{RESET}");
-                if used_cli {
-                    exit(1);
-                }
-                continue;
-            }
-            let veljvo = veljvo.unwrap().join(" ");
-            let best = get_lujvo_with_analytics(&veljvo, &settings);
-            if let Ok((best_lujvo, best_score, _)) = best
-                && normalize(&input) != best_lujvo
-            {
-                let best_hyphens =
-                    analyze_brivla(&best_lujvo, &settings).map(|(_, h)| h).unwrap_or_default();
-                let input_hyphens = hyphens.clone();
-                print!("{CYAN}best: {GREEN}");
-                let mut m = 0;
-                let mut b = 0;
-                let mut diverged = false;
-                while m < input_hyphens.len() && b < best_hyphens.len() {
-                    let mabla_curr = input_hyphens.get(m).map_or("", String::as_str);
-                    let best_curr = best_hyphens.get(b).map_or("", String::as_str);
-                    if HYPHENS.contains(&mabla_curr) {
-                        if !HYPHENS.contains(&best_curr) {
-                            if best_curr == input_hyphens.get(m + 1).map_or("", String::as_str)
-                                && !diverged
-                            {
-                                print!("{RED}-{GREEN}");
-                            }
-                            m += 1;
-                            continue;
-                        }
-                        if mabla_curr == best_curr {
-                            print!("{best_curr}");
-                            m += 1;
-                            b += 1;
-                            continue;
-                        }
-                    } else if HYPHENS.contains(&best_curr) {
-                        print!("{RED}{best_curr}{GREEN}");
-                        b += 1;
-                        diverged = true;
-                        continue;
-                    }
-                    if mabla_curr == best_curr {
-                        print!("{best_curr}");
-                        diverged = false;
-                    } else {
-                        print!("{RED}{best_curr}{GREEN}");
-                        diverged = true;
-                    }
-                    m += 1;
-                    b += 1;
-                }
-                while b < best_hyphens.len() {
-                    print!("{}", best_hyphens[b]);
-                    b += 1;
-                }
-                println!("{RESET} {CYAN}({best_score}){RESET}");
-                if score_lujvo(&input, &settings).unwrap() < best_score {
-                    println!(
-                        "{RED}hm, the 'best' lujvo actually has a higher score? that can't be \
-                         good...{RESET}"
-                    );
-                    exit(1);
-                }
+        let ok = if json {
+            let report = describe(&input, &settings);
+            println!("{}", report.to_json());
+            !report.is_err()
+        } else if lanli {
+            if explain {
+                print_trace(&input, &settings);
             }
+            print_katna(&normalize(&input), &settings, true)
         } else {
-            let res = get_lujvo(&input, &settings);
-            println!(
-                "{}{}{RESET}",
-                if res.is_err() { RED } else { GREEN },
-                res.clone().unwrap_or_else(|e| e.to_string())
-            );
-            if res.is_err() && used_cli {
-                exit(1);
-            }
-        }
-        if used_cli && !arginput.is_empty() {
-            return;
+            print_zba(&input, &settings)
+        };
+        if used_cli {
+            exit(i32::from(!ok));
         }
-        used_cli = false;
+        failed |= json && !ok;
     }
 }
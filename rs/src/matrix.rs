@@ -0,0 +1,262 @@
+//! Functions for comparing what happens to an input under every [`Settings`],
+//! and for finding the settings that make a word valid.
+
+use std::fmt;
+
+use itertools::Itertools as _;
+
+use crate::{
+    exceptions::Jvonunfli,
+    katna::get_veljvo,
+    report::describe,
+    tarmi::{BrivlaType, ConsonantSetting, SETTINGS_ITERATOR, Settings, YHyphenSetting},
+    tools::analyze_brivla,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// The parts of a [`Report`](crate::report::Report) that can differ between
+/// settings.
+pub struct Outcome {
+    pub brivla_type: Option<BrivlaType>,
+    /// Rafsi and hyphens, for a single word.
+    pub pieces: Vec<String>,
+    /// The best lujvo for the input tanru, or for the analyzed lujvo's tanru.
+    pub best: Option<String>,
+    pub error: Option<Jvonunfli>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A group of settings that give the same [`Outcome`].
+pub struct SettingsClass {
+    pub outcome: Outcome,
+    /// In the same order as [`SETTINGS_ITERATOR`].
+    pub settings: Vec<Settings>,
+}
+
+impl SettingsClass {
+    /// Describes `self.settings`; see [`summarize_settings`].
+    #[must_use]
+    pub fn summary(&self) -> String { summarize_settings(&self.settings) }
+}
+
+/// The flags for each possible value of a field of [`Settings`], and a
+/// function getting the flag for the field's value.
+type Field = (&'static [&'static str], fn(&Settings) -> &'static str);
+
+/// Each field of a [`Settings`], with `!c` etc. meaning a toggle is off.
+const FIELDS: [Field; 6] = [
+    (&["!c", "c"], |s| if s.generate_cmevla { "c" } else { "!c" }),
+    (&["S", "A", "F"], |s| match s.y_hyphens {
+        YHyphenSetting::Standard => "S",
+        YHyphenSetting::AllowY => "A",
+        YHyphenSetting::ForceY => "F",
+    }),
+    (&["C", "2", "1"], |s| match s.consonants {
+        ConsonantSetting::Cluster => "C",
+        ConsonantSetting::TwoConsonants => "2",
+        ConsonantSetting::OneConsonant => "1",
+    }),
+    (&["!r", "r"], |s| if s.exp_rafsi { "r" } else { "!r" }),
+    (&["!g", "g"], |s| if s.glides { "g" } else { "!g" }),
+    (&["!z", "z"], |s| if s.allow_mz { "z" } else { "!z" }),
+];
+
+/// Describes a list of settings that is every combination of some values for
+/// each field, by listing those values (separated by `|`) for each field that
+/// doesn't allow everything. Returns `None` for other lists.
+fn summarize_product(settings: &[Settings]) -> Option<String> {
+    let values = FIELDS
+        .iter()
+        .map(|(all, get)| {
+            all.iter().filter(|v| settings.iter().any(|s| get(s) == **v)).copied().collect_vec()
+        })
+        .collect_vec();
+    if values.iter().map(Vec::len).product::<usize>()
+        != settings.iter().map(ToString::to_string).unique().count()
+    {
+        return None;
+    }
+    let summary = values
+        .iter()
+        .zip(FIELDS)
+        .filter(|(v, (all, _))| v.len() < all.len())
+        .map(|(v, _)| v.join("|"))
+        .join(" ");
+    Some(if summary.is_empty() { "any".to_string() } else { summary })
+}
+
+/// Describes a list of settings with flags, e.g. `A|F !r` is all settings with
+/// `-A` or `-F` and without `-r`, and `not (A|F r)` is everything else. If
+/// neither works, each settings is listed, e.g. `-A, -Fr`. An empty list is
+/// `none`, and a list of everything is `any`.
+#[must_use]
+pub fn summarize_settings(settings: &[Settings]) -> String {
+    if settings.is_empty() {
+        return "none".to_string();
+    }
+    if let Some(summary) = summarize_product(settings) {
+        return summary;
+    }
+    let others = SETTINGS_ITERATOR.iter().filter(|s| !settings.contains(s)).copied().collect_vec();
+    if let Some(summary) = summarize_product(&others) {
+        return format!("not ({summary})");
+    }
+    settings.iter().map(|s| format!("-{s}")).join(", ")
+}
+
+/// Analyzes the input (if it's one word) or makes a lujvo from it (if it's
+/// several) under every settings in [`SETTINGS_ITERATOR`], and groups the
+/// settings by what happened. Classes are in order of their first settings,
+/// so the default settings are in the first class.
+#[must_use]
+pub fn settings_matrix(input: &str) -> Vec<SettingsClass> {
+    let mut classes: Vec<SettingsClass> = vec![];
+    for settings in SETTINGS_ITERATOR.iter() {
+        let report = describe(input, settings);
+        let outcome = Outcome {
+            brivla_type: report.brivla_type,
+            pieces: report.pieces,
+            best: report.best.map(|b| b.lujvo),
+            error: report.error,
+        };
+        if let Some(class) = classes.iter_mut().find(|c| c.outcome == outcome) {
+            class.settings.push(*settings);
+        } else {
+            classes.push(SettingsClass { outcome, settings: vec![*settings] });
+        }
+    }
+    classes
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
+/// A way a [`Settings`] can differ from the default.
+pub enum Flag {
+    GenerateCmevla,
+    AllowY,
+    ForceY,
+    TwoConsonants,
+    OneConsonant,
+    ExpRafsi,
+    Glides,
+    AllowMz,
+}
+
+impl Flag {
+    /// Every flag, from the one that lets the fewest extra words through to
+    /// the one that lets the most.
+    pub const BY_PERMISSIVENESS: [Flag; 8] = [
+        Flag::AllowMz,
+        Flag::Glides,
+        Flag::TwoConsonants,
+        Flag::AllowY,
+        Flag::ExpRafsi,
+        Flag::OneConsonant,
+        Flag::ForceY,
+        Flag::GenerateCmevla,
+    ];
+
+    /// Where this is in [`BY_PERMISSIVENESS`](Self::BY_PERMISSIVENESS).
+    #[must_use]
+    pub fn permissiveness(self) -> usize {
+        Self::BY_PERMISSIVENESS.iter().position(|f| *f == self).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Flag {
+    /// Shown in snake case, like the [`Settings`] fields.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Flag::GenerateCmevla => "generate_cmevla",
+            Flag::AllowY => "allow_y",
+            Flag::ForceY => "force_y",
+            Flag::TwoConsonants => "two_consonants",
+            Flag::OneConsonant => "one_consonant",
+            Flag::ExpRafsi => "exp_rafsi",
+            Flag::Glides => "glides",
+            Flag::AllowMz => "allow_mz",
+        })
+    }
+}
+
+/// Lists the ways `settings` differs from the default.
+#[must_use]
+pub fn changed_flags(settings: &Settings) -> Vec<Flag> {
+    let mut flags = vec![];
+    if settings.generate_cmevla {
+        flags.push(Flag::GenerateCmevla);
+    }
+    match settings.y_hyphens {
+        YHyphenSetting::Standard => (),
+        YHyphenSetting::AllowY => flags.push(Flag::AllowY),
+        YHyphenSetting::ForceY => flags.push(Flag::ForceY),
+    }
+    match settings.consonants {
+        ConsonantSetting::Cluster => (),
+        ConsonantSetting::TwoConsonants => flags.push(Flag::TwoConsonants),
+        ConsonantSetting::OneConsonant => flags.push(Flag::OneConsonant),
+    }
+    if settings.exp_rafsi {
+        flags.push(Flag::ExpRafsi);
+    }
+    if settings.glides {
+        flags.push(Flag::Glides);
+    }
+    if settings.allow_mz {
+        flags.push(Flag::AllowMz);
+    }
+    flags
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// Settings that accept a word, from [`minimal_settings`].
+pub struct Acceptance {
+    pub settings: Settings,
+    /// What had to change from the default to accept the word.
+    pub flags: Vec<Flag>,
+    /// The most permissive of `flags`, which is the one responsible for the
+    /// word being accepted. `None` for the default settings.
+    pub deciding_flag: Option<Flag>,
+    pub brivla_type: BrivlaType,
+    /// Rafsi and hyphens.
+    pub pieces: Vec<String>,
+    pub veljvo: Vec<String>,
+}
+
+/// Finds the least permissive settings under which [`analyze_brivla`] accepts
+/// the word and [`get_veljvo`] decomposes it: those with the fewest
+/// [`changed_flags`], and of those, the ones whose flags come earliest in
+/// [`Flag::BY_PERMISSIVENESS`] in total. Several settings are returned if
+/// they're still tied.
+/// # Errors
+/// If no settings work, the error from the default settings is returned.
+pub fn minimal_settings(word: &str) -> Result<Vec<Acceptance>, Jvonunfli> {
+    let accepted = SETTINGS_ITERATOR
+        .iter()
+        .filter_map(|settings| {
+            let (brivla_type, pieces) = analyze_brivla(word, settings).ok()?;
+            let veljvo = get_veljvo(word, settings).ok()?;
+            let flags = changed_flags(settings);
+            let deciding_flag = flags.iter().copied().max_by_key(|f| f.permissiveness());
+            Some(Acceptance {
+                settings: *settings,
+                flags,
+                deciding_flag,
+                brivla_type,
+                pieces,
+                veljvo,
+            })
+        })
+        .collect_vec();
+    let rank = |a: &Acceptance| {
+        (a.flags.len(), a.flags.iter().map(|f| f.permissiveness()).sum::<usize>())
+    };
+    let Some(least) = accepted.iter().map(rank).min() else {
+        let settings = Settings::default();
+        analyze_brivla(word, &settings)?;
+        return Err(get_veljvo(word, &settings).unwrap_err());
+    };
+    Ok(accepted.into_iter().filter(|a| rank(a) == least).collect())
+}
@@ -1,2028 +1,2049 @@
-//! Contains the static RAFSI, a map from words to their affixes.
+//! Contains [`RAFSI_LIST`], the words that have rafsi and their rafsi, and
+//! the static [`RAFSI`], the same list as a map.
+#[cfg(feature = "std")]
 use std::{collections::HashMap, sync::LazyLock};
-/// Big giant rafsi list.
-pub static RAFSI: LazyLock<HashMap<&'static str, Vec<&'static str>>> = LazyLock::new(|| {
-    HashMap::from([
-        ("babda", vec![]),
-        ("bablo", vec![]),
-        ("backi", vec![]),
-        ("bacru", vec!["ba'u"]),
-        ("badji", vec![]),
-        ("badna", vec![]),
-        ("badri", vec!["dri"]),
-        ("bajra", vec!["baj"]),
-        ("bakfu", vec!["baf"]),
-        ("bakni", vec!["bak"]),
-        ("bakri", vec![]),
-        ("baktu", vec![]),
-        ("balji", vec![]),
-        ("balni", vec![]),
-        ("balre", vec!["ba'e"]),
-        ("balto", vec![]),
-        ("balvi", vec!["bav"]),
-        ("bambu", vec![]),
-        ("bamri", vec![]),
-        ("bancu", vec!["bac"]),
-        ("bandu", vec!["bad"]),
-        ("banfi", vec![]),
-        ("bangu", vec!["ban", "bau"]),
-        ("banli", vec!["bal", "ba'i"]),
-        ("banro", vec!["ba'o"]),
-        ("banxa", vec!["bax"]),
-        ("banzu", vec!["baz"]),
-        ("bapli", vec!["bap", "bai"]),
-        ("barda", vec!["bra"]),
-        ("bargu", vec!["bag"]),
-        ("barja", vec![]),
-        ("barna", vec!["ba'a"]),
-        ("bartu", vec!["bar"]),
-        ("basfa", vec![]),
-        ("baslu", vec![]),
-        ("basna", vec![]),
-        ("basti", vec!["bas"]),
-        ("batci", vec!["bat"]),
-        ("batke", vec![]),
-        ("bavmi", vec![]),
-        ("bavza", vec![]),
-        ("baxso", vec![]),
-        ("bebna", vec!["beb"]),
-        ("bekpi", vec![]),
-        ("belta", vec![]),
-        ("bemro", vec!["bem", "be'o"]),
-        ("bende", vec!["bed", "be'e"]),
-        ("bengo", vec!["beg"]),
-        ("benji", vec!["bej", "be'i"]),
-        ("benre", vec![]),
-        ("benzo", vec![]),
-        ("bergu", vec![]),
-        ("bersa", vec!["bes", "be'a"]),
-        ("berti", vec!["ber"]),
-        ("besna", vec!["ben"]),
-        ("besto", vec![]),
-        ("betfu", vec!["bef", "be'u"]),
-        ("betka", vec![]),
-        ("betri", vec!["bet"]),
-        ("bevri", vec!["bev", "bei"]),
-        ("bi", vec!["biv"]),
-        ("bidju", vec![]),
-        ("bifce", vec!["bic"]),
-        ("bi'i", vec!["biz"]),
-        ("bikla", vec!["bik"]),
-        ("bilbo", vec![]),
-        ("bilga", vec!["big"]),
-        ("bilma", vec!["bi'a"]),
-        ("bilni", vec!["bil"]),
-        ("bindo", vec!["bid"]),
-        ("binma", vec![]),
-        ("binra", vec![]),
-        ("binxo", vec!["bix", "bi'o"]),
-        ("birco", vec![]),
-        ("birje", vec![]),
-        ("birka", vec!["bir"]),
-        ("birti", vec!["bit"]),
-        ("bisli", vec!["bis"]),
-        ("bitco", vec![]),
-        ("bitmu", vec!["bim", "bi'u"]),
-        ("bitni", vec![]),
-        ("blabi", vec!["lab"]),
-        ("blaci", vec![]),
-        ("blanu", vec!["bla"]),
-        ("bliku", vec!["bli"]),
-        ("blina", vec![]),
-        ("blolo", vec![]),
-        ("bloti", vec!["lot", "lo'i", "blo"]),
-        ("bluji", vec![]),
-        ("bo", vec!["bor"]),
-        ("bolci", vec!["bol", "boi"]),
-        ("bongu", vec!["bog", "bo'u"]),
-        ("bonta", vec![]),
-        ("borma", vec![]),
-        ("boske", vec![]),
-        ("botpi", vec!["bot", "bo'i"]),
-        ("boxfo", vec!["bof", "bo'o"]),
-        ("boxna", vec!["bon", "bo'a"]),
-        ("bradi", vec![]),
-        ("brano", vec![]),
-        ("bratu", vec![]),
-        ("bravi", vec![]),
-        ("brazo", vec!["raz"]),
-        ("bredi", vec!["red", "bre"]),
-        ("bridi", vec!["bri"]),
-        ("brife", vec!["bif", "bi'e"]),
-        ("briju", vec!["bij"]),
-        ("brito", vec!["rit"]),
-        ("brivo", vec![]),
-        ("broda", vec!["rod"]),
-        ("brode", vec!["bo'e"]),
-        ("brodi", vec![]),
-        ("brodo", vec![]),
-        ("brodu", vec![]),
-        ("bruci", vec![]),
-        ("bruna", vec!["bun", "bu'a"]),
-        ("bu", vec!["bus", "bu'i"]),
-        ("bu'a", vec!["bul"]),
-        ("budjo", vec!["buj", "bu'o"]),
-        ("budza", vec![]),
-        ("bukpu", vec!["buk", "bu'u"]),
-        ("bumru", vec!["bum"]),
-        ("bunda", vec!["bud"]),
-        ("bunre", vec!["bur", "bu'e"]),
-        ("buntu", vec![]),
-        ("bunxa", vec![]),
-        ("burcu", vec!["bru"]),
-        ("burka", vec![]),
-        ("burna", vec![]),
-        ("buvla", vec![]),
-        ("ca'a", vec!["caz"]),
-        ("cabna", vec!["cab"]),
-        ("cabra", vec!["ca'a"]),
-        ("cacra", vec![]),
-        ("cadga", vec![]),
-        ("cadma", vec![]),
-        ("cadzu", vec!["dzu"]),
-        ("cafne", vec!["caf"]),
-        ("cagna", vec![]),
-        ("cajni", vec![]),
-        ("cakla", vec![]),
-        ("calku", vec!["cak"]),
-        ("calse", vec![]),
-        ("campu", vec![]),
-        ("canci", vec![]),
-        ("cando", vec!["cad"]),
-        ("cange", vec!["cag"]),
-        ("canja", vec!["caj"]),
-        ("canko", vec!["ca'o"]),
-        ("canlu", vec!["cal", "ca'u"]),
-        ("canma", vec![]),
-        ("canpa", vec!["cna"]),
-        ("canre", vec!["can"]),
-        ("canti", vec![]),
-        ("carce", vec![]),
-        ("carfu", vec![]),
-        ("carmi", vec!["cam", "cai"]),
-        ("carna", vec!["car"]),
-        ("cartu", vec!["cat"]),
-        ("carvi", vec!["cav"]),
-        ("casnu", vec!["snu"]),
-        ("catke", vec!["ca'e"]),
-        ("catlu", vec!["cta"]),
-        ("catni", vec!["ca'i"]),
-        ("catra", vec![]),
-        ("caxno", vec!["cax"]),
-        ("ce", vec!["cec"]),
-        ("cecla", vec!["cel", "ce'a"]),
-        ("cecmu", vec!["cem", "ce'u"]),
-        ("cedra", vec![]),
-        ("ce'i", vec!["cez"]),
-        ("celdi", vec!["cle"]),
-        ("celxa", vec![]),
-        ("cenba", vec!["cne"]),
-        ("cendo", vec![]),
-        ("censa", vec!["ces"]),
-        ("centi", vec!["cen"]),
-        ("ce'o", vec!["ce'o"]),
-        ("cerda", vec!["ced"]),
-        ("cerni", vec!["cer"]),
-        ("certu", vec!["cre"]),
-        ("cevni", vec!["cev", "cei"]),
-        ("cfale", vec![]),
-        ("cfari", vec!["cfa"]),
-        ("cfika", vec!["fik", "fi'a"]),
-        ("cfila", vec!["cfi"]),
-        ("cfine", vec![]),
-        ("cfipu", vec!["fi'u"]),
-        ("ci", vec!["cib"]),
-        ("ciblu", vec!["blu"]),
-        ("cicna", vec![]),
-        ("cidja", vec!["dja"]),
-        ("cidni", vec!["cid"]),
-        ("cidro", vec!["dro"]),
-        ("cifnu", vec!["cif"]),
-        ("cigla", vec!["cig"]),
-        ("cikna", vec!["cik"]),
-        ("cikre", vec![]),
-        ("ciksi", vec!["cki"]),
-        ("cilce", vec!["cic"]),
-        ("cilfu", vec![]),
-        ("cilga", vec![]),
-        ("cilmo", vec!["cim"]),
-        ("cilre", vec!["cli"]),
-        ("cilta", vec!["cil"]),
-        ("cimde", vec![]),
-        ("cimle", vec![]),
-        ("cimni", vec![]),
-        ("cimra", vec![]),
-        ("cinba", vec![]),
-        ("cindu", vec![]),
-        ("cinfo", vec![]),
-        ("cinje", vec!["cij"]),
-        ("cinki", vec![]),
-        ("cinla", vec![]),
-        ("cinmo", vec!["cni"]),
-        ("cinri", vec!["ci'i"]),
-        ("cinse", vec!["cin"]),
-        ("cinta", vec![]),
-        ("cinza", vec![]),
-        ("cipni", vec!["cpi"]),
-        ("cipra", vec!["cip"]),
-        ("cirko", vec!["cri"]),
-        ("cirla", vec![]),
-        ("ciska", vec!["ci'a"]),
-        ("cisma", vec![]),
-        ("cisni", vec![]),
-        ("ciste", vec!["ci'e"]),
-        ("citka", vec!["cti"]),
-        ("citno", vec!["cit", "ci'o"]),
-        ("citri", vec!["cir"]),
-        ("citsi", vec![]),
-        ("civla", vec!["civ"]),
-        ("cizra", vec!["ciz"]),
-        ("ckabu", vec![]),
-        ("ckafi", vec!["kaf"]),
-        ("ckaji", vec!["kai"]),
-        ("ckana", vec!["cka"]),
-        ("ckape", vec!["cap"]),
-        ("ckasu", vec!["cas"]),
-        ("ckeji", vec!["kej", "cke"]),
-        ("ckeno", vec![]),
-        ("ckiku", vec!["kik"]),
-        ("ckilu", vec!["ci'u"]),
-        ("ckini", vec!["ki'i"]),
-        ("ckire", vec!["kir"]),
-        ("ckopi", vec![]),
-        ("ckule", vec!["kul", "cu'e"]),
-        ("ckunu", vec!["ku'u"]),
-        ("cladu", vec!["lau"]),
-        ("clafi", vec![]),
-        ("clani", vec!["cla"]),
-        ("claxu", vec!["cau"]),
-        ("clenu", vec![]),
-        ("cletu", vec![]),
-        ("clika", vec![]),
-        ("clinu", vec![]),
-        ("clira", vec!["lir"]),
-        ("clite", vec!["lit"]),
-        ("cliva", vec!["liv", "li'a"]),
-        ("clupa", vec!["cup"]),
-        ("cmaci", vec![]),
-        ("cmalu", vec!["cma"]),
-        ("cmana", vec!["ma'a"]),
-        ("cmavo", vec!["ma'o"]),
-        ("cmene", vec!["me'e", "cme"]),
-        ("cmero", vec![]),
-        ("cmeta", vec![]),
-        ("cmevo", vec![]),
-        ("cmiju", vec![]),
-        ("cmila", vec!["mi'a"]),
-        ("cmima", vec!["mim", "cmi"]),
-        ("cmoni", vec!["co'i", "cmo"]),
-        ("cnano", vec!["na'o"]),
-        ("cnata", vec![]),
-        ("cnebo", vec!["neb", "ne'o"]),
-        ("cnemu", vec!["nem", "ne'u"]),
-        ("cnici", vec!["nic"]),
-        ("cnida", vec![]),
-        ("cnino", vec!["nin", "ni'o"]),
-        ("cnisa", vec!["nis"]),
-        ("cnita", vec!["nit", "ni'a"]),
-        ("co", vec!["col"]),
-        ("co'a", vec!["co'a"]),
-        ("co'e", vec!["com", "co'e"]),
-        ("cokcu", vec!["cko"]),
-        ("cokte", vec![]),
-        ("condi", vec!["con", "cno", "coi"]),
-        ("conka", vec![]),
-        ("corci", vec!["coc"]),
-        ("cortu", vec!["cor", "cro"]),
-        ("cotci", vec![]),
-        ("co'u", vec!["co'u"]),
-        ("cpacu", vec!["cpa"]),
-        ("cpana", vec![]),
-        ("cpare", vec!["par"]),
-        ("cpedu", vec!["cpe"]),
-        ("cpina", vec![]),
-        ("cpixa", vec![]),
-        ("cradi", vec![]),
-        ("crami", vec![]),
-        ("crane", vec!["cra"]),
-        ("cravi", vec![]),
-        ("creka", vec!["cek"]),
-        ("crepu", vec!["rep"]),
-        ("creze", vec![]),
-        ("cribe", vec!["rib"]),
-        ("crida", vec!["rid"]),
-        ("crino", vec!["ri'o"]),
-        ("cripu", vec!["rip"]),
-        ("crisa", vec!["cis"]),
-        ("critu", vec![]),
-        ("ctaru", vec![]),
-        ("ctebi", vec!["teb"]),
-        ("cteki", vec!["tek", "ce'i"]),
-        ("ctera", vec![]),
-        ("ctile", vec![]),
-        ("ctino", vec!["ti'o"]),
-        ("ctuca", vec!["ctu"]),
-        ("cukla", vec!["cuk"]),
-        ("cukre", vec![]),
-        ("cukse", vec![]),
-        ("cukta", vec!["cku"]),
-        ("culno", vec!["clu"]),
-        ("cumba", vec![]),
-        ("cumki", vec!["cum", "cu'i"]),
-        ("cumla", vec!["cul"]),
-        ("cunmi", vec![]),
-        ("cunso", vec!["cun", "cu'o"]),
-        ("cuntu", vec!["cu'u"]),
-        ("cupra", vec!["pra"]),
-        ("curmi", vec!["cru"]),
-        ("curnu", vec!["cur"]),
-        ("curve", vec!["cuv"]),
-        ("cusku", vec!["cus", "sku"]),
-        ("cusna", vec![]),
-        ("cuspe", vec![]),
-        ("cutci", vec!["cuc"]),
-        ("cutne", vec!["cut"]),
-        ("cuxna", vec!["cux", "cu'a"]),
-        ("da", vec!["dav", "dza"]),
-        ("da'a", vec!["daz"]),
-        ("dacru", vec!["dac"]),
-        ("dacti", vec!["dai"]),
-        ("dadjo", vec!["daj"]),
-        ("dajbu", vec![]),
-        ("dajne", vec![]),
-        ("dakfu", vec!["dak"]),
-        ("dakli", vec![]),
-        ("dalti", vec![]),
-        ("damba", vec!["dab", "da'a"]),
-        ("damko", vec![]),
-        ("damri", vec![]),
-        ("danci", vec![]),
-        ("dandu", vec!["dad"]),
-        ("danfu", vec!["daf"]),
-        ("danke", vec![]),
-        ("danlu", vec!["dal", "da'u"]),
-        ("danmo", vec!["dam"]),
-        ("danre", vec!["da'e"]),
-        ("dansu", vec![]),
-        ("danti", vec!["dan"]),
-        ("daplu", vec!["plu"]),
-        ("dapma", vec!["dap"]),
-        ("darca", vec![]),
-        ("dardu", vec![]),
-        ("dargu", vec!["dag"]),
-        ("darlu", vec!["dau"]),
-        ("darno", vec!["dar", "da'o"]),
-        ("darsi", vec![]),
-        ("darxi", vec!["dax", "da'i"]),
-        ("daski", vec![]),
-        ("dasni", vec!["das"]),
-        ("daspo", vec!["spo"]),
-        ("dasri", vec!["sri"]),
-        ("datca", vec![]),
-        ("datka", vec![]),
-        ("datni", vec![]),
-        ("datro", vec![]),
-        ("datru", vec![]),
-        ("datxo", vec![]),
-        ("decti", vec!["dec"]),
-        ("degji", vec!["deg"]),
-        ("dejni", vec!["dej"]),
-        ("dekpu", vec![]),
-        ("dekto", vec!["dek"]),
-        ("delca", vec![]),
-        ("delno", vec!["del", "de'o"]),
-        ("delta", vec![]),
-        ("dembi", vec!["deb"]),
-        ("denci", vec!["den", "de'i"]),
-        ("dendi", vec![]),
-        ("denmi", vec!["dem"]),
-        ("denpa", vec!["dep", "de'a"]),
-        ("dertu", vec!["der", "de'u"]),
-        ("derxi", vec!["dre"]),
-        ("desku", vec!["des"]),
-        ("detri", vec!["det"]),
-        ("dicma", vec![]),
-        ("dicra", vec!["dir"]),
-        ("didni", vec![]),
-        ("digno", vec![]),
-        ("dikca", vec!["dic"]),
-        ("diklo", vec!["klo"]),
-        ("dikni", vec!["dik"]),
-        ("dilcu", vec![]),
-        ("dildo", vec![]),
-        ("dilma", vec![]),
-        ("dilnu", vec!["dil"]),
-        ("dimna", vec!["dim"]),
-        ("dindi", vec!["did"]),
-        ("dinju", vec!["dij", "di'u"]),
-        ("dinko", vec!["di'o"]),
-        ("dinso", vec!["dis"]),
-        ("dinti", vec![]),
-        ("dirba", vec!["dib"]),
-        ("dirce", vec!["di'e"]),
-        ("dirgo", vec!["dig"]),
-        ("disko", vec![]),
-        ("ditcu", vec!["dit"]),
-        ("divzi", vec![]),
-        ("dizlo", vec!["diz", "dzi"]),
-        ("djacu", vec!["jac", "jau"]),
-        ("djedi", vec!["dje", "dei"]),
-        ("djica", vec!["dji"]),
-        ("djika", vec![]),
-        ("djine", vec!["jin"]),
-        ("djuno", vec!["jun", "ju'o"]),
-        ("do", vec!["don", "doi"]),
-        ("dokcu", vec!["do'u"]),
-        ("dolna", vec!["dol"]),
-        ("donri", vec!["dor", "do'i"]),
-        ("dotco", vec!["dot", "do'o"]),
-        ("draci", vec![]),
-        ("drani", vec!["dra"]),
-        ("drata", vec!["dat"]),
-        ("drisu", vec![]),
-        ("drudi", vec!["rud", "dru"]),
-        ("du", vec!["dub", "du'o"]),
-        ("dugri", vec!["dug"]),
-        ("dukni", vec![]),
-        ("dukse", vec!["dus", "du'e"]),
-        ("dukti", vec!["dut"]),
-        ("dulnu", vec![]),
-        ("dunda", vec!["dud", "du'a"]),
-        ("dunja", vec!["duj"]),
-        ("dunku", vec!["duk", "du'u"]),
-        ("dunli", vec!["dun", "du'i"]),
-        ("dunra", vec!["dur"]),
-        ("durna", vec![]),
-        ("dutso", vec![]),
-        ("du'u", vec!["dum"]),
-        ("dzama", vec![]),
-        ("dzena", vec!["dze"]),
-        ("dzipo", vec!["zip", "zi'o"]),
-        ("dzoli", vec!["dzo"]),
-        ("dzuli", vec![]),
-        ("dzuma", vec![]),
-        ("facki", vec!["fak", "fa'i"]),
-        ("facni", vec![]),
-        ("fadni", vec!["fad"]),
-        ("fagri", vec!["fag"]),
-        ("falnu", vec!["fan"]),
-        ("famti", vec![]),
-        ("fancu", vec![]),
-        ("fange", vec![]),
-        ("fanji", vec![]),
-        ("fanmo", vec!["fam", "fa'o"]),
-        ("fanri", vec![]),
-        ("fanta", vec![]),
-        ("fanva", vec![]),
-        ("fanza", vec!["faz"]),
-        ("fapro", vec!["fap", "pro"]),
-        ("fardu", vec![]),
-        ("farka", vec![]),
-        ("farlu", vec!["fal", "fa'u"]),
-        ("farna", vec!["far", "fa'a"]),
-        ("farvi", vec!["fav"]),
-        ("fasnu", vec!["fau"]),
-        ("fatci", vec!["fac"]),
-        ("fatne", vec!["fat", "fa'e"]),
-        ("fatri", vec!["fai"]),
-        ("febvi", vec!["feb"]),
-        ("fegli", vec![]),
-        ("femti", vec!["fem"]),
-        ("fendi", vec!["fed"]),
-        ("fenfa", vec![]),
-        ("fengu", vec!["feg", "fe'u"]),
-        ("fenki", vec!["fek"]),
-        ("fenra", vec!["fer", "fe'a"]),
-        ("fenso", vec!["fen", "fe'o"]),
-        ("fepni", vec!["fep", "fei"]),
-        ("fepri", vec![]),
-        ("ferti", vec!["fre"]),
-        ("festi", vec!["fes"]),
-        ("fetsi", vec!["fet", "fe'i"]),
-        ("fibra", vec![]),
-        ("fidna", vec!["fid"]),
-        ("figre", vec!["fig"]),
-        ("filso", vec!["fis"]),
-        ("finpe", vec!["fip", "fi'e"]),
-        ("finti", vec!["fin", "fi'i"]),
-        ("firca", vec![]),
-        ("fisli", vec![]),
-        ("fizbu", vec!["zbu"]),
-        ("fizde", vec!["fiz"]),
-        ("flaci", vec![]),
-        ("flalu", vec!["fla"]),
-        ("flani", vec![]),
-        ("flecu", vec!["fle"]),
-        ("flese", vec!["les"]),
-        ("fliba", vec!["fli"]),
-        ("flipo", vec![]),
-        ("flira", vec!["fir"]),
-        ("fo'a", vec!["fo'a"]),
-        ("fo'e", vec!["fo'e"]),
-        ("fo'i", vec!["fo'i"]),
-        ("foldi", vec!["flo", "foi"]),
-        ("fonmo", vec!["fom", "fo'o"]),
-        ("fonto", vec![]),
-        ("fonxa", vec!["fon"]),
-        ("forca", vec!["fro"]),
-        ("forse", vec![]),
-        ("fraso", vec!["fas"]),
-        ("frati", vec!["fra"]),
-        ("fraxu", vec!["fax"]),
-        ("frica", vec!["fic"]),
-        ("friko", vec!["fi'o"]),
-        ("frili", vec!["fil"]),
-        ("frinu", vec![]),
-        ("friti", vec!["fit"]),
-        ("frumu", vec!["fru"]),
-        ("fugvo", vec![]),
-        ("fukpi", vec!["fuk", "fu'i"]),
-        ("fulta", vec!["ful", "flu"]),
-        ("funca", vec!["fun", "fu'a"]),
-        ("fusra", vec!["fur"]),
-        ("fuvla", vec![]),
-        ("fuzme", vec!["fuz", "fu'e"]),
-        ("gacri", vec!["gai"]),
-        ("gadri", vec!["gad"]),
-        ("galfi", vec!["gaf", "ga'i"]),
-        ("galki", vec![]),
-        ("galtu", vec!["gal", "ga'u"]),
-        ("galxe", vec![]),
-        ("ganda", vec![]),
-        ("ganlo", vec!["ga'o"]),
-        ("ganra", vec!["gan"]),
-        ("ganse", vec!["gas", "ga'e"]),
-        ("ganti", vec![]),
-        ("ganvi", vec![]),
-        ("ganxo", vec!["gax"]),
-        ("ganzu", vec!["gaz"]),
-        ("gapci", vec!["gac"]),
-        ("gapru", vec!["gap"]),
-        ("gardu", vec![]),
-        ("garna", vec!["gar"]),
-        ("gasnu", vec!["gau"]),
-        ("gaspo", vec![]),
-        ("gasta", vec!["gat"]),
-        ("gelga", vec![]),
-        ("genja", vec!["gej"]),
-        ("gento", vec!["get", "ge'o"]),
-        ("genxu", vec!["gex"]),
-        ("gerku", vec!["ger", "ge'u"]),
-        ("germo", vec!["gem"]),
-        ("gerna", vec!["gen", "ge'a"]),
-        ("gicmu", vec![]),
-        ("gidva", vec!["gid", "gi'a"]),
-        ("gigdo", vec!["gig", "gi'o"]),
-        ("ginka", vec!["gik"]),
-        ("girzu", vec!["gir", "gri"]),
-        ("gismu", vec!["gim", "gi'u"]),
-        ("glare", vec!["gla"]),
-        ("gleki", vec!["gek", "gei"]),
-        ("gletu", vec!["let", "gle"]),
-        ("glico", vec!["gic", "gli"]),
-        ("glife", vec![]),
-        ("glosa", vec![]),
-        ("gluli", vec![]),
-        ("gluta", vec!["glu"]),
-        ("gocti", vec!["goc"]),
-        ("gomja", vec!["gom"]),
-        ("gomsi", vec![]),
-        ("gonte", vec![]),
-        ("gotro", vec!["got"]),
-        ("gradu", vec!["rau"]),
-        ("grafu", vec![]),
-        ("grake", vec!["gra"]),
-        ("grame", vec![]),
-        ("grana", vec!["ga'a"]),
-        ("grasu", vec!["ras"]),
-        ("grava", vec!["gav"]),
-        ("greku", vec!["rek"]),
-        ("grusi", vec!["rus"]),
-        ("grute", vec!["rut"]),
-        ("gubni", vec!["gub"]),
-        ("gugde", vec!["gug", "gu'e"]),
-        ("gugle", vec![]),
-        ("gumri", vec![]),
-        ("gundi", vec!["gud"]),
-        ("gunka", vec!["gun", "gu'a"]),
-        ("gunma", vec!["gum"]),
-        ("gunro", vec!["gur", "gu'o"]),
-        ("gunse", vec![]),
-        ("gunta", vec!["gut"]),
-        ("gurni", vec!["gru"]),
-        ("guska", vec!["guk"]),
-        ("gusni", vec!["gus", "gu'i"]),
-        ("gusta", vec![]),
-        ("gutci", vec!["guc"]),
-        ("gutra", vec![]),
-        ("guzme", vec!["guz", "zme"]),
-        ("ja", vec!["jav"]),
-        ("jabre", vec![]),
-        ("jadji", vec![]),
-        ("jadni", vec!["jad", "ja'i"]),
-        ("jai", vec!["jax"]),
-        ("jakne", vec![]),
-        ("jaktu", vec![]),
-        ("jaldi", vec![]),
-        ("jalge", vec!["jag", "ja'e"]),
-        ("jalna", vec![]),
-        ("jalra", vec![]),
-        ("jamfu", vec!["jaf", "jma"]),
-        ("jamna", vec!["jam"]),
-        ("janbe", vec!["jab"]),
-        ("janco", vec!["jan"]),
-        ("janka", vec![]),
-        ("janli", vec!["jal"]),
-        ("jansu", vec!["jas"]),
-        ("janta", vec!["jat"]),
-        ("jarbu", vec![]),
-        ("jarco", vec!["ja'o"]),
-        ("jarki", vec!["jak"]),
-        ("jaspu", vec!["jap"]),
-        ("jatna", vec!["ja'a"]),
-        ("jatsu", vec![]),
-        ("javni", vec!["jva"]),
-        ("jbama", vec!["bam"]),
-        ("jbari", vec!["jba"]),
-        ("jbena", vec!["jbe"]),
-        ("jbera", vec!["jer"]),
-        ("jbini", vec!["bin", "bi'i"]),
-        ("jbobo", vec![]),
-        ("jdari", vec!["jar"]),
-        ("jdice", vec!["jdi"]),
-        ("jdika", vec![]),
-        ("jdima", vec!["di'a"]),
-        ("jdini", vec!["din", "di'i"]),
-        ("jduli", vec!["dul", "jdu"]),
-        ("je", vec!["jev", "jve"]),
-        ("jecta", vec!["jec", "je'a"]),
-        ("jeftu", vec!["jef"]),
-        ("jegvo", vec!["jeg", "je'o"]),
-        ("jei", vec!["jez"]),
-        ("jelca", vec!["jel"]),
-        ("jemna", vec!["jme"]),
-        ("jenca", vec!["jen"]),
-        ("jendu", vec!["jed"]),
-        ("jenje", vec![]),
-        ("jenmi", vec!["jem", "jei"]),
-        ("jensi", vec![]),
-        ("jerna", vec![]),
-        ("jersi", vec!["je'i"]),
-        ("jerxo", vec!["jex"]),
-        ("jesni", vec!["jes"]),
-        ("jetce", vec!["je'e"]),
-        ("jetnu", vec!["jet", "je'u"]),
-        ("jgalu", vec!["ja'u"]),
-        ("jganu", vec!["jga"]),
-        ("jgari", vec!["jai"]),
-        ("jgena", vec!["jge"]),
-        ("jgina", vec!["gin"]),
-        ("jgira", vec!["jgi"]),
-        ("jgita", vec!["git"]),
-        ("jguna", vec!["jgu"]),
-        ("jguvi", vec![]),
-        ("jibni", vec!["jbi"]),
-        ("jibri", vec!["jib"]),
-        ("jicfo", vec!["cfo"]),
-        ("jicla", vec![]),
-        ("jicmu", vec!["cmu"]),
-        ("jidge", vec!["jid"]),
-        ("jijgi", vec![]),
-        ("jijnu", vec!["jij"]),
-        ("jikca", vec!["jik"]),
-        ("jikfi", vec![]),
-        ("jikni", vec![]),
-        ("jikru", vec![]),
-        ("jilka", vec!["jil"]),
-        ("jilra", vec![]),
-        ("jimca", vec!["jic"]),
-        ("jimpe", vec!["jmi"]),
-        ("jimte", vec!["jit"]),
-        ("jinci", vec![]),
-        ("jinda", vec![]),
-        ("jinga", vec!["jig", "ji'a"]),
-        ("jinku", vec![]),
-        ("jinme", vec!["jim"]),
-        ("jinru", vec!["jir"]),
-        ("jinsa", vec!["jis"]),
-        ("jinto", vec![]),
-        ("jinvi", vec!["jiv", "ji'i"]),
-        ("jinzi", vec!["jiz"]),
-        ("jipci", vec![]),
-        ("jipno", vec!["jip", "ji'o"]),
-        ("jirna", vec![]),
-        ("jisra", vec![]),
-        ("jitfa", vec!["jif"]),
-        ("jitni", vec![]),
-        ("jitro", vec!["tro"]),
-        ("jivbu", vec![]),
-        ("jivna", vec!["jvi"]),
-        ("jmaji", vec!["jaj"]),
-        ("jmedu", vec![]),
-        ("jmice", vec![]),
-        ("jmifa", vec![]),
-        ("jmina", vec!["min"]),
-        ("jmite", vec![]),
-        ("jmive", vec!["miv", "ji'e"]),
-        ("jo", vec!["jov"]),
-        ("jo'e", vec!["jom"]),
-        ("joi", vec!["jol", "joi"]),
-        ("jonse", vec!["jos"]),
-        ("jordo", vec!["jor", "jo'o"]),
-        ("jorne", vec!["jon", "jo'e"]),
-        ("jo'u", vec!["jo'u"]),
-        ("ju", vec!["juv"]),
-        ("jubme", vec!["jub", "jbu"]),
-        ("judri", vec![]),
-        ("jufra", vec!["juf", "ju'a"]),
-        ("jukni", vec!["juk"]),
-        ("jukpa", vec!["jup"]),
-        ("julne", vec!["ju'e"]),
-        ("julro", vec![]),
-        ("jundi", vec!["jud", "ju'i"]),
-        ("jungo", vec!["jug"]),
-        ("junla", vec!["jul"]),
-        ("junri", vec!["jur"]),
-        ("junta", vec![]),
-        ("jurme", vec!["jum"]),
-        ("jursa", vec!["jus"]),
-        ("jutsi", vec!["jut"]),
-        ("juxre", vec!["jux"]),
-        ("jvinu", vec!["vin", "ji'u"]),
-        ("jviso", vec![]),
-        ("jvoso", vec!["vos"]),
-        ("ka", vec!["kam"]),
-        ("kabri", vec![]),
-        ("kacma", vec![]),
-        ("kadno", vec![]),
-        ("kafke", vec![]),
-        ("kagni", vec!["kag"]),
-        ("kajde", vec!["jde"]),
-        ("kajna", vec![]),
-        ("kakne", vec!["ka'e"]),
-        ("kakpa", vec![]),
-        ("kakti", vec![]),
-        ("kalci", vec![]),
-        ("kalpi", vec![]),
-        ("kalri", vec!["kar"]),
-        ("kalsa", vec!["kas"]),
-        ("kalte", vec!["kat"]),
-        ("kamju", vec![]),
-        ("kamni", vec![]),
-        ("kampu", vec!["kau"]),
-        ("kamre", vec![]),
-        ("kamro", vec![]),
-        ("kanba", vec![]),
-        ("kancu", vec!["kac"]),
-        ("kandi", vec!["kad"]),
-        ("kanji", vec!["kaj"]),
-        ("kanla", vec!["kal"]),
-        ("kanpe", vec![]),
-        ("kanro", vec!["ka'o"]),
-        ("kansa", vec!["kan"]),
-        ("kantu", vec!["ka'u"]),
-        ("kanxe", vec!["kax"]),
-        ("kapli", vec![]),
-        ("kapsi", vec![]),
-        ("karbi", vec!["kab"]),
-        ("karce", vec![]),
-        ("karda", vec![]),
-        ("kargu", vec![]),
-        ("karki", vec![]),
-        ("karli", vec![]),
-        ("karmo", vec![]),
-        ("karni", vec![]),
-        ("karpa", vec![]),
-        ("katci", vec![]),
-        ("katna", vec!["ka'a"]),
-        ("katso", vec![]),
-        ("kavbu", vec!["kav"]),
-        ("kavzi", vec![]),
-        ("kazmi", vec![]),
-        ("kazra", vec!["kaz"]),
-        ("ke", vec!["kem"]),
-        ("kebzo", vec![]),
-        ("kecti", vec!["kec", "ke'i"]),
-        ("ke'e", vec!["kep", "ke'e"]),
-        ("kei", vec!["kez"]),
-        ("kekli", vec!["kek"]),
-        ("kekti", vec![]),
-        ("kelci", vec!["kel", "kei"]),
-        ("kelto", vec![]),
-        ("kelvo", vec!["ke'o"]),
-        ("kenka", vec![]),
-        ("kenra", vec!["ken"]),
-        ("kensa", vec!["kes"]),
-        ("kerfa", vec!["kre"]),
-        ("kerlo", vec!["ker"]),
-        ("kerni", vec![]),
-        ("kesfi", vec![]),
-        ("kesri", vec![]),
-        ("ketco", vec!["ket", "tco"]),
-        ("ketli", vec![]),
-        ("ketro", vec![]),
-        ("ketsu", vec![]),
-        ("kevna", vec!["kev", "ke'a"]),
-        ("kibro", vec!["kib"]),
-        ("kicne", vec!["kic", "ki'e"]),
-        ("kicra", vec![]),
-        ("kijno", vec!["kij"]),
-        ("kilga", vec![]),
-        ("kilma", vec!["kim"]),
-        ("kilto", vec!["ki'o"]),
-        ("kinda", vec![]),
-        ("kinfi", vec![]),
-        ("kinli", vec!["kil"]),
-        ("kinmu", vec![]),
-        ("kinra", vec![]),
-        ("kipsi", vec![]),
-        ("kisto", vec!["kis"]),
-        ("klaji", vec!["laj"]),
-        ("klaku", vec!["kak"]),
-        ("klama", vec!["kla"]),
-        ("klani", vec!["lai"]),
-        ("klaso", vec![]),
-        ("klesi", vec!["kle", "lei"]),
-        ("kligo", vec!["kig"]),
-        ("kliki", vec![]),
-        ("klina", vec!["kli"]),
-        ("kliru", vec![]),
-        ("kliti", vec!["kit"]),
-        ("kloje", vec![]),
-        ("klupe", vec!["lup", "lu'e"]),
-        ("kluza", vec!["luz"]),
-        ("kobli", vec!["kob", "ko'i"]),
-        ("kogno", vec![]),
-        ("kojna", vec!["koj", "ko'a"]),
-        ("kokso", vec![]),
-        ("kolme", vec!["kol", "ko'e"]),
-        ("komcu", vec!["kom"]),
-        ("konju", vec!["kon", "ko'u"]),
-        ("konve", vec![]),
-        ("korbi", vec!["kor", "koi"]),
-        ("korcu", vec!["kro"]),
-        ("kordu", vec![]),
-        ("korja", vec![]),
-        ("korka", vec!["kok"]),
-        ("korle", vec![]),
-        ("korvo", vec![]),
-        ("kosmu", vec![]),
-        ("kosta", vec!["kos"]),
-        ("krali", vec![]),
-        ("kramu", vec![]),
-        ("krasi", vec!["kra"]),
-        ("krati", vec!["ka'i"]),
-        ("krefu", vec!["ref", "ke'u"]),
-        ("krici", vec!["kri"]),
-        ("krili", vec![]),
-        ("krinu", vec!["rin", "ki'u"]),
-        ("krixa", vec!["kix", "ki'a"]),
-        ("kroke", vec![]),
-        ("kroxo", vec![]),
-        ("kruca", vec!["kuc"]),
-        ("kruji", vec!["ruj"]),
-        ("kruvi", vec!["ruv", "kru"]),
-        ("ku'a", vec!["kuz"]),
-        ("kubli", vec!["kub"]),
-        ("kucli", vec![]),
-        ("kufra", vec!["kuf"]),
-        ("kukru", vec![]),
-        ("kukte", vec!["kuk"]),
-        ("kulnu", vec!["klu"]),
-        ("kumfa", vec!["kum", "ku'a"]),
-        ("kumte", vec![]),
-        ("kunra", vec!["kun"]),
-        ("kunti", vec!["kut"]),
-        ("kurfa", vec!["kur"]),
-        ("kurji", vec!["kuj", "ku'i"]),
-        ("kurki", vec![]),
-        ("kurti", vec![]),
-        ("kuspe", vec!["kup", "ku'e"]),
-        ("kusru", vec!["kus"]),
-        ("labno", vec![]),
-        ("lacni", vec![]),
-        ("lacpu", vec!["lap", "cpu"]),
-        ("lacri", vec!["lac"]),
-        ("ladlo", vec![]),
-        ("ladru", vec!["lad"]),
-        ("lafti", vec!["laf"]),
-        ("lakne", vec!["la'e"]),
-        ("lakse", vec!["lak"]),
-        ("laldo", vec![]),
-        ("lalxu", vec!["la'u"]),
-        ("lamda", vec![]),
-        ("lamdu", vec![]),
-        ("lamji", vec!["lam", "la'i"]),
-        ("lanbi", vec![]),
-        ("lanci", vec![]),
-        ("landa", vec![]),
-        ("langi", vec![]),
-        ("lanka", vec![]),
-        ("lanli", vec!["lal"]),
-        ("lanme", vec!["lan"]),
-        ("lanru", vec![]),
-        ("lante", vec![]),
-        ("lanxe", vec!["lax"]),
-        ("lanzu", vec!["laz"]),
-        ("larcu", vec!["lar"]),
-        ("larki", vec![]),
-        ("larva", vec![]),
-        ("lasna", vec!["la'a"]),
-        ("lastu", vec![]),
-        ("latci", vec![]),
-        ("latmo", vec!["la'o"]),
-        ("latna", vec![]),
-        ("laxte", vec![]),
-        ("lazni", vec![]),
-        ("lebna", vec!["leb", "le'a"]),
-        ("le'e", vec!["lem"]),
-        ("lelde", vec![]),
-        ("lelxe", vec!["lel"]),
-        ("lenga", vec![]),
-        ("lengu", vec![]),
-        ("lenjo", vec!["len", "le'o"]),
-        ("lenku", vec!["lek"]),
-        ("lerci", vec!["lec"]),
-        ("lerfu", vec!["ler", "le'u"]),
-        ("letci", vec![]),
-        ("libjo", vec!["lib"]),
-        ("licne", vec![]),
-        ("lidne", vec!["li'e"]),
-        ("lifri", vec!["lif", "fri"]),
-        ("ligma", vec![]),
-        ("li'i", vec!["liz"]),
-        ("lijda", vec!["jda"]),
-        ("likse", vec![]),
-        ("lilvo", vec![]),
-        ("limfa", vec![]),
-        ("limna", vec!["lim"]),
-        ("lince", vec![]),
-        ("lindi", vec!["lid"]),
-        ("linga", vec![]),
-        ("linji", vec!["lij", "li'i"]),
-        ("linme", vec![]),
-        ("linsi", vec!["lin"]),
-        ("linto", vec!["li'o"]),
-        ("lisri", vec!["lis"]),
-        ("liste", vec!["ste"]),
-        ("litce", vec!["lic"]),
-        ("litki", vec!["lik"]),
-        ("litru", vec!["li'u"]),
-        ("livga", vec![]),
-        ("livla", vec!["lil"]),
-        ("lo'e", vec!["lom"]),
-        ("logji", vec!["loj"]),
-        ("loglo", vec!["log", "glo"]),
-        ("lojbo", vec!["lob", "jbo"]),
-        ("lojgo", vec![]),
-        ("lokra", vec![]),
-        ("loldi", vec!["lol", "loi"]),
-        ("lorxu", vec!["lor", "lo'u"]),
-        ("losmo", vec!["los"]),
-        ("losto", vec![]),
-        ("lubma", vec![]),
-        ("lubno", vec!["lu'o"]),
-        ("lucpa", vec![]),
-        ("lujvo", vec!["luv", "jvo"]),
-        ("luksi", vec!["luk"]),
-        ("lumci", vec!["lum", "lu'i"]),
-        ("lunbe", vec!["lub"]),
-        ("lungo", vec![]),
-        ("lunra", vec!["lur"]),
-        ("lunsa", vec!["lus"]),
-        ("luska", vec![]),
-        ("lusto", vec![]),
-        ("mabla", vec!["mal"]),
-        ("mabru", vec!["mab"]),
-        ("macnu", vec!["cnu"]),
-        ("majga", vec![]),
-        ("majgo", vec!["jgo"]),
-        ("majvu", vec![]),
-        ("makcu", vec!["ma'u"]),
-        ("makfa", vec!["maf"]),
-        ("maksi", vec!["mak"]),
-        ("malbu", vec![]),
-        ("malsi", vec!["mas"]),
-        ("maltu", vec![]),
-        ("malve", vec![]),
-        ("mamta", vec!["mam"]),
-        ("manci", vec!["mac"]),
-        ("manfo", vec![]),
-        ("mango", vec![]),
-        ("manku", vec!["man"]),
-        ("manri", vec!["mar"]),
-        ("mansa", vec![]),
-        ("manti", vec![]),
-        ("manva", vec![]),
-        ("mapku", vec!["map"]),
-        ("mapni", vec![]),
-        ("mapra", vec![]),
-        ("mapti", vec!["mat"]),
-        ("marbi", vec!["mra"]),
-        ("marce", vec!["ma'e"]),
-        ("marde", vec!["mad"]),
-        ("margu", vec!["mag"]),
-        ("marji", vec!["maj", "mai"]),
-        ("marna", vec![]),
-        ("marxa", vec!["max"]),
-        ("maska", vec![]),
-        ("masno", vec!["sno"]),
-        ("masti", vec!["ma'i"]),
-        ("matci", vec![]),
-        ("matli", vec![]),
-        ("matne", vec![]),
-        ("matra", vec![]),
-        ("matxe", vec![]),
-        ("mavji", vec!["mav"]),
-        ("maxri", vec!["xri"]),
-        ("mebri", vec!["meb"]),
-        ("medzo", vec![]),
-        ("megdo", vec!["meg"]),
-        ("mei", vec!["mem", "mei"]),
-        ("mekca", vec![]),
-        ("mekso", vec!["mek", "me'o"]),
-        ("melbi", vec!["mel", "mle"]),
-        ("meljo", vec!["mej"]),
-        ("melmi", vec![]),
-        ("menli", vec!["men"]),
-        ("menre", vec![]),
-        ("mensi", vec!["mes", "me'i"]),
-        ("mentu", vec!["met", "me'u"]),
-        ("merko", vec!["mer"]),
-        ("merli", vec!["mre"]),
-        ("mespo", vec![]),
-        ("metfo", vec![]),
-        ("metro", vec![]),
-        ("mexno", vec!["mex"]),
-        ("mi", vec!["mib"]),
-        ("midju", vec!["mij"]),
-        ("mifra", vec!["mif"]),
-        ("mikce", vec!["mic"]),
-        ("mikri", vec!["mik"]),
-        ("milti", vec!["mil"]),
-        ("milxe", vec!["mli"]),
-        ("mimta", vec![]),
-        ("minde", vec!["mid", "mi'e"]),
-        ("minji", vec!["mi'i"]),
-        ("minli", vec![]),
-        ("minra", vec!["mir"]),
-        ("mintu", vec!["mit", "mi'u"]),
-        ("mipri", vec!["mip"]),
-        ("mirli", vec![]),
-        ("misno", vec!["mis", "mi'o"]),
-        ("misro", vec![]),
-        ("miste", vec![]),
-        ("mitre", vec!["tre"]),
-        ("mixre", vec!["mix", "xre"]),
-        ("mlana", vec!["mla"]),
-        ("mlatu", vec!["lat"]),
-        ("mleca", vec!["mec", "me'a"]),
-        ("mledi", vec!["led"]),
-        ("mluni", vec!["lun"]),
-        ("mo'a", vec!["mob"]),
-        ("modju", vec![]),
-        ("modzi", vec![]),
-        ("mogle", vec![]),
-        ("mo'i", vec!["mov"]),
-        ("moi", vec!["mom", "moi"]),
-        ("mokca", vec!["moc"]),
-        ("moklu", vec!["mol", "mo'u"]),
-        ("molki", vec!["mlo"]),
-        ("molro", vec!["mo'o"]),
-        ("mondo", vec![]),
-        ("morji", vec!["moj", "mo'i"]),
-        ("morko", vec!["mor"]),
-        ("mormo", vec![]),
-        ("morna", vec!["mon", "mo'a"]),
-        ("morsi", vec!["mro"]),
-        ("mosra", vec!["mos"]),
-        ("moxna", vec![]),
-        ("mraji", vec![]),
-        ("mrilu", vec!["mri"]),
-        ("mruli", vec!["mru"]),
-        ("mu", vec!["mum"]),
-        ("mucti", vec!["mut"]),
-        ("mudri", vec!["mud"]),
-        ("mu'e", vec!["muf"]),
-        ("mugle", vec![]),
-        ("mukti", vec!["muk", "mu'i"]),
-        ("mulno", vec!["mul", "mu'o"]),
-        ("munje", vec!["muj", "mu'e"]),
-        ("munpi", vec![]),
-        ("mupli", vec!["mup"]),
-        ("murse", vec![]),
-        ("murta", vec!["mur", "mu'a"]),
-        ("muslo", vec!["mus"]),
-        ("mutce", vec!["tce"]),
-        ("muvdu", vec!["muv", "mu'u"]),
-        ("muxne", vec!["mux"]),
-        ("muzga", vec!["muz"]),
-        ("na", vec!["nar"]),
-        ("nabmi", vec!["nam"]),
-        ("na'e", vec!["nal"]),
-        ("nakni", vec!["nak"]),
-        ("nalci", vec!["na'i"]),
-        ("nalfi", vec![]),
-        ("nalfo", vec![]),
-        ("nalti", vec![]),
-        ("namcu", vec!["nac", "na'u"]),
-        ("namra", vec![]),
-        ("nanba", vec!["nab"]),
-        ("nanca", vec!["na'a"]),
-        ("nandu", vec!["nad"]),
-        ("nanla", vec![]),
-        ("nanmu", vec!["nau"]),
-        ("nanvi", vec!["nav"]),
-        ("narge", vec!["nag"]),
-        ("narju", vec!["naj"]),
-        ("natfe", vec!["naf", "na'e"]),
-        ("natlo", vec![]),
-        ("natmi", vec!["nat", "nai"]),
-        ("natsi", vec![]),
-        ("navni", vec![]),
-        ("naxle", vec!["nax", "xle"]),
-        ("nazbi", vec!["naz", "zbi"]),
-        ("nazmo", vec![]),
-        ("nedlo", vec![]),
-        ("nejni", vec!["nen"]),
-        ("nelci", vec!["nel", "nei"]),
-        ("nenmu", vec![]),
-        ("nenri", vec!["ner", "ne'i"]),
-        ("nerde", vec![]),
-        ("ni", vec!["nil"]),
-        ("nibli", vec!["nib", "ni'i"]),
-        ("nicfa", vec!["nif"]),
-        ("niclu", vec![]),
-        ("nicte", vec!["cte"]),
-        ("nikle", vec!["nik"]),
-        ("nilce", vec!["ni'e"]),
-        ("nimre", vec![]),
-        ("ninja", vec![]),
-        ("ninmu", vec!["nim", "ni'u"]),
-        ("nirna", vec!["nir"]),
-        ("nitcu", vec!["tcu"]),
-        ("nivji", vec!["niv"]),
-        ("nixli", vec!["nix", "xli"]),
-        ("no", vec!["non"]),
-        ("nobli", vec!["nol", "no'i"]),
-        ("no'e", vec!["nor", "no'e"]),
-        ("nonka", vec!["nok"]),
-        ("nonmu", vec![]),
-        ("nordo", vec![]),
-        ("norgo", vec![]),
-        ("notci", vec!["not", "noi"]),
-        ("nu", vec!["nun"]),
-        ("nudle", vec![]),
-        ("nukni", vec!["nuk"]),
-        ("nuktu", vec![]),
-        ("nulpa", vec![]),
-        ("numje", vec![]),
-        ("nunmu", vec!["nu'u"]),
-        ("nu'o", vec!["nu'o"]),
-        ("nupre", vec!["nup", "nu'e"]),
-        ("nurma", vec!["num"]),
-        ("nusna", vec![]),
-        ("nutka", vec![]),
-        ("nutko", vec![]),
-        ("nutli", vec!["nul", "nu'i"]),
-        ("nuzba", vec!["nuz"]),
-        ("nuzlo", vec![]),
-        ("pa", vec!["pav"]),
-        ("pacna", vec!["pa'a"]),
-        ("pagbu", vec!["pag", "pau"]),
-        ("pagre", vec!["gre"]),
-        ("pagzu", vec![]),
-        ("pajni", vec!["pai"]),
-        ("palci", vec!["pac"]),
-        ("palku", vec!["pak"]),
-        ("palma", vec![]),
-        ("palne", vec![]),
-        ("palpi", vec![]),
-        ("palta", vec![]),
-        ("pambe", vec![]),
-        ("pamga", vec![]),
-        ("panci", vec!["pan"]),
-        ("pandi", vec!["pad"]),
-        ("panje", vec![]),
-        ("panka", vec![]),
-        ("panlo", vec!["pa'o"]),
-        ("panpi", vec!["pap"]),
-        ("panra", vec![]),
-        ("pante", vec!["pat"]),
-        ("panzi", vec!["paz"]),
-        ("papri", vec![]),
-        ("parbi", vec!["pab"]),
-        ("pardu", vec![]),
-        ("parji", vec![]),
-        ("paska", vec![]),
-        ("pastu", vec!["pas"]),
-        ("patfu", vec!["paf", "pa'u"]),
-        ("patlu", vec![]),
-        ("patxu", vec!["pax"]),
-        ("paxra", vec![]),
-        ("paznu", vec![]),
-        ("pe'a", vec!["pev"]),
-        ("pegva", vec!["peg"]),
-        ("pelji", vec!["ple"]),
-        ("pelxu", vec!["pel"]),
-        ("pemci", vec!["pem"]),
-        ("penbi", vec!["peb"]),
-        ("pencu", vec!["pec", "pe'u"]),
-        ("pendo", vec!["ped", "pe'o"]),
-        ("penka", vec![]),
-        ("penki", vec![]),
-        ("penmi", vec!["pen", "pe'i"]),
-        ("pensi", vec!["pes", "pei"]),
-        ("pentu", vec![]),
-        ("pepsi", vec![]),
-        ("perli", vec!["per"]),
-        ("pesxu", vec!["pex"]),
-        ("petso", vec!["pet"]),
-        ("pevna", vec![]),
-        ("pezli", vec!["pez"]),
-        ("pi", vec!["piz"]),
-        ("picti", vec!["pic"]),
-        ("pijne", vec![]),
-        ("pikci", vec![]),
-        ("pikpi", vec![]),
-        ("pikta", vec![]),
-        ("pilda", vec![]),
-        ("pilji", vec!["pi'i"]),
-        ("pilka", vec!["pil", "pi'a"]),
-        ("pilno", vec!["pli"]),
-        ("pimlu", vec!["pim", "pi'u"]),
-        ("pinca", vec![]),
-        ("pindi", vec!["pid"]),
-        ("pinfu", vec!["pif"]),
-        ("Pingo", vec![]),
-        ("pinji", vec![]),
-        ("pinka", vec!["pik"]),
-        ("pinsi", vec!["pis"]),
-        ("pinta", vec!["pin"]),
-        ("pinxe", vec!["pix"]),
-        ("pipno", vec!["pi'o"]),
-        ("pirxo", vec![]),
-        ("pitsa", vec![]),
-        ("pi'u", vec!["piv"]),
-        ("pixra", vec!["pir", "xra"]),
-        ("plana", vec![]),
-        ("platu", vec!["pla"]),
-        ("pleji", vec!["lej", "le'i"]),
-        ("plibu", vec!["pib"]),
-        ("plini", vec![]),
-        ("plipe", vec!["pip", "pi'e"]),
-        ("plise", vec![]),
-        ("plita", vec!["pit"]),
-        ("plixa", vec!["lix"]),
-        ("pluja", vec!["luj"]),
-        ("pluka", vec!["puk", "pu'a"]),
-        ("pluna", vec![]),
-        ("pluta", vec!["lut", "lu'a"]),
-        ("pocli", vec![]),
-        ("polje", vec!["plo"]),
-        ("polno", vec!["pol"]),
-        ("pombo", vec!["pom"]),
-        ("ponjo", vec!["pon", "po'o"]),
-        ("ponse", vec!["pos", "po'e"]),
-        ("poplu", vec![]),
-        ("porno", vec![]),
-        ("porpi", vec!["pop", "po'i"]),
-        ("porsi", vec!["por", "poi"]),
-        ("porto", vec!["pot"]),
-        ("posko", vec!["pok"]),
-        ("praci", vec![]),
-        ("prali", vec!["pal"]),
-        ("prami", vec!["pam", "pa'i"]),
-        ("prane", vec!["pa'e"]),
-        ("predi", vec![]),
-        ("preja", vec!["pej", "pe'a"]),
-        ("premi", vec![]),
-        ("prenu", vec!["pre"]),
-        ("preri", vec![]),
-        ("preti", vec!["ret", "rei"]),
-        ("prije", vec!["pij"]),
-        ("prina", vec!["pri"]),
-        ("pritu", vec![]),
-        ("proga", vec!["pog"]),
-        ("proji", vec![]),
-        ("prosa", vec!["ros", "ro'a"]),
-        ("pruce", vec!["ruc", "ru'e"]),
-        ("pruni", vec!["pun"]),
-        ("pruri", vec![]),
-        ("pruxi", vec!["rux", "ru'i"]),
-        ("pu'i", vec!["pus"]),
-        ("pulce", vec!["puc", "pu'e"]),
-        ("pulji", vec![]),
-        ("pulni", vec![]),
-        ("punji", vec!["puj", "pu'i"]),
-        ("punli", vec!["pul"]),
-        ("pupsu", vec!["pup"]),
-        ("purci", vec!["pur", "pru"]),
-        ("purdi", vec!["pud"]),
-        ("purmo", vec!["pum", "pu'o"]),
-        ("purni", vec![]),
-        ("pu'u", vec!["puv"]),
-        ("puvza", vec![]),
-        ("racli", vec![]),
-        ("ractu", vec![]),
-        ("radno", vec!["ra'o"]),
-        ("rafsi", vec!["raf"]),
-        ("ragbi", vec![]),
-        ("ragve", vec!["rav"]),
-        ("rakle", vec![]),
-        ("rakso", vec![]),
-        ("raktu", vec!["ra'u"]),
-        ("ralci", vec!["rac"]),
-        ("ralju", vec!["ral"]),
-        ("ralte", vec!["ra'e"]),
-        ("rance", vec![]),
-        ("randa", vec!["rad"]),
-        ("rango", vec!["rag"]),
-        ("ranji", vec!["ra'i"]),
-        ("ranlu", vec![]),
-        ("ranmi", vec!["ram"]),
-        ("ransu", vec![]),
-        ("ranti", vec!["ran"]),
-        ("ranxi", vec!["rax"]),
-        ("rapli", vec!["rap"]),
-        ("rarna", vec!["rar"]),
-        ("ratcu", vec![]),
-        ("ratma", vec![]),
-        ("ratni", vec!["rat"]),
-        ("re", vec!["rel"]),
-        ("rebla", vec!["reb"]),
-        ("rectu", vec!["rec", "re'u"]),
-        ("reksa", vec![]),
-        ("rekto", vec![]),
-        ("remna", vec!["rem", "re'a"]),
-        ("renro", vec!["rer", "re'o"]),
-        ("renvi", vec!["rev", "re'i"]),
-        ("respa", vec!["res"]),
-        ("rexsa", vec!["rex"]),
-        ("ribga", vec![]),
-        ("ricfu", vec!["rif", "cfu"]),
-        ("rigni", vec!["rig"]),
-        ("rijno", vec!["rij"]),
-        ("rikro", vec![]),
-        ("rilti", vec!["ril"]),
-        ("rimni", vec!["rim"]),
-        ("rinci", vec![]),
-        ("rindo", vec![]),
-        ("rinju", vec!["ri'u"]),
-        ("rinka", vec!["rik", "ri'a"]),
-        ("rinsa", vec![]),
-        ("ripto", vec![]),
-        ("rirci", vec![]),
-        ("rirli", vec![]),
-        ("rirni", vec!["rir"]),
-        ("rirxe", vec!["ri'e"]),
-        ("rismi", vec!["ris"]),
-        ("risna", vec![]),
-        ("ritli", vec!["ri'i"]),
-        ("rivbi", vec!["riv"]),
-        ("ro", vec!["rol"]),
-        ("roi", vec!["rom", "roi"]),
-        ("rokci", vec!["rok", "ro'i"]),
-        ("romge", vec!["rog"]),
-        ("romlo", vec![]),
-        ("ronci", vec!["roc"]),
-        ("ronro", vec![]),
-        ("ronsa", vec![]),
-        ("ronte", vec![]),
-        ("ronti", vec![]),
-        ("ropno", vec!["ron", "ro'o"]),
-        ("rorci", vec!["ror"]),
-        ("rotsu", vec!["rot", "ro'u", "tsu"]),
-        ("rozgu", vec!["roz", "zgu"]),
-        ("ruble", vec!["rub", "ble"]),
-        ("rucni", vec![]),
-        ("rufsu", vec!["ruf"]),
-        ("runme", vec!["rum"]),
-        ("runta", vec![]),
-        ("rupnu", vec!["rup", "ru'u"]),
-        ("rusko", vec!["ruk", "ru'o"]),
-        ("rutni", vec!["run"]),
-        ("sabji", vec!["sab"]),
-        ("sabnu", vec![]),
-        ("sacki", vec![]),
-        ("saclu", vec![]),
-        ("sacni", vec![]),
-        ("sadjo", vec!["djo"]),
-        ("sakci", vec!["sak"]),
-        ("sakli", vec!["sal"]),
-        ("sakta", vec!["sat"]),
-        ("salci", vec!["sla"]),
-        ("salmo", vec![]),
-        ("salni", vec![]),
-        ("salpo", vec!["sa'o"]),
-        ("salri", vec![]),
-        ("salta", vec![]),
-        ("samcu", vec![]),
-        ("samlu", vec![]),
-        ("sampu", vec!["sap"]),
-        ("sanbu", vec![]),
-        ("sance", vec!["sna"]),
-        ("sanga", vec!["sag", "sa'a"]),
-        ("sanji", vec!["saj"]),
-        ("sanli", vec!["sa'i"]),
-        ("sanmi", vec!["sai"]),
-        ("sanra", vec![]),
-        ("sanso", vec![]),
-        ("santa", vec![]),
-        ("sarcu", vec!["sa'u"]),
-        ("sarji", vec!["sra"]),
-        ("sarlu", vec![]),
-        ("sarni", vec![]),
-        ("sarxe", vec!["sax"]),
-        ("saske", vec!["ske"]),
-        ("satci", vec![]),
-        ("satre", vec!["sa'e"]),
-        ("savru", vec!["sav", "vru"]),
-        ("sazri", vec!["saz"]),
-        ("se", vec!["sel"]),
-        ("sefsi", vec![]),
-        ("sefta", vec!["sfe"]),
-        ("sekre", vec![]),
-        ("selci", vec!["sle"]),
-        ("selfu", vec!["sef", "se'u"]),
-        ("semto", vec!["sme"]),
-        ("senci", vec!["sec"]),
-        ("sengi", vec![]),
-        ("senpi", vec!["sen"]),
-        ("senta", vec!["set"]),
-        ("senva", vec!["sev", "sne"]),
-        ("sepli", vec!["sep", "sei"]),
-        ("sepre", vec![]),
-        ("serti", vec!["ser"]),
-        ("sesre", vec![]),
-        ("setca", vec!["se'a"]),
-        ("sevda", vec![]),
-        ("sevzi", vec!["sez", "se'i"]),
-        ("sezni", vec![]),
-        ("sfani", vec![]),
-        ("sfasa", vec!["sfa"]),
-        ("sfeno", vec!["se'o"]),
-        ("sfero", vec![]),
-        ("sfite", vec!["sfi"]),
-        ("sfofa", vec!["sfo"]),
-        ("sfopu", vec!["fop", "fo'u"]),
-        ("sfubu", vec!["sub", "su'u"]),
-        ("sibli", vec![]),
-        ("siclu", vec!["sil"]),
-        ("sicni", vec!["si'i"]),
-        ("sicpi", vec![]),
-        ("sidbo", vec!["sib", "si'o"]),
-        ("sidju", vec!["dju"]),
-        ("sigda", vec![]),
-        ("sigja", vec!["sig"]),
-        ("sigma", vec![]),
-        ("sikta", vec![]),
-        ("silja", vec![]),
-        ("silka", vec!["sik"]),
-        ("silna", vec![]),
-        ("silru", vec![]),
-        ("simlu", vec!["mlu"]),
-        ("simsa", vec!["smi"]),
-        ("simxu", vec!["sim", "si'u"]),
-        ("since", vec![]),
-        ("sinma", vec!["si'a"]),
-        ("sinso", vec![]),
-        ("sinxa", vec!["sni"]),
-        ("si'o", vec!["siz"]),
-        ("sipna", vec!["sip"]),
-        ("sirji", vec!["sir"]),
-        ("sirmu", vec![]),
-        ("sirxo", vec!["six"]),
-        ("sisku", vec!["sis"]),
-        ("sisti", vec!["sti"]),
-        ("sitla", vec![]),
-        ("sitna", vec!["sit"]),
-        ("sivni", vec!["siv"]),
-        ("sizgo", vec![]),
-        ("skaci", vec![]),
-        ("skami", vec!["sam"]),
-        ("skano", vec![]),
-        ("skapi", vec!["kap"]),
-        ("skari", vec!["ska"]),
-        ("skibo", vec![]),
-        ("skicu", vec!["ski"]),
-        ("skifa", vec![]),
-        ("skiji", vec!["sij"]),
-        ("skina", vec!["kin"]),
-        ("skori", vec!["sko"]),
-        ("skoto", vec!["kot", "ko'o"]),
-        ("skuba", vec![]),
-        ("skudi", vec![]),
-        ("skuna", vec![]),
-        ("skuro", vec!["ku'o"]),
-        ("slabu", vec!["sau"]),
-        ("slaka", vec![]),
-        ("slami", vec![]),
-        ("slanu", vec![]),
-        ("slari", vec!["sar"]),
-        ("slasi", vec!["las"]),
-        ("sligu", vec!["lig"]),
-        ("slilu", vec!["sli"]),
-        ("sliri", vec![]),
-        ("slovo", vec!["lov", "lo'o"]),
-        ("sluji", vec!["slu"]),
-        ("sluni", vec![]),
-        ("smacu", vec![]),
-        ("smadi", vec![]),
-        ("smaji", vec!["sma"]),
-        ("smaka", vec![]),
-        ("smani", vec![]),
-        ("smela", vec![]),
-        ("smoka", vec!["smo"]),
-        ("smuci", vec!["muc"]),
-        ("smuni", vec!["mun", "smu"]),
-        ("smusu", vec![]),
-        ("snada", vec!["sad"]),
-        ("snafu", vec![]),
-        ("snanu", vec!["nan"]),
-        ("snavi", vec![]),
-        ("snejo", vec![]),
-        ("snidu", vec!["nid"]),
-        ("sniju", vec![]),
-        ("snime", vec!["si'e"]),
-        ("snipa", vec!["nip"]),
-        ("snuji", vec!["nuj"]),
-        ("snura", vec!["nur", "nu'a"]),
-        ("snuti", vec!["nut"]),
-        ("so", vec!["soz"]),
-        ("so'a", vec!["soj"]),
-        ("sobde", vec!["sob", "so'e"]),
-        ("socni", vec![]),
-        ("sodna", vec![]),
-        ("sodva", vec!["sod"]),
-        ("so'e", vec!["sop"]),
-        ("softo", vec!["sof"]),
-        ("so'i", vec!["sor", "so'i"]),
-        ("solji", vec!["slo"]),
-        ("solri", vec!["sol"]),
-        ("sombo", vec!["som", "so'o"]),
-        ("sonci", vec!["son", "soi"]),
-        ("sondu", vec![]),
-        ("sonli", vec![]),
-        ("so'o", vec!["sos"]),
-        ("sorcu", vec!["soc", "sro"]),
-        ("sorgu", vec!["sog"]),
-        ("sorni", vec![]),
-        ("sorta", vec![]),
-        ("so'u", vec!["sot"]),
-        ("sovda", vec!["sov", "so'a"]),
-        ("spaji", vec!["paj"]),
-        ("spali", vec![]),
-        ("spano", vec!["san"]),
-        ("spati", vec!["spa"]),
-        ("speci", vec![]),
-        ("speni", vec!["spe"]),
-        ("spero", vec![]),
-        ("spisa", vec!["spi"]),
-        ("spita", vec![]),
-        ("spofu", vec!["pof", "po'u"]),
-        ("spoja", vec!["poj", "po'a"]),
-        ("spuda", vec!["spu"]),
-        ("spupi", vec![]),
-        ("sputu", vec!["put", "pu'u"]),
-        ("sraji", vec!["raj"]),
-        ("sraku", vec!["rak"]),
-        ("sralo", vec![]),
-        ("srana", vec!["ra'a"]),
-        ("srasu", vec!["sas"]),
-        ("srate", vec![]),
-        ("srera", vec!["sre"]),
-        ("srito", vec![]),
-        ("sruma", vec!["ru'a"]),
-        ("sruri", vec!["rur", "sru"]),
-        ("stace", vec!["sac"]),
-        ("stagi", vec![]),
-        ("staku", vec!["tak"]),
-        ("stali", vec!["sta"]),
-        ("stani", vec![]),
-        ("stapa", vec!["tap"]),
-        ("stasu", vec![]),
-        ("stati", vec![]),
-        ("steba", vec!["seb"]),
-        ("steci", vec!["tec", "te'i"]),
-        ("stedu", vec!["sed"]),
-        ("stela", vec!["tel"]),
-        ("stero", vec!["te'o"]),
-        ("stici", vec!["sic"]),
-        ("stidi", vec!["sid", "ti'i"]),
-        ("stika", vec!["tik"]),
-        ("stima", vec![]),
-        ("stizu", vec!["tiz"]),
-        ("stodi", vec!["sto"]),
-        ("stori", vec![]),
-        ("stuna", vec!["sun"]),
-        ("stura", vec!["tur", "su'a"]),
-        ("stuzi", vec!["tuz", "stu"]),
-        ("sucta", vec!["suc"]),
-        ("sudga", vec!["sud"]),
-        ("su'e", vec!["sup", "su'e"]),
-        ("sufti", vec!["sfu"]),
-        ("suksa", vec!["suk"]),
-        ("sumji", vec!["suj"]),
-        ("sumla", vec![]),
-        ("sumne", vec![]),
-        ("sumro", vec![]),
-        ("sumti", vec!["sum", "su'i"]),
-        ("sunga", vec!["sug"]),
-        ("sunla", vec!["sul"]),
-        ("su'o", vec!["suz", "su'o"]),
-        ("supso", vec!["sus"]),
-        ("surla", vec!["sur"]),
-        ("sutra", vec!["sut"]),
-        ("su'u", vec!["suv"]),
-        ("ta", vec!["taz"]),
-        ("tabno", vec!["tab"]),
-        ("tabra", vec![]),
-        ("tadji", vec![]),
-        ("tadni", vec!["tad"]),
-        ("tagji", vec!["tag"]),
-        ("takni", vec![]),
-        ("taksi", vec![]),
-        ("talko", vec![]),
-        ("talno", vec![]),
-        ("talsa", vec!["tal"]),
-        ("tamca", vec![]),
-        ("tamji", vec!["taj"]),
-        ("tamle", vec![]),
-        ("tamne", vec![]),
-        ("tanbo", vec!["ta'o"]),
-        ("tance", vec!["tac"]),
-        ("tanjo", vec![]),
-        ("tanko", vec![]),
-        ("tanru", vec!["tau"]),
-        ("tansi", vec!["tas"]),
-        ("tanxe", vec!["tax", "ta'e"]),
-        ("tapla", vec![]),
-        ("tarbi", vec![]),
-        ("tarci", vec!["tar"]),
-        ("tarla", vec![]),
-        ("tarmi", vec!["tam", "tai"]),
-        ("tarti", vec!["tra"]),
-        ("taske", vec![]),
-        ("tasmi", vec![]),
-        ("tasta", vec![]),
-        ("tatpi", vec!["ta'i"]),
-        ("tatru", vec!["tat"]),
-        ("tavla", vec!["tav", "ta'a"]),
-        ("taxfu", vec!["taf", "ta'u"]),
-        ("tcaci", vec!["cac"]),
-        ("tcadu", vec!["tca"]),
-        ("tcaka", vec![]),
-        ("tcame", vec![]),
-        ("tcana", vec![]),
-        ("tcati", vec![]),
-        ("tcaxe", vec![]),
-        ("tcema", vec![]),
-        ("tcemi", vec![]),
-        ("tcena", vec!["ten"]),
-        ("tcesa", vec![]),
-        ("tcese", vec![]),
-        ("tceta", vec![]),
-        ("tcica", vec!["tic"]),
-        ("tcidu", vec!["tid"]),
-        ("tcika", vec![]),
-        ("tcila", vec!["til"]),
-        ("tcima", vec!["tim", "ti'a"]),
-        ("tcini", vec![]),
-        ("tcipi", vec![]),
-        ("tcita", vec![]),
-        ("tcivi", vec![]),
-        ("tcobi", vec![]),
-        ("tcone", vec![]),
-        ("tcuti", vec![]),
-        ("te", vec!["ter"]),
-        ("tekla", vec![]),
-        ("temci", vec!["tem", "tei"]),
-        ("temse", vec![]),
-        ("tende", vec!["te'e"]),
-        ("tendo", vec![]),
-        ("tenfa", vec!["tef"]),
-        ("tengu", vec!["teg", "te'u"]),
-        ("terdi", vec!["ted"]),
-        ("terki", vec![]),
-        ("terpa", vec!["tep", "te'a"]),
-        ("terto", vec!["tet"]),
-        ("tezda", vec![]),
-        ("ti", vec!["tif"]),
-        ("tifri", vec![]),
-        ("tigni", vec!["tig"]),
-        ("tigra", vec![]),
-        ("tikpa", vec!["tip"]),
-        ("tilju", vec!["tij"]),
-        ("timno", vec![]),
-        ("tinbe", vec!["tib"]),
-        ("tinci", vec![]),
-        ("tinri", vec![]),
-        ("tinsa", vec![]),
-        ("tinto", vec![]),
-        ("tipre", vec![]),
-        ("tirna", vec!["tin"]),
-        ("tirse", vec!["tir"]),
-        ("tirxu", vec![]),
-        ("tisna", vec!["tis"]),
-        ("titla", vec!["tit"]),
-        ("tivni", vec!["tiv"]),
-        ("tixnu", vec!["tix", "ti'u"]),
-        ("tizva", vec![]),
-        ("to'e", vec!["tol", "to'e"]),
-        ("toknu", vec!["tok"]),
-        ("tokpo", vec!["top"]),
-        ("toldi", vec!["tod"]),
-        ("tolfa", vec![]),
-        ("tonga", vec!["tog", "to'a"]),
-        ("tonsi", vec!["tos"]),
-        ("tonzo", vec!["to'o"]),
-        ("tordu", vec!["tor", "to'u"]),
-        ("torka", vec![]),
-        ("torni", vec!["ton", "to'i"]),
-        ("torso", vec![]),
-        ("torta", vec![]),
-        ("tovla", vec![]),
-        ("traji", vec!["rai"]),
-        ("trano", vec![]),
-        ("trati", vec![]),
-        ("trene", vec!["ren", "re'e"]),
-        ("tricu", vec!["ric"]),
-        ("triga", vec![]),
-        ("trina", vec!["tri"]),
-        ("trixe", vec!["rix", "ti'e"]),
-        ("troci", vec!["toc", "toi"]),
-        ("tsaba", vec![]),
-        ("tsako", vec!["tso"]),
-        ("tsali", vec!["tsa"]),
-        ("tsani", vec!["tan"]),
-        ("tsapi", vec![]),
-        ("tsida", vec![]),
-        ("tsiju", vec!["tsi"]),
-        ("tsina", vec!["sin"]),
-        ("tsuku", vec![]),
-        ("tsuli", vec![]),
-        ("tu", vec!["tuf"]),
-        ("tubnu", vec!["tu'u"]),
-        ("tubra", vec![]),
-        ("tugni", vec!["tug", "tu'i"]),
-        ("tujli", vec!["tuj"]),
-        ("tulnu", vec![]),
-        ("tumla", vec!["tum", "tu'a"]),
-        ("tunba", vec!["tub"]),
-        ("tunka", vec!["tuk"]),
-        ("tunlo", vec!["tul", "tu'o"]),
-        ("tunta", vec!["tun"]),
-        ("tuple", vec!["tup", "tu'e"]),
-        ("turko", vec![]),
-        ("turni", vec!["tru"]),
-        ("tutci", vec!["tci"]),
-        ("tutle", vec![]),
-        ("tutra", vec!["tut"]),
-        ("va", vec!["vaz"]),
-        ("vacri", vec!["var"]),
-        ("vajni", vec!["vaj", "vai"]),
-        ("valfa", vec!["vaf"]),
-        ("valsi", vec!["val", "vla"]),
-        ("vamgu", vec![]),
-        ("vamji", vec!["vam", "va'i"]),
-        ("vamtu", vec!["vat"]),
-        ("vanbi", vec!["vab"]),
-        ("vanci", vec!["vac"]),
-        ("vanju", vec!["van"]),
-        ("vasru", vec!["vas", "vau"]),
-        ("vasxu", vec!["vax", "va'u"]),
-        ("ve", vec!["vel"]),
-        ("vecnu", vec!["ven", "ve'u"]),
-        ("vedli", vec!["ve'i"]),
-        ("ve'e", vec!["ve'e"]),
-        ("venfu", vec!["vef"]),
-        ("vensa", vec!["ves"]),
-        ("vente", vec![]),
-        ("vepre", vec![]),
-        ("verba", vec!["ver", "ve'a"]),
-        ("verjo", vec![]),
-        ("vetno", vec![]),
-        ("vi", vec!["viz"]),
-        ("vibna", vec!["vib"]),
-        ("vidni", vec![]),
-        ("vidru", vec!["vir"]),
-        ("vidvi", vec![]),
-        ("vifne", vec!["vif"]),
-        ("vikmi", vec!["vim", "vi'i"]),
-        ("viknu", vec!["vik"]),
-        ("vilco", vec![]),
-        ("vimcu", vec!["vic", "vi'u"]),
-        ("vindu", vec!["vid"]),
-        ("vinji", vec!["vij"]),
-        ("vinpa", vec![]),
-        ("vinta", vec![]),
-        ("vipsi", vec!["vip"]),
-        ("virme", vec![]),
-        ("virnu", vec!["vri"]),
-        ("viska", vec!["vis", "vi'a"]),
-        ("vitci", vec!["vit"]),
-        ("vitke", vec!["vi'e"]),
-        ("vitno", vec!["vi'o"]),
-        ("vlagi", vec!["lag"]),
-        ("vlile", vec!["vil"]),
-        ("vlina", vec![]),
-        ("vlipa", vec!["vli"]),
-        ("vo", vec!["von"]),
-        ("vofli", vec!["vol", "voi"]),
-        ("voksa", vec!["vok", "vo'a"]),
-        ("volve", vec![]),
-        ("vorme", vec!["vor", "vro"]),
-        ("vraga", vec!["vra"]),
-        ("vreji", vec!["rej", "vei"]),
-        ("vreta", vec!["vre"]),
-        ("vrici", vec![]),
-        ("vrude", vec!["vud", "vu'e"]),
-        ("vrusi", vec!["vus", "vu'i"]),
-        ("vu", vec!["vuz"]),
-        ("vubla", vec!["vul"]),
-        ("vujnu", vec!["vuj", "vu'u"]),
-        ("vukna", vec![]),
-        ("vukro", vec!["vur", "vu'o"]),
-        ("xa", vec!["xav"]),
-        ("xabju", vec!["xa'u"]),
-        ("xadba", vec!["xab"]),
-        ("xadji", vec![]),
-        ("xadni", vec!["xad"]),
-        ("xagji", vec![]),
-        ("xagri", vec![]),
-        ("xajmi", vec!["xam"]),
-        ("xaksu", vec!["xak"]),
-        ("xakto", vec![]),
-        ("xalbo", vec![]),
-        ("xalda", vec![]),
-        ("xalka", vec!["xal"]),
-        ("xalni", vec![]),
-        ("xamgu", vec!["xag", "xau"]),
-        ("xampo", vec!["xap", "xa'o"]),
-        ("xamsi", vec!["xas"]),
-        ("xance", vec!["xan", "xa'e"]),
-        ("xango", vec![]),
-        ("xanka", vec![]),
-        ("xanmi", vec![]),
-        ("xanri", vec!["xar"]),
-        ("xansa", vec![]),
-        ("xanto", vec![]),
-        ("xarci", vec!["xac", "xa'i"]),
-        ("xarju", vec!["xaj"]),
-        ("xarmi", vec![]),
-        ("xarnu", vec![]),
-        ("xasli", vec![]),
-        ("xasne", vec![]),
-        ("xatra", vec!["xa'a"]),
-        ("xatsi", vec!["xat"]),
-        ("xavli", vec![]),
-        ("xaxre", vec![]),
-        ("xazdo", vec!["xaz", "zdo"]),
-        ("xe", vec!["xel"]),
-        ("xebni", vec!["xen", "xei"]),
-        ("xebro", vec!["xeb", "bro"]),
-        ("xecto", vec!["xet", "cto"]),
-        ("xedja", vec!["xej", "xe'a"]),
-        ("xekce", vec!["xec", "xe'e"]),
-        ("xekri", vec!["xek", "xe'i"]),
-        ("xelso", vec!["xes"]),
-        ("xendo", vec!["xed", "xe'o"]),
-        ("xenmu", vec![]),
-        ("xenru", vec!["xer", "xe'u"]),
-        ("xerno", vec![]),
-        ("xexso", vec!["xex"]),
-        ("xexto", vec![]),
-        ("xigzo", vec!["xig"]),
-        ("xijra", vec![]),
-        ("xilva", vec!["xiv"]),
-        ("ximta", vec![]),
-        ("xindo", vec!["xin"]),
-        ("xinmo", vec!["xim"]),
-        ("xinro", vec![]),
-        ("xinsi", vec![]),
-        ("xirma", vec!["xir", "xi'a"]),
-        ("xislu", vec!["xil", "xi'u"]),
-        ("xispo", vec!["xip"]),
-        ("xixli", vec![]),
-        ("xixnu", vec![]),
-        ("xlali", vec!["xla"]),
-        ("xlobi", vec![]),
-        ("xlura", vec!["xlu"]),
-        ("xoltu", vec![]),
-        ("xonba", vec![]),
-        ("xorbo", vec![]),
-        ("xorlo", vec![]),
-        ("xotli", vec!["xol", "xoi"]),
-        ("xrabo", vec!["rab"]),
-        ("xrani", vec!["xai"]),
-        ("xrata", vec![]),
-        ("xriso", vec!["xis", "xi'o"]),
-        ("xroma", vec![]),
-        ("xrotu", vec!["xro"]),
-        ("xruba", vec!["xub"]),
-        ("xruki", vec!["xuk"]),
-        ("xrula", vec!["rul"]),
-        ("xruti", vec!["xru"]),
-        ("xudvu", vec!["xu'u"]),
-        ("xugri", vec![]),
-        ("xukmi", vec!["xum", "xu'i"]),
-        ("xulta", vec!["xut"]),
-        ("xumbe", vec![]),
-        ("xunre", vec!["xun", "xu'e"]),
-        ("xurdo", vec!["xur", "xu'o"]),
-        ("xusra", vec!["xus", "xu'a"]),
-        ("xutla", vec!["xul"]),
-        ("zabna", vec!["zan", "za'a"]),
-        ("za'i", vec!["zaz"]),
-        ("zajba", vec!["zaj"]),
-        ("zalvi", vec!["zal"]),
-        ("zandi", vec!["zad"]),
-        ("zanru", vec!["zar", "zau"]),
-        ("zanxi", vec![]),
-        ("za'o", vec!["za'o"]),
-        ("zarci", vec!["zac", "zai"]),
-        ("zargu", vec!["zag", "za'u"]),
-        ("zasni", vec!["zas"]),
-        ("zasti", vec!["zat", "za'i"]),
-        ("zbabu", vec!["bab"]),
-        ("zbani", vec![]),
-        ("zbasu", vec!["zba"]),
-        ("zbepi", vec!["zbe"]),
-        ("zdani", vec!["zda"]),
-        ("zdile", vec!["zdi"]),
-        ("ze", vec!["zel"]),
-        ("ze'e", vec!["ze'e"]),
-        ("zejvo", vec![]),
-        ("zekri", vec!["zer", "zei"]),
-        ("zenba", vec!["zen", "ze'a"]),
-        ("ze'o", vec!["zev", "ze'o"]),
-        ("zepti", vec!["zep"]),
-        ("zergi", vec![]),
-        ("zetro", vec!["zet"]),
-        ("zevla", vec!["zve"]),
-        ("zgadi", vec![]),
-        ("zgana", vec!["zga"]),
-        ("zgike", vec!["gi'e", "zgi"]),
-        ("zguro", vec![]),
-        ("zifre", vec!["zif", "zi'e"]),
-        ("zinki", vec!["zin", "zi'i"]),
-        ("zi'o", vec!["zil"]),
-        ("zirli", vec![]),
-        ("zirpu", vec!["zir", "zi'u"]),
-        ("zivle", vec!["ziv", "vle"]),
-        ("zmadu", vec!["zma", "mau"]),
-        ("zmase", vec![]),
-        ("zmico", vec!["zic"]),
-        ("zmiku", vec!["zmi"]),
-        ("zo'a", vec!["zon", "zo'a"]),
-        ("zo'i", vec!["zor", "zo'i"]),
-        ("zorti", vec![]),
-        ("zozno", vec![]),
-        ("zubra", vec![]),
-        ("zucna", vec!["zuc", "zu'a"]),
-        ("zukte", vec!["zuk", "zu'e"]),
-        ("zulna", vec![]),
-        ("zumri", vec!["zmu"]),
-        ("zungi", vec!["zug"]),
-        ("zunle", vec!["zul"]),
-        ("zunti", vec!["zun", "zu'i"]),
-        ("zu'o", vec!["zum"]),
-        ("zurja", vec!["zuj"]),
-        ("zutse", vec!["zut", "tse"]),
-        ("zvati", vec!["zva"]),
-        ("zviki", vec!["zvi"]),
-        ("zvomo", vec!["zvo"]),
-        ("zvuzu", vec![]),
-        ("xi", vec!["xix"]),
-        ("zai'e", vec!["zam"]),
-        ("gelse", vec!["ges", "ge'e"]),
-        ("je'ebzi", vec!["jeb"]),
-        ("mu'umgu", vec!["mug"]),
-        ("va'arga", vec!["va'a"]),
-        ("co'ai", vec!["cox"]),
-        ("dau", vec!["duv"]),
-        ("fei", vec!["fel"]),
-        ("ga'ei", vec!["gel"]),
-        ("gai", vec!["gam"]),
-        ("jau", vec!["juz"]),
-        ("ke'ai", vec!["ked"]),
-        ("ke'ei", vec!["keb"]),
-        ("ke'ei'a", vec!["kex"]),
-        ("lai'ai", vec!["lav"]),
-        ("sa", vec!["saf"]),
-        ("si", vec!["sif"]),
-        ("su", vec!["suf"]),
-        ("su'ei", vec!["sem"]),
-        ("tau'u", vec!["tux"]),
-        ("to'ai", vec!["toz"]),
-        ("vai", vec!["vav"]),
-        ("vo'a", vec!["vob"]),
-        ("vo'ai", vec!["voz"]),
-        ("xei", vec!["xem"]),
-        ("xo'ai", vec!["xoz"]),
-        ("xo'ei", vec!["xon"]),
-        ("zei'e", vec!["zex"]),
-        ("zei'o", vec!["zes"]),
-    ])
-});
+
+/// Big giant rafsi list, sorted by word.
+pub const RAFSI_LIST: &[(&str, &[&str])] = &[
+    ("Pingo", &[]),
+    ("babda", &[]),
+    ("bablo", &[]),
+    ("backi", &[]),
+    ("bacru", &["ba'u"]),
+    ("badji", &[]),
+    ("badna", &[]),
+    ("badri", &["dri"]),
+    ("bajra", &["baj"]),
+    ("bakfu", &["baf"]),
+    ("bakni", &["bak"]),
+    ("bakri", &[]),
+    ("baktu", &[]),
+    ("balji", &[]),
+    ("balni", &[]),
+    ("balre", &["ba'e"]),
+    ("balto", &[]),
+    ("balvi", &["bav"]),
+    ("bambu", &[]),
+    ("bamri", &[]),
+    ("bancu", &["bac"]),
+    ("bandu", &["bad"]),
+    ("banfi", &[]),
+    ("bangu", &["ban", "bau"]),
+    ("banli", &["bal", "ba'i"]),
+    ("banro", &["ba'o"]),
+    ("banxa", &["bax"]),
+    ("banzu", &["baz"]),
+    ("bapli", &["bap", "bai"]),
+    ("barda", &["bra"]),
+    ("bargu", &["bag"]),
+    ("barja", &[]),
+    ("barna", &["ba'a"]),
+    ("bartu", &["bar"]),
+    ("basfa", &[]),
+    ("baslu", &[]),
+    ("basna", &[]),
+    ("basti", &["bas"]),
+    ("batci", &["bat"]),
+    ("batke", &[]),
+    ("bavmi", &[]),
+    ("bavza", &[]),
+    ("baxso", &[]),
+    ("bebna", &["beb"]),
+    ("bekpi", &[]),
+    ("belta", &[]),
+    ("bemro", &["bem", "be'o"]),
+    ("bende", &["bed", "be'e"]),
+    ("bengo", &["beg"]),
+    ("benji", &["bej", "be'i"]),
+    ("benre", &[]),
+    ("benzo", &[]),
+    ("bergu", &[]),
+    ("bersa", &["bes", "be'a"]),
+    ("berti", &["ber"]),
+    ("besna", &["ben"]),
+    ("besto", &[]),
+    ("betfu", &["bef", "be'u"]),
+    ("betka", &[]),
+    ("betri", &["bet"]),
+    ("bevri", &["bev", "bei"]),
+    ("bi", &["biv"]),
+    ("bi'i", &["biz"]),
+    ("bidju", &[]),
+    ("bifce", &["bic"]),
+    ("bikla", &["bik"]),
+    ("bilbo", &[]),
+    ("bilga", &["big"]),
+    ("bilma", &["bi'a"]),
+    ("bilni", &["bil"]),
+    ("bindo", &["bid"]),
+    ("binma", &[]),
+    ("binra", &[]),
+    ("binxo", &["bix", "bi'o"]),
+    ("birco", &[]),
+    ("birje", &[]),
+    ("birka", &["bir"]),
+    ("birti", &["bit"]),
+    ("bisli", &["bis"]),
+    ("bitco", &[]),
+    ("bitmu", &["bim", "bi'u"]),
+    ("bitni", &[]),
+    ("blabi", &["lab"]),
+    ("blaci", &[]),
+    ("blanu", &["bla"]),
+    ("bliku", &["bli"]),
+    ("blina", &[]),
+    ("blolo", &[]),
+    ("bloti", &["lot", "lo'i", "blo"]),
+    ("bluji", &[]),
+    ("bo", &["bor"]),
+    ("bolci", &["bol", "boi"]),
+    ("bongu", &["bog", "bo'u"]),
+    ("bonta", &[]),
+    ("borma", &[]),
+    ("boske", &[]),
+    ("botpi", &["bot", "bo'i"]),
+    ("boxfo", &["bof", "bo'o"]),
+    ("boxna", &["bon", "bo'a"]),
+    ("bradi", &[]),
+    ("brano", &[]),
+    ("bratu", &[]),
+    ("bravi", &[]),
+    ("brazo", &["raz"]),
+    ("bredi", &["red", "bre"]),
+    ("bridi", &["bri"]),
+    ("brife", &["bif", "bi'e"]),
+    ("briju", &["bij"]),
+    ("brito", &["rit"]),
+    ("brivo", &[]),
+    ("broda", &["rod"]),
+    ("brode", &["bo'e"]),
+    ("brodi", &[]),
+    ("brodo", &[]),
+    ("brodu", &[]),
+    ("bruci", &[]),
+    ("bruna", &["bun", "bu'a"]),
+    ("bu", &["bus", "bu'i"]),
+    ("bu'a", &["bul"]),
+    ("budjo", &["buj", "bu'o"]),
+    ("budza", &[]),
+    ("bukpu", &["buk", "bu'u"]),
+    ("bumru", &["bum"]),
+    ("bunda", &["bud"]),
+    ("bunre", &["bur", "bu'e"]),
+    ("buntu", &[]),
+    ("bunxa", &[]),
+    ("burcu", &["bru"]),
+    ("burka", &[]),
+    ("burna", &[]),
+    ("buvla", &[]),
+    ("ca'a", &["caz"]),
+    ("cabna", &["cab"]),
+    ("cabra", &["ca'a"]),
+    ("cacra", &[]),
+    ("cadga", &[]),
+    ("cadma", &[]),
+    ("cadzu", &["dzu"]),
+    ("cafne", &["caf"]),
+    ("cagna", &[]),
+    ("cajni", &[]),
+    ("cakla", &[]),
+    ("calku", &["cak"]),
+    ("calse", &[]),
+    ("campu", &[]),
+    ("canci", &[]),
+    ("cando", &["cad"]),
+    ("cange", &["cag"]),
+    ("canja", &["caj"]),
+    ("canko", &["ca'o"]),
+    ("canlu", &["cal", "ca'u"]),
+    ("canma", &[]),
+    ("canpa", &["cna"]),
+    ("canre", &["can"]),
+    ("canti", &[]),
+    ("carce", &[]),
+    ("carfu", &[]),
+    ("carmi", &["cam", "cai"]),
+    ("carna", &["car"]),
+    ("cartu", &["cat"]),
+    ("carvi", &["cav"]),
+    ("casnu", &["snu"]),
+    ("catke", &["ca'e"]),
+    ("catlu", &["cta"]),
+    ("catni", &["ca'i"]),
+    ("catra", &[]),
+    ("caxno", &["cax"]),
+    ("ce", &["cec"]),
+    ("ce'i", &["cez"]),
+    ("ce'o", &["ce'o"]),
+    ("cecla", &["cel", "ce'a"]),
+    ("cecmu", &["cem", "ce'u"]),
+    ("cedra", &[]),
+    ("celdi", &["cle"]),
+    ("celxa", &[]),
+    ("cenba", &["cne"]),
+    ("cendo", &[]),
+    ("censa", &["ces"]),
+    ("centi", &["cen"]),
+    ("cerda", &["ced"]),
+    ("cerni", &["cer"]),
+    ("certu", &["cre"]),
+    ("cevni", &["cev", "cei"]),
+    ("cfale", &[]),
+    ("cfari", &["cfa"]),
+    ("cfika", &["fik", "fi'a"]),
+    ("cfila", &["cfi"]),
+    ("cfine", &[]),
+    ("cfipu", &["fi'u"]),
+    ("ci", &["cib"]),
+    ("ciblu", &["blu"]),
+    ("cicna", &[]),
+    ("cidja", &["dja"]),
+    ("cidni", &["cid"]),
+    ("cidro", &["dro"]),
+    ("cifnu", &["cif"]),
+    ("cigla", &["cig"]),
+    ("cikna", &["cik"]),
+    ("cikre", &[]),
+    ("ciksi", &["cki"]),
+    ("cilce", &["cic"]),
+    ("cilfu", &[]),
+    ("cilga", &[]),
+    ("cilmo", &["cim"]),
+    ("cilre", &["cli"]),
+    ("cilta", &["cil"]),
+    ("cimde", &[]),
+    ("cimle", &[]),
+    ("cimni", &[]),
+    ("cimra", &[]),
+    ("cinba", &[]),
+    ("cindu", &[]),
+    ("cinfo", &[]),
+    ("cinje", &["cij"]),
+    ("cinki", &[]),
+    ("cinla", &[]),
+    ("cinmo", &["cni"]),
+    ("cinri", &["ci'i"]),
+    ("cinse", &["cin"]),
+    ("cinta", &[]),
+    ("cinza", &[]),
+    ("cipni", &["cpi"]),
+    ("cipra", &["cip"]),
+    ("cirko", &["cri"]),
+    ("cirla", &[]),
+    ("ciska", &["ci'a"]),
+    ("cisma", &[]),
+    ("cisni", &[]),
+    ("ciste", &["ci'e"]),
+    ("citka", &["cti"]),
+    ("citno", &["cit", "ci'o"]),
+    ("citri", &["cir"]),
+    ("citsi", &[]),
+    ("civla", &["civ"]),
+    ("cizra", &["ciz"]),
+    ("ckabu", &[]),
+    ("ckafi", &["kaf"]),
+    ("ckaji", &["kai"]),
+    ("ckana", &["cka"]),
+    ("ckape", &["cap"]),
+    ("ckasu", &["cas"]),
+    ("ckeji", &["kej", "cke"]),
+    ("ckeno", &[]),
+    ("ckiku", &["kik"]),
+    ("ckilu", &["ci'u"]),
+    ("ckini", &["ki'i"]),
+    ("ckire", &["kir"]),
+    ("ckopi", &[]),
+    ("ckule", &["kul", "cu'e"]),
+    ("ckunu", &["ku'u"]),
+    ("cladu", &["lau"]),
+    ("clafi", &[]),
+    ("clani", &["cla"]),
+    ("claxu", &["cau"]),
+    ("clenu", &[]),
+    ("cletu", &[]),
+    ("clika", &[]),
+    ("clinu", &[]),
+    ("clira", &["lir"]),
+    ("clite", &["lit"]),
+    ("cliva", &["liv", "li'a"]),
+    ("clupa", &["cup"]),
+    ("cmaci", &[]),
+    ("cmalu", &["cma"]),
+    ("cmana", &["ma'a"]),
+    ("cmavo", &["ma'o"]),
+    ("cmene", &["me'e", "cme"]),
+    ("cmero", &[]),
+    ("cmeta", &[]),
+    ("cmevo", &[]),
+    ("cmiju", &[]),
+    ("cmila", &["mi'a"]),
+    ("cmima", &["mim", "cmi"]),
+    ("cmoni", &["co'i", "cmo"]),
+    ("cnano", &["na'o"]),
+    ("cnata", &[]),
+    ("cnebo", &["neb", "ne'o"]),
+    ("cnemu", &["nem", "ne'u"]),
+    ("cnici", &["nic"]),
+    ("cnida", &[]),
+    ("cnino", &["nin", "ni'o"]),
+    ("cnisa", &["nis"]),
+    ("cnita", &["nit", "ni'a"]),
+    ("co", &["col"]),
+    ("co'a", &["co'a"]),
+    ("co'ai", &["cox"]),
+    ("co'e", &["com", "co'e"]),
+    ("co'u", &["co'u"]),
+    ("cokcu", &["cko"]),
+    ("cokte", &[]),
+    ("condi", &["con", "cno", "coi"]),
+    ("conka", &[]),
+    ("corci", &["coc"]),
+    ("cortu", &["cor", "cro"]),
+    ("cotci", &[]),
+    ("cpacu", &["cpa"]),
+    ("cpana", &[]),
+    ("cpare", &["par"]),
+    ("cpedu", &["cpe"]),
+    ("cpina", &[]),
+    ("cpixa", &[]),
+    ("cradi", &[]),
+    ("crami", &[]),
+    ("crane", &["cra"]),
+    ("cravi", &[]),
+    ("creka", &["cek"]),
+    ("crepu", &["rep"]),
+    ("creze", &[]),
+    ("cribe", &["rib"]),
+    ("crida", &["rid"]),
+    ("crino", &["ri'o"]),
+    ("cripu", &["rip"]),
+    ("crisa", &["cis"]),
+    ("critu", &[]),
+    ("ctaru", &[]),
+    ("ctebi", &["teb"]),
+    ("cteki", &["tek", "ce'i"]),
+    ("ctera", &[]),
+    ("ctile", &[]),
+    ("ctino", &["ti'o"]),
+    ("ctuca", &["ctu"]),
+    ("cukla", &["cuk"]),
+    ("cukre", &[]),
+    ("cukse", &[]),
+    ("cukta", &["cku"]),
+    ("culno", &["clu"]),
+    ("cumba", &[]),
+    ("cumki", &["cum", "cu'i"]),
+    ("cumla", &["cul"]),
+    ("cunmi", &[]),
+    ("cunso", &["cun", "cu'o"]),
+    ("cuntu", &["cu'u"]),
+    ("cupra", &["pra"]),
+    ("curmi", &["cru"]),
+    ("curnu", &["cur"]),
+    ("curve", &["cuv"]),
+    ("cusku", &["cus", "sku"]),
+    ("cusna", &[]),
+    ("cuspe", &[]),
+    ("cutci", &["cuc"]),
+    ("cutne", &["cut"]),
+    ("cuxna", &["cux", "cu'a"]),
+    ("da", &["dav", "dza"]),
+    ("da'a", &["daz"]),
+    ("dacru", &["dac"]),
+    ("dacti", &["dai"]),
+    ("dadjo", &["daj"]),
+    ("dajbu", &[]),
+    ("dajne", &[]),
+    ("dakfu", &["dak"]),
+    ("dakli", &[]),
+    ("dalti", &[]),
+    ("damba", &["dab", "da'a"]),
+    ("damko", &[]),
+    ("damri", &[]),
+    ("danci", &[]),
+    ("dandu", &["dad"]),
+    ("danfu", &["daf"]),
+    ("danke", &[]),
+    ("danlu", &["dal", "da'u"]),
+    ("danmo", &["dam"]),
+    ("danre", &["da'e"]),
+    ("dansu", &[]),
+    ("danti", &["dan"]),
+    ("daplu", &["plu"]),
+    ("dapma", &["dap"]),
+    ("darca", &[]),
+    ("dardu", &[]),
+    ("dargu", &["dag"]),
+    ("darlu", &["dau"]),
+    ("darno", &["dar", "da'o"]),
+    ("darsi", &[]),
+    ("darxi", &["dax", "da'i"]),
+    ("daski", &[]),
+    ("dasni", &["das"]),
+    ("daspo", &["spo"]),
+    ("dasri", &["sri"]),
+    ("datca", &[]),
+    ("datka", &[]),
+    ("datni", &[]),
+    ("datro", &[]),
+    ("datru", &[]),
+    ("datxo", &[]),
+    ("dau", &["duv"]),
+    ("decti", &["dec"]),
+    ("degji", &["deg"]),
+    ("dejni", &["dej"]),
+    ("dekpu", &[]),
+    ("dekto", &["dek"]),
+    ("delca", &[]),
+    ("delno", &["del", "de'o"]),
+    ("delta", &[]),
+    ("dembi", &["deb"]),
+    ("denci", &["den", "de'i"]),
+    ("dendi", &[]),
+    ("denmi", &["dem"]),
+    ("denpa", &["dep", "de'a"]),
+    ("dertu", &["der", "de'u"]),
+    ("derxi", &["dre"]),
+    ("desku", &["des"]),
+    ("detri", &["det"]),
+    ("dicma", &[]),
+    ("dicra", &["dir"]),
+    ("didni", &[]),
+    ("digno", &[]),
+    ("dikca", &["dic"]),
+    ("diklo", &["klo"]),
+    ("dikni", &["dik"]),
+    ("dilcu", &[]),
+    ("dildo", &[]),
+    ("dilma", &[]),
+    ("dilnu", &["dil"]),
+    ("dimna", &["dim"]),
+    ("dindi", &["did"]),
+    ("dinju", &["dij", "di'u"]),
+    ("dinko", &["di'o"]),
+    ("dinso", &["dis"]),
+    ("dinti", &[]),
+    ("dirba", &["dib"]),
+    ("dirce", &["di'e"]),
+    ("dirgo", &["dig"]),
+    ("disko", &[]),
+    ("ditcu", &["dit"]),
+    ("divzi", &[]),
+    ("dizlo", &["diz", "dzi"]),
+    ("djacu", &["jac", "jau"]),
+    ("djedi", &["dje", "dei"]),
+    ("djica", &["dji"]),
+    ("djika", &[]),
+    ("djine", &["jin"]),
+    ("djuno", &["jun", "ju'o"]),
+    ("do", &["don", "doi"]),
+    ("dokcu", &["do'u"]),
+    ("dolna", &["dol"]),
+    ("donri", &["dor", "do'i"]),
+    ("dotco", &["dot", "do'o"]),
+    ("draci", &[]),
+    ("drani", &["dra"]),
+    ("drata", &["dat"]),
+    ("drisu", &[]),
+    ("drudi", &["rud", "dru"]),
+    ("du", &["dub", "du'o"]),
+    ("du'u", &["dum"]),
+    ("dugri", &["dug"]),
+    ("dukni", &[]),
+    ("dukse", &["dus", "du'e"]),
+    ("dukti", &["dut"]),
+    ("dulnu", &[]),
+    ("dunda", &["dud", "du'a"]),
+    ("dunja", &["duj"]),
+    ("dunku", &["duk", "du'u"]),
+    ("dunli", &["dun", "du'i"]),
+    ("dunra", &["dur"]),
+    ("durna", &[]),
+    ("dutso", &[]),
+    ("dzama", &[]),
+    ("dzena", &["dze"]),
+    ("dzipo", &["zip", "zi'o"]),
+    ("dzoli", &["dzo"]),
+    ("dzuli", &[]),
+    ("dzuma", &[]),
+    ("facki", &["fak", "fa'i"]),
+    ("facni", &[]),
+    ("fadni", &["fad"]),
+    ("fagri", &["fag"]),
+    ("falnu", &["fan"]),
+    ("famti", &[]),
+    ("fancu", &[]),
+    ("fange", &[]),
+    ("fanji", &[]),
+    ("fanmo", &["fam", "fa'o"]),
+    ("fanri", &[]),
+    ("fanta", &[]),
+    ("fanva", &[]),
+    ("fanza", &["faz"]),
+    ("fapro", &["fap", "pro"]),
+    ("fardu", &[]),
+    ("farka", &[]),
+    ("farlu", &["fal", "fa'u"]),
+    ("farna", &["far", "fa'a"]),
+    ("farvi", &["fav"]),
+    ("fasnu", &["fau"]),
+    ("fatci", &["fac"]),
+    ("fatne", &["fat", "fa'e"]),
+    ("fatri", &["fai"]),
+    ("febvi", &["feb"]),
+    ("fegli", &[]),
+    ("fei", &["fel"]),
+    ("femti", &["fem"]),
+    ("fendi", &["fed"]),
+    ("fenfa", &[]),
+    ("fengu", &["feg", "fe'u"]),
+    ("fenki", &["fek"]),
+    ("fenra", &["fer", "fe'a"]),
+    ("fenso", &["fen", "fe'o"]),
+    ("fepni", &["fep", "fei"]),
+    ("fepri", &[]),
+    ("ferti", &["fre"]),
+    ("festi", &["fes"]),
+    ("fetsi", &["fet", "fe'i"]),
+    ("fibra", &[]),
+    ("fidna", &["fid"]),
+    ("figre", &["fig"]),
+    ("filso", &["fis"]),
+    ("finpe", &["fip", "fi'e"]),
+    ("finti", &["fin", "fi'i"]),
+    ("firca", &[]),
+    ("fisli", &[]),
+    ("fizbu", &["zbu"]),
+    ("fizde", &["fiz"]),
+    ("flaci", &[]),
+    ("flalu", &["fla"]),
+    ("flani", &[]),
+    ("flecu", &["fle"]),
+    ("flese", &["les"]),
+    ("fliba", &["fli"]),
+    ("flipo", &[]),
+    ("flira", &["fir"]),
+    ("fo'a", &["fo'a"]),
+    ("fo'e", &["fo'e"]),
+    ("fo'i", &["fo'i"]),
+    ("foldi", &["flo", "foi"]),
+    ("fonmo", &["fom", "fo'o"]),
+    ("fonto", &[]),
+    ("fonxa", &["fon"]),
+    ("forca", &["fro"]),
+    ("forse", &[]),
+    ("fraso", &["fas"]),
+    ("frati", &["fra"]),
+    ("fraxu", &["fax"]),
+    ("frica", &["fic"]),
+    ("friko", &["fi'o"]),
+    ("frili", &["fil"]),
+    ("frinu", &[]),
+    ("friti", &["fit"]),
+    ("frumu", &["fru"]),
+    ("fugvo", &[]),
+    ("fukpi", &["fuk", "fu'i"]),
+    ("fulta", &["ful", "flu"]),
+    ("funca", &["fun", "fu'a"]),
+    ("fusra", &["fur"]),
+    ("fuvla", &[]),
+    ("fuzme", &["fuz", "fu'e"]),
+    ("ga'ei", &["gel"]),
+    ("gacri", &["gai"]),
+    ("gadri", &["gad"]),
+    ("gai", &["gam"]),
+    ("galfi", &["gaf", "ga'i"]),
+    ("galki", &[]),
+    ("galtu", &["gal", "ga'u"]),
+    ("galxe", &[]),
+    ("ganda", &[]),
+    ("ganlo", &["ga'o"]),
+    ("ganra", &["gan"]),
+    ("ganse", &["gas", "ga'e"]),
+    ("ganti", &[]),
+    ("ganvi", &[]),
+    ("ganxo", &["gax"]),
+    ("ganzu", &["gaz"]),
+    ("gapci", &["gac"]),
+    ("gapru", &["gap"]),
+    ("gardu", &[]),
+    ("garna", &["gar"]),
+    ("gasnu", &["gau"]),
+    ("gaspo", &[]),
+    ("gasta", &["gat"]),
+    ("gelga", &[]),
+    ("gelse", &["ges", "ge'e"]),
+    ("genja", &["gej"]),
+    ("gento", &["get", "ge'o"]),
+    ("genxu", &["gex"]),
+    ("gerku", &["ger", "ge'u"]),
+    ("germo", &["gem"]),
+    ("gerna", &["gen", "ge'a"]),
+    ("gicmu", &[]),
+    ("gidva", &["gid", "gi'a"]),
+    ("gigdo", &["gig", "gi'o"]),
+    ("ginka", &["gik"]),
+    ("girzu", &["gir", "gri"]),
+    ("gismu", &["gim", "gi'u"]),
+    ("glare", &["gla"]),
+    ("gleki", &["gek", "gei"]),
+    ("gletu", &["let", "gle"]),
+    ("glico", &["gic", "gli"]),
+    ("glife", &[]),
+    ("glosa", &[]),
+    ("gluli", &[]),
+    ("gluta", &["glu"]),
+    ("gocti", &["goc"]),
+    ("gomja", &["gom"]),
+    ("gomsi", &[]),
+    ("gonte", &[]),
+    ("gotro", &["got"]),
+    ("gradu", &["rau"]),
+    ("grafu", &[]),
+    ("grake", &["gra"]),
+    ("grame", &[]),
+    ("grana", &["ga'a"]),
+    ("grasu", &["ras"]),
+    ("grava", &["gav"]),
+    ("greku", &["rek"]),
+    ("grusi", &["rus"]),
+    ("grute", &["rut"]),
+    ("gubni", &["gub"]),
+    ("gugde", &["gug", "gu'e"]),
+    ("gugle", &[]),
+    ("gumri", &[]),
+    ("gundi", &["gud"]),
+    ("gunka", &["gun", "gu'a"]),
+    ("gunma", &["gum"]),
+    ("gunro", &["gur", "gu'o"]),
+    ("gunse", &[]),
+    ("gunta", &["gut"]),
+    ("gurni", &["gru"]),
+    ("guska", &["guk"]),
+    ("gusni", &["gus", "gu'i"]),
+    ("gusta", &[]),
+    ("gutci", &["guc"]),
+    ("gutra", &[]),
+    ("guzme", &["guz", "zme"]),
+    ("ja", &["jav"]),
+    ("jabre", &[]),
+    ("jadji", &[]),
+    ("jadni", &["jad", "ja'i"]),
+    ("jai", &["jax"]),
+    ("jakne", &[]),
+    ("jaktu", &[]),
+    ("jaldi", &[]),
+    ("jalge", &["jag", "ja'e"]),
+    ("jalna", &[]),
+    ("jalra", &[]),
+    ("jamfu", &["jaf", "jma"]),
+    ("jamna", &["jam"]),
+    ("janbe", &["jab"]),
+    ("janco", &["jan"]),
+    ("janka", &[]),
+    ("janli", &["jal"]),
+    ("jansu", &["jas"]),
+    ("janta", &["jat"]),
+    ("jarbu", &[]),
+    ("jarco", &["ja'o"]),
+    ("jarki", &["jak"]),
+    ("jaspu", &["jap"]),
+    ("jatna", &["ja'a"]),
+    ("jatsu", &[]),
+    ("jau", &["juz"]),
+    ("javni", &["jva"]),
+    ("jbama", &["bam"]),
+    ("jbari", &["jba"]),
+    ("jbena", &["jbe"]),
+    ("jbera", &["jer"]),
+    ("jbini", &["bin", "bi'i"]),
+    ("jbobo", &[]),
+    ("jdari", &["jar"]),
+    ("jdice", &["jdi"]),
+    ("jdika", &[]),
+    ("jdima", &["di'a"]),
+    ("jdini", &["din", "di'i"]),
+    ("jduli", &["dul", "jdu"]),
+    ("je", &["jev", "jve"]),
+    ("je'ebzi", &["jeb"]),
+    ("jecta", &["jec", "je'a"]),
+    ("jeftu", &["jef"]),
+    ("jegvo", &["jeg", "je'o"]),
+    ("jei", &["jez"]),
+    ("jelca", &["jel"]),
+    ("jemna", &["jme"]),
+    ("jenca", &["jen"]),
+    ("jendu", &["jed"]),
+    ("jenje", &[]),
+    ("jenmi", &["jem", "jei"]),
+    ("jensi", &[]),
+    ("jerna", &[]),
+    ("jersi", &["je'i"]),
+    ("jerxo", &["jex"]),
+    ("jesni", &["jes"]),
+    ("jetce", &["je'e"]),
+    ("jetnu", &["jet", "je'u"]),
+    ("jgalu", &["ja'u"]),
+    ("jganu", &["jga"]),
+    ("jgari", &["jai"]),
+    ("jgena", &["jge"]),
+    ("jgina", &["gin"]),
+    ("jgira", &["jgi"]),
+    ("jgita", &["git"]),
+    ("jguna", &["jgu"]),
+    ("jguvi", &[]),
+    ("jibni", &["jbi"]),
+    ("jibri", &["jib"]),
+    ("jicfo", &["cfo"]),
+    ("jicla", &[]),
+    ("jicmu", &["cmu"]),
+    ("jidge", &["jid"]),
+    ("jijgi", &[]),
+    ("jijnu", &["jij"]),
+    ("jikca", &["jik"]),
+    ("jikfi", &[]),
+    ("jikni", &[]),
+    ("jikru", &[]),
+    ("jilka", &["jil"]),
+    ("jilra", &[]),
+    ("jimca", &["jic"]),
+    ("jimpe", &["jmi"]),
+    ("jimte", &["jit"]),
+    ("jinci", &[]),
+    ("jinda", &[]),
+    ("jinga", &["jig", "ji'a"]),
+    ("jinku", &[]),
+    ("jinme", &["jim"]),
+    ("jinru", &["jir"]),
+    ("jinsa", &["jis"]),
+    ("jinto", &[]),
+    ("jinvi", &["jiv", "ji'i"]),
+    ("jinzi", &["jiz"]),
+    ("jipci", &[]),
+    ("jipno", &["jip", "ji'o"]),
+    ("jirna", &[]),
+    ("jisra", &[]),
+    ("jitfa", &["jif"]),
+    ("jitni", &[]),
+    ("jitro", &["tro"]),
+    ("jivbu", &[]),
+    ("jivna", &["jvi"]),
+    ("jmaji", &["jaj"]),
+    ("jmedu", &[]),
+    ("jmice", &[]),
+    ("jmifa", &[]),
+    ("jmina", &["min"]),
+    ("jmite", &[]),
+    ("jmive", &["miv", "ji'e"]),
+    ("jo", &["jov"]),
+    ("jo'e", &["jom"]),
+    ("jo'u", &["jo'u"]),
+    ("joi", &["jol", "joi"]),
+    ("jonse", &["jos"]),
+    ("jordo", &["jor", "jo'o"]),
+    ("jorne", &["jon", "jo'e"]),
+    ("ju", &["juv"]),
+    ("jubme", &["jub", "jbu"]),
+    ("judri", &[]),
+    ("jufra", &["juf", "ju'a"]),
+    ("jukni", &["juk"]),
+    ("jukpa", &["jup"]),
+    ("julne", &["ju'e"]),
+    ("julro", &[]),
+    ("jundi", &["jud", "ju'i"]),
+    ("jungo", &["jug"]),
+    ("junla", &["jul"]),
+    ("junri", &["jur"]),
+    ("junta", &[]),
+    ("jurme", &["jum"]),
+    ("jursa", &["jus"]),
+    ("jutsi", &["jut"]),
+    ("juxre", &["jux"]),
+    ("jvinu", &["vin", "ji'u"]),
+    ("jviso", &[]),
+    ("jvoso", &["vos"]),
+    ("ka", &["kam"]),
+    ("kabri", &[]),
+    ("kacma", &[]),
+    ("kadno", &[]),
+    ("kafke", &[]),
+    ("kagni", &["kag"]),
+    ("kajde", &["jde"]),
+    ("kajna", &[]),
+    ("kakne", &["ka'e"]),
+    ("kakpa", &[]),
+    ("kakti", &[]),
+    ("kalci", &[]),
+    ("kalpi", &[]),
+    ("kalri", &["kar"]),
+    ("kalsa", &["kas"]),
+    ("kalte", &["kat"]),
+    ("kamju", &[]),
+    ("kamni", &[]),
+    ("kampu", &["kau"]),
+    ("kamre", &[]),
+    ("kamro", &[]),
+    ("kanba", &[]),
+    ("kancu", &["kac"]),
+    ("kandi", &["kad"]),
+    ("kanji", &["kaj"]),
+    ("kanla", &["kal"]),
+    ("kanpe", &[]),
+    ("kanro", &["ka'o"]),
+    ("kansa", &["kan"]),
+    ("kantu", &["ka'u"]),
+    ("kanxe", &["kax"]),
+    ("kapli", &[]),
+    ("kapsi", &[]),
+    ("karbi", &["kab"]),
+    ("karce", &[]),
+    ("karda", &[]),
+    ("kargu", &[]),
+    ("karki", &[]),
+    ("karli", &[]),
+    ("karmo", &[]),
+    ("karni", &[]),
+    ("karpa", &[]),
+    ("katci", &[]),
+    ("katna", &["ka'a"]),
+    ("katso", &[]),
+    ("kavbu", &["kav"]),
+    ("kavzi", &[]),
+    ("kazmi", &[]),
+    ("kazra", &["kaz"]),
+    ("ke", &["kem"]),
+    ("ke'ai", &["ked"]),
+    ("ke'e", &["kep", "ke'e"]),
+    ("ke'ei", &["keb"]),
+    ("ke'ei'a", &["kex"]),
+    ("kebzo", &[]),
+    ("kecti", &["kec", "ke'i"]),
+    ("kei", &["kez"]),
+    ("kekli", &["kek"]),
+    ("kekti", &[]),
+    ("kelci", &["kel", "kei"]),
+    ("kelto", &[]),
+    ("kelvo", &["ke'o"]),
+    ("kenka", &[]),
+    ("kenra", &["ken"]),
+    ("kensa", &["kes"]),
+    ("kerfa", &["kre"]),
+    ("kerlo", &["ker"]),
+    ("kerni", &[]),
+    ("kesfi", &[]),
+    ("kesri", &[]),
+    ("ketco", &["ket", "tco"]),
+    ("ketli", &[]),
+    ("ketro", &[]),
+    ("ketsu", &[]),
+    ("kevna", &["kev", "ke'a"]),
+    ("kibro", &["kib"]),
+    ("kicne", &["kic", "ki'e"]),
+    ("kicra", &[]),
+    ("kijno", &["kij"]),
+    ("kilga", &[]),
+    ("kilma", &["kim"]),
+    ("kilto", &["ki'o"]),
+    ("kinda", &[]),
+    ("kinfi", &[]),
+    ("kinli", &["kil"]),
+    ("kinmu", &[]),
+    ("kinra", &[]),
+    ("kipsi", &[]),
+    ("kisto", &["kis"]),
+    ("klaji", &["laj"]),
+    ("klaku", &["kak"]),
+    ("klama", &["kla"]),
+    ("klani", &["lai"]),
+    ("klaso", &[]),
+    ("klesi", &["kle", "lei"]),
+    ("kligo", &["kig"]),
+    ("kliki", &[]),
+    ("klina", &["kli"]),
+    ("kliru", &[]),
+    ("kliti", &["kit"]),
+    ("kloje", &[]),
+    ("klupe", &["lup", "lu'e"]),
+    ("kluza", &["luz"]),
+    ("kobli", &["kob", "ko'i"]),
+    ("kogno", &[]),
+    ("kojna", &["koj", "ko'a"]),
+    ("kokso", &[]),
+    ("kolme", &["kol", "ko'e"]),
+    ("komcu", &["kom"]),
+    ("konju", &["kon", "ko'u"]),
+    ("konve", &[]),
+    ("korbi", &["kor", "koi"]),
+    ("korcu", &["kro"]),
+    ("kordu", &[]),
+    ("korja", &[]),
+    ("korka", &["kok"]),
+    ("korle", &[]),
+    ("korvo", &[]),
+    ("kosmu", &[]),
+    ("kosta", &["kos"]),
+    ("krali", &[]),
+    ("kramu", &[]),
+    ("krasi", &["kra"]),
+    ("krati", &["ka'i"]),
+    ("krefu", &["ref", "ke'u"]),
+    ("krici", &["kri"]),
+    ("krili", &[]),
+    ("krinu", &["rin", "ki'u"]),
+    ("krixa", &["kix", "ki'a"]),
+    ("kroke", &[]),
+    ("kroxo", &[]),
+    ("kruca", &["kuc"]),
+    ("kruji", &["ruj"]),
+    ("kruvi", &["ruv", "kru"]),
+    ("ku'a", &["kuz"]),
+    ("kubli", &["kub"]),
+    ("kucli", &[]),
+    ("kufra", &["kuf"]),
+    ("kukru", &[]),
+    ("kukte", &["kuk"]),
+    ("kulnu", &["klu"]),
+    ("kumfa", &["kum", "ku'a"]),
+    ("kumte", &[]),
+    ("kunra", &["kun"]),
+    ("kunti", &["kut"]),
+    ("kurfa", &["kur"]),
+    ("kurji", &["kuj", "ku'i"]),
+    ("kurki", &[]),
+    ("kurti", &[]),
+    ("kuspe", &["kup", "ku'e"]),
+    ("kusru", &["kus"]),
+    ("labno", &[]),
+    ("lacni", &[]),
+    ("lacpu", &["lap", "cpu"]),
+    ("lacri", &["lac"]),
+    ("ladlo", &[]),
+    ("ladru", &["lad"]),
+    ("lafti", &["laf"]),
+    ("lai'ai", &["lav"]),
+    ("lakne", &["la'e"]),
+    ("lakse", &["lak"]),
+    ("laldo", &[]),
+    ("lalxu", &["la'u"]),
+    ("lamda", &[]),
+    ("lamdu", &[]),
+    ("lamji", &["lam", "la'i"]),
+    ("lanbi", &[]),
+    ("lanci", &[]),
+    ("landa", &[]),
+    ("langi", &[]),
+    ("lanka", &[]),
+    ("lanli", &["lal"]),
+    ("lanme", &["lan"]),
+    ("lanru", &[]),
+    ("lante", &[]),
+    ("lanxe", &["lax"]),
+    ("lanzu", &["laz"]),
+    ("larcu", &["lar"]),
+    ("larki", &[]),
+    ("larva", &[]),
+    ("lasna", &["la'a"]),
+    ("lastu", &[]),
+    ("latci", &[]),
+    ("latmo", &["la'o"]),
+    ("latna", &[]),
+    ("laxte", &[]),
+    ("lazni", &[]),
+    ("le'e", &["lem"]),
+    ("lebna", &["leb", "le'a"]),
+    ("lelde", &[]),
+    ("lelxe", &["lel"]),
+    ("lenga", &[]),
+    ("lengu", &[]),
+    ("lenjo", &["len", "le'o"]),
+    ("lenku", &["lek"]),
+    ("lerci", &["lec"]),
+    ("lerfu", &["ler", "le'u"]),
+    ("letci", &[]),
+    ("li'i", &["liz"]),
+    ("libjo", &["lib"]),
+    ("licne", &[]),
+    ("lidne", &["li'e"]),
+    ("lifri", &["lif", "fri"]),
+    ("ligma", &[]),
+    ("lijda", &["jda"]),
+    ("likse", &[]),
+    ("lilvo", &[]),
+    ("limfa", &[]),
+    ("limna", &["lim"]),
+    ("lince", &[]),
+    ("lindi", &["lid"]),
+    ("linga", &[]),
+    ("linji", &["lij", "li'i"]),
+    ("linme", &[]),
+    ("linsi", &["lin"]),
+    ("linto", &["li'o"]),
+    ("lisri", &["lis"]),
+    ("liste", &["ste"]),
+    ("litce", &["lic"]),
+    ("litki", &["lik"]),
+    ("litru", &["li'u"]),
+    ("livga", &[]),
+    ("livla", &["lil"]),
+    ("lo'e", &["lom"]),
+    ("logji", &["loj"]),
+    ("loglo", &["log", "glo"]),
+    ("lojbo", &["lob", "jbo"]),
+    ("lojgo", &[]),
+    ("lokra", &[]),
+    ("loldi", &["lol", "loi"]),
+    ("lorxu", &["lor", "lo'u"]),
+    ("losmo", &["los"]),
+    ("losto", &[]),
+    ("lubma", &[]),
+    ("lubno", &["lu'o"]),
+    ("lucpa", &[]),
+    ("lujvo", &["luv", "jvo"]),
+    ("luksi", &["luk"]),
+    ("lumci", &["lum", "lu'i"]),
+    ("lunbe", &["lub"]),
+    ("lungo", &[]),
+    ("lunra", &["lur"]),
+    ("lunsa", &["lus"]),
+    ("luska", &[]),
+    ("lusto", &[]),
+    ("mabla", &["mal"]),
+    ("mabru", &["mab"]),
+    ("macnu", &["cnu"]),
+    ("majga", &[]),
+    ("majgo", &["jgo"]),
+    ("majvu", &[]),
+    ("makcu", &["ma'u"]),
+    ("makfa", &["maf"]),
+    ("maksi", &["mak"]),
+    ("malbu", &[]),
+    ("malsi", &["mas"]),
+    ("maltu", &[]),
+    ("malve", &[]),
+    ("mamta", &["mam"]),
+    ("manci", &["mac"]),
+    ("manfo", &[]),
+    ("mango", &[]),
+    ("manku", &["man"]),
+    ("manri", &["mar"]),
+    ("mansa", &[]),
+    ("manti", &[]),
+    ("manva", &[]),
+    ("mapku", &["map"]),
+    ("mapni", &[]),
+    ("mapra", &[]),
+    ("mapti", &["mat"]),
+    ("marbi", &["mra"]),
+    ("marce", &["ma'e"]),
+    ("marde", &["mad"]),
+    ("margu", &["mag"]),
+    ("marji", &["maj", "mai"]),
+    ("marna", &[]),
+    ("marxa", &["max"]),
+    ("maska", &[]),
+    ("masno", &["sno"]),
+    ("masti", &["ma'i"]),
+    ("matci", &[]),
+    ("matli", &[]),
+    ("matne", &[]),
+    ("matra", &[]),
+    ("matxe", &[]),
+    ("mavji", &["mav"]),
+    ("maxri", &["xri"]),
+    ("mebri", &["meb"]),
+    ("medzo", &[]),
+    ("megdo", &["meg"]),
+    ("mei", &["mem", "mei"]),
+    ("mekca", &[]),
+    ("mekso", &["mek", "me'o"]),
+    ("melbi", &["mel", "mle"]),
+    ("meljo", &["mej"]),
+    ("melmi", &[]),
+    ("menli", &["men"]),
+    ("menre", &[]),
+    ("mensi", &["mes", "me'i"]),
+    ("mentu", &["met", "me'u"]),
+    ("merko", &["mer"]),
+    ("merli", &["mre"]),
+    ("mespo", &[]),
+    ("metfo", &[]),
+    ("metro", &[]),
+    ("mexno", &["mex"]),
+    ("mi", &["mib"]),
+    ("midju", &["mij"]),
+    ("mifra", &["mif"]),
+    ("mikce", &["mic"]),
+    ("mikri", &["mik"]),
+    ("milti", &["mil"]),
+    ("milxe", &["mli"]),
+    ("mimta", &[]),
+    ("minde", &["mid", "mi'e"]),
+    ("minji", &["mi'i"]),
+    ("minli", &[]),
+    ("minra", &["mir"]),
+    ("mintu", &["mit", "mi'u"]),
+    ("mipri", &["mip"]),
+    ("mirli", &[]),
+    ("misno", &["mis", "mi'o"]),
+    ("misro", &[]),
+    ("miste", &[]),
+    ("mitre", &["tre"]),
+    ("mixre", &["mix", "xre"]),
+    ("mlana", &["mla"]),
+    ("mlatu", &["lat"]),
+    ("mleca", &["mec", "me'a"]),
+    ("mledi", &["led"]),
+    ("mluni", &["lun"]),
+    ("mo'a", &["mob"]),
+    ("mo'i", &["mov"]),
+    ("modju", &[]),
+    ("modzi", &[]),
+    ("mogle", &[]),
+    ("moi", &["mom", "moi"]),
+    ("mokca", &["moc"]),
+    ("moklu", &["mol", "mo'u"]),
+    ("molki", &["mlo"]),
+    ("molro", &["mo'o"]),
+    ("mondo", &[]),
+    ("morji", &["moj", "mo'i"]),
+    ("morko", &["mor"]),
+    ("mormo", &[]),
+    ("morna", &["mon", "mo'a"]),
+    ("morsi", &["mro"]),
+    ("mosra", &["mos"]),
+    ("moxna", &[]),
+    ("mraji", &[]),
+    ("mrilu", &["mri"]),
+    ("mruli", &["mru"]),
+    ("mu", &["mum"]),
+    ("mu'e", &["muf"]),
+    ("mu'umgu", &["mug"]),
+    ("mucti", &["mut"]),
+    ("mudri", &["mud"]),
+    ("mugle", &[]),
+    ("mukti", &["muk", "mu'i"]),
+    ("mulno", &["mul", "mu'o"]),
+    ("munje", &["muj", "mu'e"]),
+    ("munpi", &[]),
+    ("mupli", &["mup"]),
+    ("murse", &[]),
+    ("murta", &["mur", "mu'a"]),
+    ("muslo", &["mus"]),
+    ("mutce", &["tce"]),
+    ("muvdu", &["muv", "mu'u"]),
+    ("muxne", &["mux"]),
+    ("muzga", &["muz"]),
+    ("na", &["nar"]),
+    ("na'e", &["nal"]),
+    ("nabmi", &["nam"]),
+    ("nakni", &["nak"]),
+    ("nalci", &["na'i"]),
+    ("nalfi", &[]),
+    ("nalfo", &[]),
+    ("nalti", &[]),
+    ("namcu", &["nac", "na'u"]),
+    ("namra", &[]),
+    ("nanba", &["nab"]),
+    ("nanca", &["na'a"]),
+    ("nandu", &["nad"]),
+    ("nanla", &[]),
+    ("nanmu", &["nau"]),
+    ("nanvi", &["nav"]),
+    ("narge", &["nag"]),
+    ("narju", &["naj"]),
+    ("natfe", &["naf", "na'e"]),
+    ("natlo", &[]),
+    ("natmi", &["nat", "nai"]),
+    ("natsi", &[]),
+    ("navni", &[]),
+    ("naxle", &["nax", "xle"]),
+    ("nazbi", &["naz", "zbi"]),
+    ("nazmo", &[]),
+    ("nedlo", &[]),
+    ("nejni", &["nen"]),
+    ("nelci", &["nel", "nei"]),
+    ("nenmu", &[]),
+    ("nenri", &["ner", "ne'i"]),
+    ("nerde", &[]),
+    ("ni", &["nil"]),
+    ("nibli", &["nib", "ni'i"]),
+    ("nicfa", &["nif"]),
+    ("niclu", &[]),
+    ("nicte", &["cte"]),
+    ("nikle", &["nik"]),
+    ("nilce", &["ni'e"]),
+    ("nimre", &[]),
+    ("ninja", &[]),
+    ("ninmu", &["nim", "ni'u"]),
+    ("nirna", &["nir"]),
+    ("nitcu", &["tcu"]),
+    ("nivji", &["niv"]),
+    ("nixli", &["nix", "xli"]),
+    ("no", &["non"]),
+    ("no'e", &["nor", "no'e"]),
+    ("nobli", &["nol", "no'i"]),
+    ("nonka", &["nok"]),
+    ("nonmu", &[]),
+    ("nordo", &[]),
+    ("norgo", &[]),
+    ("notci", &["not", "noi"]),
+    ("nu", &["nun"]),
+    ("nu'o", &["nu'o"]),
+    ("nudle", &[]),
+    ("nukni", &["nuk"]),
+    ("nuktu", &[]),
+    ("nulpa", &[]),
+    ("numje", &[]),
+    ("nunmu", &["nu'u"]),
+    ("nupre", &["nup", "nu'e"]),
+    ("nurma", &["num"]),
+    ("nusna", &[]),
+    ("nutka", &[]),
+    ("nutko", &[]),
+    ("nutli", &["nul", "nu'i"]),
+    ("nuzba", &["nuz"]),
+    ("nuzlo", &[]),
+    ("pa", &["pav"]),
+    ("pacna", &["pa'a"]),
+    ("pagbu", &["pag", "pau"]),
+    ("pagre", &["gre"]),
+    ("pagzu", &[]),
+    ("pajni", &["pai"]),
+    ("palci", &["pac"]),
+    ("palku", &["pak"]),
+    ("palma", &[]),
+    ("palne", &[]),
+    ("palpi", &[]),
+    ("palta", &[]),
+    ("pambe", &[]),
+    ("pamga", &[]),
+    ("panci", &["pan"]),
+    ("pandi", &["pad"]),
+    ("panje", &[]),
+    ("panka", &[]),
+    ("panlo", &["pa'o"]),
+    ("panpi", &["pap"]),
+    ("panra", &[]),
+    ("pante", &["pat"]),
+    ("panzi", &["paz"]),
+    ("papri", &[]),
+    ("parbi", &["pab"]),
+    ("pardu", &[]),
+    ("parji", &[]),
+    ("paska", &[]),
+    ("pastu", &["pas"]),
+    ("patfu", &["paf", "pa'u"]),
+    ("patlu", &[]),
+    ("patxu", &["pax"]),
+    ("paxra", &[]),
+    ("paznu", &[]),
+    ("pe'a", &["pev"]),
+    ("pegva", &["peg"]),
+    ("pelji", &["ple"]),
+    ("pelxu", &["pel"]),
+    ("pemci", &["pem"]),
+    ("penbi", &["peb"]),
+    ("pencu", &["pec", "pe'u"]),
+    ("pendo", &["ped", "pe'o"]),
+    ("penka", &[]),
+    ("penki", &[]),
+    ("penmi", &["pen", "pe'i"]),
+    ("pensi", &["pes", "pei"]),
+    ("pentu", &[]),
+    ("pepsi", &[]),
+    ("perli", &["per"]),
+    ("pesxu", &["pex"]),
+    ("petso", &["pet"]),
+    ("pevna", &[]),
+    ("pezli", &["pez"]),
+    ("pi", &["piz"]),
+    ("pi'u", &["piv"]),
+    ("picti", &["pic"]),
+    ("pijne", &[]),
+    ("pikci", &[]),
+    ("pikpi", &[]),
+    ("pikta", &[]),
+    ("pilda", &[]),
+    ("pilji", &["pi'i"]),
+    ("pilka", &["pil", "pi'a"]),
+    ("pilno", &["pli"]),
+    ("pimlu", &["pim", "pi'u"]),
+    ("pinca", &[]),
+    ("pindi", &["pid"]),
+    ("pinfu", &["pif"]),
+    ("pinji", &[]),
+    ("pinka", &["pik"]),
+    ("pinsi", &["pis"]),
+    ("pinta", &["pin"]),
+    ("pinxe", &["pix"]),
+    ("pipno", &["pi'o"]),
+    ("pirxo", &[]),
+    ("pitsa", &[]),
+    ("pixra", &["pir", "xra"]),
+    ("plana", &[]),
+    ("platu", &["pla"]),
+    ("pleji", &["lej", "le'i"]),
+    ("plibu", &["pib"]),
+    ("plini", &[]),
+    ("plipe", &["pip", "pi'e"]),
+    ("plise", &[]),
+    ("plita", &["pit"]),
+    ("plixa", &["lix"]),
+    ("pluja", &["luj"]),
+    ("pluka", &["puk", "pu'a"]),
+    ("pluna", &[]),
+    ("pluta", &["lut", "lu'a"]),
+    ("pocli", &[]),
+    ("polje", &["plo"]),
+    ("polno", &["pol"]),
+    ("pombo", &["pom"]),
+    ("ponjo", &["pon", "po'o"]),
+    ("ponse", &["pos", "po'e"]),
+    ("poplu", &[]),
+    ("porno", &[]),
+    ("porpi", &["pop", "po'i"]),
+    ("porsi", &["por", "poi"]),
+    ("porto", &["pot"]),
+    ("posko", &["pok"]),
+    ("praci", &[]),
+    ("prali", &["pal"]),
+    ("prami", &["pam", "pa'i"]),
+    ("prane", &["pa'e"]),
+    ("predi", &[]),
+    ("preja", &["pej", "pe'a"]),
+    ("premi", &[]),
+    ("prenu", &["pre"]),
+    ("preri", &[]),
+    ("preti", &["ret", "rei"]),
+    ("prije", &["pij"]),
+    ("prina", &["pri"]),
+    ("pritu", &[]),
+    ("proga", &["pog"]),
+    ("proji", &[]),
+    ("prosa", &["ros", "ro'a"]),
+    ("pruce", &["ruc", "ru'e"]),
+    ("pruni", &["pun"]),
+    ("pruri", &[]),
+    ("pruxi", &["rux", "ru'i"]),
+    ("pu'i", &["pus"]),
+    ("pu'u", &["puv"]),
+    ("pulce", &["puc", "pu'e"]),
+    ("pulji", &[]),
+    ("pulni", &[]),
+    ("punji", &["puj", "pu'i"]),
+    ("punli", &["pul"]),
+    ("pupsu", &["pup"]),
+    ("purci", &["pur", "pru"]),
+    ("purdi", &["pud"]),
+    ("purmo", &["pum", "pu'o"]),
+    ("purni", &[]),
+    ("puvza", &[]),
+    ("racli", &[]),
+    ("ractu", &[]),
+    ("radno", &["ra'o"]),
+    ("rafsi", &["raf"]),
+    ("ragbi", &[]),
+    ("ragve", &["rav"]),
+    ("rakle", &[]),
+    ("rakso", &[]),
+    ("raktu", &["ra'u"]),
+    ("ralci", &["rac"]),
+    ("ralju", &["ral"]),
+    ("ralte", &["ra'e"]),
+    ("rance", &[]),
+    ("randa", &["rad"]),
+    ("rango", &["rag"]),
+    ("ranji", &["ra'i"]),
+    ("ranlu", &[]),
+    ("ranmi", &["ram"]),
+    ("ransu", &[]),
+    ("ranti", &["ran"]),
+    ("ranxi", &["rax"]),
+    ("rapli", &["rap"]),
+    ("rarna", &["rar"]),
+    ("ratcu", &[]),
+    ("ratma", &[]),
+    ("ratni", &["rat"]),
+    ("re", &["rel"]),
+    ("rebla", &["reb"]),
+    ("rectu", &["rec", "re'u"]),
+    ("reksa", &[]),
+    ("rekto", &[]),
+    ("remna", &["rem", "re'a"]),
+    ("renro", &["rer", "re'o"]),
+    ("renvi", &["rev", "re'i"]),
+    ("respa", &["res"]),
+    ("rexsa", &["rex"]),
+    ("ribga", &[]),
+    ("ricfu", &["rif", "cfu"]),
+    ("rigni", &["rig"]),
+    ("rijno", &["rij"]),
+    ("rikro", &[]),
+    ("rilti", &["ril"]),
+    ("rimni", &["rim"]),
+    ("rinci", &[]),
+    ("rindo", &[]),
+    ("rinju", &["ri'u"]),
+    ("rinka", &["rik", "ri'a"]),
+    ("rinsa", &[]),
+    ("ripto", &[]),
+    ("rirci", &[]),
+    ("rirli", &[]),
+    ("rirni", &["rir"]),
+    ("rirxe", &["ri'e"]),
+    ("rismi", &["ris"]),
+    ("risna", &[]),
+    ("ritli", &["ri'i"]),
+    ("rivbi", &["riv"]),
+    ("ro", &["rol"]),
+    ("roi", &["rom", "roi"]),
+    ("rokci", &["rok", "ro'i"]),
+    ("romge", &["rog"]),
+    ("romlo", &[]),
+    ("ronci", &["roc"]),
+    ("ronro", &[]),
+    ("ronsa", &[]),
+    ("ronte", &[]),
+    ("ronti", &[]),
+    ("ropno", &["ron", "ro'o"]),
+    ("rorci", &["ror"]),
+    ("rotsu", &["rot", "ro'u", "tsu"]),
+    ("rozgu", &["roz", "zgu"]),
+    ("ruble", &["rub", "ble"]),
+    ("rucni", &[]),
+    ("rufsu", &["ruf"]),
+    ("runme", &["rum"]),
+    ("runta", &[]),
+    ("rupnu", &["rup", "ru'u"]),
+    ("rusko", &["ruk", "ru'o"]),
+    ("rutni", &["run"]),
+    ("sa", &["saf"]),
+    ("sabji", &["sab"]),
+    ("sabnu", &[]),
+    ("sacki", &[]),
+    ("saclu", &[]),
+    ("sacni", &[]),
+    ("sadjo", &["djo"]),
+    ("sakci", &["sak"]),
+    ("sakli", &["sal"]),
+    ("sakta", &["sat"]),
+    ("salci", &["sla"]),
+    ("salmo", &[]),
+    ("salni", &[]),
+    ("salpo", &["sa'o"]),
+    ("salri", &[]),
+    ("salta", &[]),
+    ("samcu", &[]),
+    ("samlu", &[]),
+    ("sampu", &["sap"]),
+    ("sanbu", &[]),
+    ("sance", &["sna"]),
+    ("sanga", &["sag", "sa'a"]),
+    ("sanji", &["saj"]),
+    ("sanli", &["sa'i"]),
+    ("sanmi", &["sai"]),
+    ("sanra", &[]),
+    ("sanso", &[]),
+    ("santa", &[]),
+    ("sarcu", &["sa'u"]),
+    ("sarji", &["sra"]),
+    ("sarlu", &[]),
+    ("sarni", &[]),
+    ("sarxe", &["sax"]),
+    ("saske", &["ske"]),
+    ("satci", &[]),
+    ("satre", &["sa'e"]),
+    ("savru", &["sav", "vru"]),
+    ("sazri", &["saz"]),
+    ("se", &["sel"]),
+    ("sefsi", &[]),
+    ("sefta", &["sfe"]),
+    ("sekre", &[]),
+    ("selci", &["sle"]),
+    ("selfu", &["sef", "se'u"]),
+    ("semto", &["sme"]),
+    ("senci", &["sec"]),
+    ("sengi", &[]),
+    ("senpi", &["sen"]),
+    ("senta", &["set"]),
+    ("senva", &["sev", "sne"]),
+    ("sepli", &["sep", "sei"]),
+    ("sepre", &[]),
+    ("serti", &["ser"]),
+    ("sesre", &[]),
+    ("setca", &["se'a"]),
+    ("sevda", &[]),
+    ("sevzi", &["sez", "se'i"]),
+    ("sezni", &[]),
+    ("sfani", &[]),
+    ("sfasa", &["sfa"]),
+    ("sfeno", &["se'o"]),
+    ("sfero", &[]),
+    ("sfite", &["sfi"]),
+    ("sfofa", &["sfo"]),
+    ("sfopu", &["fop", "fo'u"]),
+    ("sfubu", &["sub", "su'u"]),
+    ("si", &["sif"]),
+    ("si'o", &["siz"]),
+    ("sibli", &[]),
+    ("siclu", &["sil"]),
+    ("sicni", &["si'i"]),
+    ("sicpi", &[]),
+    ("sidbo", &["sib", "si'o"]),
+    ("sidju", &["dju"]),
+    ("sigda", &[]),
+    ("sigja", &["sig"]),
+    ("sigma", &[]),
+    ("sikta", &[]),
+    ("silja", &[]),
+    ("silka", &["sik"]),
+    ("silna", &[]),
+    ("silru", &[]),
+    ("simlu", &["mlu"]),
+    ("simsa", &["smi"]),
+    ("simxu", &["sim", "si'u"]),
+    ("since", &[]),
+    ("sinma", &["si'a"]),
+    ("sinso", &[]),
+    ("sinxa", &["sni"]),
+    ("sipna", &["sip"]),
+    ("sirji", &["sir"]),
+    ("sirmu", &[]),
+    ("sirxo", &["six"]),
+    ("sisku", &["sis"]),
+    ("sisti", &["sti"]),
+    ("sitla", &[]),
+    ("sitna", &["sit"]),
+    ("sivni", &["siv"]),
+    ("sizgo", &[]),
+    ("skaci", &[]),
+    ("skami", &["sam"]),
+    ("skano", &[]),
+    ("skapi", &["kap"]),
+    ("skari", &["ska"]),
+    ("skibo", &[]),
+    ("skicu", &["ski"]),
+    ("skifa", &[]),
+    ("skiji", &["sij"]),
+    ("skina", &["kin"]),
+    ("skori", &["sko"]),
+    ("skoto", &["kot", "ko'o"]),
+    ("skuba", &[]),
+    ("skudi", &[]),
+    ("skuna", &[]),
+    ("skuro", &["ku'o"]),
+    ("slabu", &["sau"]),
+    ("slaka", &[]),
+    ("slami", &[]),
+    ("slanu", &[]),
+    ("slari", &["sar"]),
+    ("slasi", &["las"]),
+    ("sligu", &["lig"]),
+    ("slilu", &["sli"]),
+    ("sliri", &[]),
+    ("slovo", &["lov", "lo'o"]),
+    ("sluji", &["slu"]),
+    ("sluni", &[]),
+    ("smacu", &[]),
+    ("smadi", &[]),
+    ("smaji", &["sma"]),
+    ("smaka", &[]),
+    ("smani", &[]),
+    ("smela", &[]),
+    ("smoka", &["smo"]),
+    ("smuci", &["muc"]),
+    ("smuni", &["mun", "smu"]),
+    ("smusu", &[]),
+    ("snada", &["sad"]),
+    ("snafu", &[]),
+    ("snanu", &["nan"]),
+    ("snavi", &[]),
+    ("snejo", &[]),
+    ("snidu", &["nid"]),
+    ("sniju", &[]),
+    ("snime", &["si'e"]),
+    ("snipa", &["nip"]),
+    ("snuji", &["nuj"]),
+    ("snura", &["nur", "nu'a"]),
+    ("snuti", &["nut"]),
+    ("so", &["soz"]),
+    ("so'a", &["soj"]),
+    ("so'e", &["sop"]),
+    ("so'i", &["sor", "so'i"]),
+    ("so'o", &["sos"]),
+    ("so'u", &["sot"]),
+    ("sobde", &["sob", "so'e"]),
+    ("socni", &[]),
+    ("sodna", &[]),
+    ("sodva", &["sod"]),
+    ("softo", &["sof"]),
+    ("solji", &["slo"]),
+    ("solri", &["sol"]),
+    ("sombo", &["som", "so'o"]),
+    ("sonci", &["son", "soi"]),
+    ("sondu", &[]),
+    ("sonli", &[]),
+    ("sorcu", &["soc", "sro"]),
+    ("sorgu", &["sog"]),
+    ("sorni", &[]),
+    ("sorta", &[]),
+    ("sovda", &["sov", "so'a"]),
+    ("spaji", &["paj"]),
+    ("spali", &[]),
+    ("spano", &["san"]),
+    ("spati", &["spa"]),
+    ("speci", &[]),
+    ("speni", &["spe"]),
+    ("spero", &[]),
+    ("spisa", &["spi"]),
+    ("spita", &[]),
+    ("spofu", &["pof", "po'u"]),
+    ("spoja", &["poj", "po'a"]),
+    ("spuda", &["spu"]),
+    ("spupi", &[]),
+    ("sputu", &["put", "pu'u"]),
+    ("sraji", &["raj"]),
+    ("sraku", &["rak"]),
+    ("sralo", &[]),
+    ("srana", &["ra'a"]),
+    ("srasu", &["sas"]),
+    ("srate", &[]),
+    ("srera", &["sre"]),
+    ("srito", &[]),
+    ("sruma", &["ru'a"]),
+    ("sruri", &["rur", "sru"]),
+    ("stace", &["sac"]),
+    ("stagi", &[]),
+    ("staku", &["tak"]),
+    ("stali", &["sta"]),
+    ("stani", &[]),
+    ("stapa", &["tap"]),
+    ("stasu", &[]),
+    ("stati", &[]),
+    ("steba", &["seb"]),
+    ("steci", &["tec", "te'i"]),
+    ("stedu", &["sed"]),
+    ("stela", &["tel"]),
+    ("stero", &["te'o"]),
+    ("stici", &["sic"]),
+    ("stidi", &["sid", "ti'i"]),
+    ("stika", &["tik"]),
+    ("stima", &[]),
+    ("stizu", &["tiz"]),
+    ("stodi", &["sto"]),
+    ("stori", &[]),
+    ("stuna", &["sun"]),
+    ("stura", &["tur", "su'a"]),
+    ("stuzi", &["tuz", "stu"]),
+    ("su", &["suf"]),
+    ("su'e", &["sup", "su'e"]),
+    ("su'ei", &["sem"]),
+    ("su'o", &["suz", "su'o"]),
+    ("su'u", &["suv"]),
+    ("sucta", &["suc"]),
+    ("sudga", &["sud"]),
+    ("sufti", &["sfu"]),
+    ("suksa", &["suk"]),
+    ("sumji", &["suj"]),
+    ("sumla", &[]),
+    ("sumne", &[]),
+    ("sumro", &[]),
+    ("sumti", &["sum", "su'i"]),
+    ("sunga", &["sug"]),
+    ("sunla", &["sul"]),
+    ("supso", &["sus"]),
+    ("surla", &["sur"]),
+    ("sutra", &["sut"]),
+    ("ta", &["taz"]),
+    ("tabno", &["tab"]),
+    ("tabra", &[]),
+    ("tadji", &[]),
+    ("tadni", &["tad"]),
+    ("tagji", &["tag"]),
+    ("takni", &[]),
+    ("taksi", &[]),
+    ("talko", &[]),
+    ("talno", &[]),
+    ("talsa", &["tal"]),
+    ("tamca", &[]),
+    ("tamji", &["taj"]),
+    ("tamle", &[]),
+    ("tamne", &[]),
+    ("tanbo", &["ta'o"]),
+    ("tance", &["tac"]),
+    ("tanjo", &[]),
+    ("tanko", &[]),
+    ("tanru", &["tau"]),
+    ("tansi", &["tas"]),
+    ("tanxe", &["tax", "ta'e"]),
+    ("tapla", &[]),
+    ("tarbi", &[]),
+    ("tarci", &["tar"]),
+    ("tarla", &[]),
+    ("tarmi", &["tam", "tai"]),
+    ("tarti", &["tra"]),
+    ("taske", &[]),
+    ("tasmi", &[]),
+    ("tasta", &[]),
+    ("tatpi", &["ta'i"]),
+    ("tatru", &["tat"]),
+    ("tau'u", &["tux"]),
+    ("tavla", &["tav", "ta'a"]),
+    ("taxfu", &["taf", "ta'u"]),
+    ("tcaci", &["cac"]),
+    ("tcadu", &["tca"]),
+    ("tcaka", &[]),
+    ("tcame", &[]),
+    ("tcana", &[]),
+    ("tcati", &[]),
+    ("tcaxe", &[]),
+    ("tcema", &[]),
+    ("tcemi", &[]),
+    ("tcena", &["ten"]),
+    ("tcesa", &[]),
+    ("tcese", &[]),
+    ("tceta", &[]),
+    ("tcica", &["tic"]),
+    ("tcidu", &["tid"]),
+    ("tcika", &[]),
+    ("tcila", &["til"]),
+    ("tcima", &["tim", "ti'a"]),
+    ("tcini", &[]),
+    ("tcipi", &[]),
+    ("tcita", &[]),
+    ("tcivi", &[]),
+    ("tcobi", &[]),
+    ("tcone", &[]),
+    ("tcuti", &[]),
+    ("te", &["ter"]),
+    ("tekla", &[]),
+    ("temci", &["tem", "tei"]),
+    ("temse", &[]),
+    ("tende", &["te'e"]),
+    ("tendo", &[]),
+    ("tenfa", &["tef"]),
+    ("tengu", &["teg", "te'u"]),
+    ("terdi", &["ted"]),
+    ("terki", &[]),
+    ("terpa", &["tep", "te'a"]),
+    ("terto", &["tet"]),
+    ("tezda", &[]),
+    ("ti", &["tif"]),
+    ("tifri", &[]),
+    ("tigni", &["tig"]),
+    ("tigra", &[]),
+    ("tikpa", &["tip"]),
+    ("tilju", &["tij"]),
+    ("timno", &[]),
+    ("tinbe", &["tib"]),
+    ("tinci", &[]),
+    ("tinri", &[]),
+    ("tinsa", &[]),
+    ("tinto", &[]),
+    ("tipre", &[]),
+    ("tirna", &["tin"]),
+    ("tirse", &["tir"]),
+    ("tirxu", &[]),
+    ("tisna", &["tis"]),
+    ("titla", &["tit"]),
+    ("tivni", &["tiv"]),
+    ("tixnu", &["tix", "ti'u"]),
+    ("tizva", &[]),
+    ("to'ai", &["toz"]),
+    ("to'e", &["tol", "to'e"]),
+    ("toknu", &["tok"]),
+    ("tokpo", &["top"]),
+    ("toldi", &["tod"]),
+    ("tolfa", &[]),
+    ("tonga", &["tog", "to'a"]),
+    ("tonsi", &["tos"]),
+    ("tonzo", &["to'o"]),
+    ("tordu", &["tor", "to'u"]),
+    ("torka", &[]),
+    ("torni", &["ton", "to'i"]),
+    ("torso", &[]),
+    ("torta", &[]),
+    ("tovla", &[]),
+    ("traji", &["rai"]),
+    ("trano", &[]),
+    ("trati", &[]),
+    ("trene", &["ren", "re'e"]),
+    ("tricu", &["ric"]),
+    ("triga", &[]),
+    ("trina", &["tri"]),
+    ("trixe", &["rix", "ti'e"]),
+    ("troci", &["toc", "toi"]),
+    ("tsaba", &[]),
+    ("tsako", &["tso"]),
+    ("tsali", &["tsa"]),
+    ("tsani", &["tan"]),
+    ("tsapi", &[]),
+    ("tsida", &[]),
+    ("tsiju", &["tsi"]),
+    ("tsina", &["sin"]),
+    ("tsuku", &[]),
+    ("tsuli", &[]),
+    ("tu", &["tuf"]),
+    ("tubnu", &["tu'u"]),
+    ("tubra", &[]),
+    ("tugni", &["tug", "tu'i"]),
+    ("tujli", &["tuj"]),
+    ("tulnu", &[]),
+    ("tumla", &["tum", "tu'a"]),
+    ("tunba", &["tub"]),
+    ("tunka", &["tuk"]),
+    ("tunlo", &["tul", "tu'o"]),
+    ("tunta", &["tun"]),
+    ("tuple", &["tup", "tu'e"]),
+    ("turko", &[]),
+    ("turni", &["tru"]),
+    ("tutci", &["tci"]),
+    ("tutle", &[]),
+    ("tutra", &["tut"]),
+    ("va", &["vaz"]),
+    ("va'arga", &["va'a"]),
+    ("vacri", &["var"]),
+    ("vai", &["vav"]),
+    ("vajni", &["vaj", "vai"]),
+    ("valfa", &["vaf"]),
+    ("valsi", &["val", "vla"]),
+    ("vamgu", &[]),
+    ("vamji", &["vam", "va'i"]),
+    ("vamtu", &["vat"]),
+    ("vanbi", &["vab"]),
+    ("vanci", &["vac"]),
+    ("vanju", &["van"]),
+    ("vasru", &["vas", "vau"]),
+    ("vasxu", &["vax", "va'u"]),
+    ("ve", &["vel"]),
+    ("ve'e", &["ve'e"]),
+    ("vecnu", &["ven", "ve'u"]),
+    ("vedli", &["ve'i"]),
+    ("venfu", &["vef"]),
+    ("vensa", &["ves"]),
+    ("vente", &[]),
+    ("vepre", &[]),
+    ("verba", &["ver", "ve'a"]),
+    ("verjo", &[]),
+    ("vetno", &[]),
+    ("vi", &["viz"]),
+    ("vibna", &["vib"]),
+    ("vidni", &[]),
+    ("vidru", &["vir"]),
+    ("vidvi", &[]),
+    ("vifne", &["vif"]),
+    ("vikmi", &["vim", "vi'i"]),
+    ("viknu", &["vik"]),
+    ("vilco", &[]),
+    ("vimcu", &["vic", "vi'u"]),
+    ("vindu", &["vid"]),
+    ("vinji", &["vij"]),
+    ("vinpa", &[]),
+    ("vinta", &[]),
+    ("vipsi", &["vip"]),
+    ("virme", &[]),
+    ("virnu", &["vri"]),
+    ("viska", &["vis", "vi'a"]),
+    ("vitci", &["vit"]),
+    ("vitke", &["vi'e"]),
+    ("vitno", &["vi'o"]),
+    ("vlagi", &["lag"]),
+    ("vlile", &["vil"]),
+    ("vlina", &[]),
+    ("vlipa", &["vli"]),
+    ("vo", &["von"]),
+    ("vo'a", &["vob"]),
+    ("vo'ai", &["voz"]),
+    ("vofli", &["vol", "voi"]),
+    ("voksa", &["vok", "vo'a"]),
+    ("volve", &[]),
+    ("vorme", &["vor", "vro"]),
+    ("vraga", &["vra"]),
+    ("vreji", &["rej", "vei"]),
+    ("vreta", &["vre"]),
+    ("vrici", &[]),
+    ("vrude", &["vud", "vu'e"]),
+    ("vrusi", &["vus", "vu'i"]),
+    ("vu", &["vuz"]),
+    ("vubla", &["vul"]),
+    ("vujnu", &["vuj", "vu'u"]),
+    ("vukna", &[]),
+    ("vukro", &["vur", "vu'o"]),
+    ("xa", &["xav"]),
+    ("xabju", &["xa'u"]),
+    ("xadba", &["xab"]),
+    ("xadji", &[]),
+    ("xadni", &["xad"]),
+    ("xagji", &[]),
+    ("xagri", &[]),
+    ("xajmi", &["xam"]),
+    ("xaksu", &["xak"]),
+    ("xakto", &[]),
+    ("xalbo", &[]),
+    ("xalda", &[]),
+    ("xalka", &["xal"]),
+    ("xalni", &[]),
+    ("xamgu", &["xag", "xau"]),
+    ("xampo", &["xap", "xa'o"]),
+    ("xamsi", &["xas"]),
+    ("xance", &["xan", "xa'e"]),
+    ("xango", &[]),
+    ("xanka", &[]),
+    ("xanmi", &[]),
+    ("xanri", &["xar"]),
+    ("xansa", &[]),
+    ("xanto", &[]),
+    ("xarci", &["xac", "xa'i"]),
+    ("xarju", &["xaj"]),
+    ("xarmi", &[]),
+    ("xarnu", &[]),
+    ("xasli", &[]),
+    ("xasne", &[]),
+    ("xatra", &["xa'a"]),
+    ("xatsi", &["xat"]),
+    ("xavli", &[]),
+    ("xaxre", &[]),
+    ("xazdo", &["xaz", "zdo"]),
+    ("xe", &["xel"]),
+    ("xebni", &["xen", "xei"]),
+    ("xebro", &["xeb", "bro"]),
+    ("xecto", &["xet", "cto"]),
+    ("xedja", &["xej", "xe'a"]),
+    ("xei", &["xem"]),
+    ("xekce", &["xec", "xe'e"]),
+    ("xekri", &["xek", "xe'i"]),
+    ("xelso", &["xes"]),
+    ("xendo", &["xed", "xe'o"]),
+    ("xenmu", &[]),
+    ("xenru", &["xer", "xe'u"]),
+    ("xerno", &[]),
+    ("xexso", &["xex"]),
+    ("xexto", &[]),
+    ("xi", &["xix"]),
+    ("xigzo", &["xig"]),
+    ("xijra", &[]),
+    ("xilva", &["xiv"]),
+    ("ximta", &[]),
+    ("xindo", &["xin"]),
+    ("xinmo", &["xim"]),
+    ("xinro", &[]),
+    ("xinsi", &[]),
+    ("xirma", &["xir", "xi'a"]),
+    ("xislu", &["xil", "xi'u"]),
+    ("xispo", &["xip"]),
+    ("xixli", &[]),
+    ("xixnu", &[]),
+    ("xlali", &["xla"]),
+    ("xlobi", &[]),
+    ("xlura", &["xlu"]),
+    ("xo'ai", &["xoz"]),
+    ("xo'ei", &["xon"]),
+    ("xoltu", &[]),
+    ("xonba", &[]),
+    ("xorbo", &[]),
+    ("xorlo", &[]),
+    ("xotli", &["xol", "xoi"]),
+    ("xrabo", &["rab"]),
+    ("xrani", &["xai"]),
+    ("xrata", &[]),
+    ("xriso", &["xis", "xi'o"]),
+    ("xroma", &[]),
+    ("xrotu", &["xro"]),
+    ("xruba", &["xub"]),
+    ("xruki", &["xuk"]),
+    ("xrula", &["rul"]),
+    ("xruti", &["xru"]),
+    ("xudvu", &["xu'u"]),
+    ("xugri", &[]),
+    ("xukmi", &["xum", "xu'i"]),
+    ("xulta", &["xut"]),
+    ("xumbe", &[]),
+    ("xunre", &["xun", "xu'e"]),
+    ("xurdo", &["xur", "xu'o"]),
+    ("xusra", &["xus", "xu'a"]),
+    ("xutla", &["xul"]),
+    ("za'i", &["zaz"]),
+    ("za'o", &["za'o"]),
+    ("zabna", &["zan", "za'a"]),
+    ("zai'e", &["zam"]),
+    ("zajba", &["zaj"]),
+    ("zalvi", &["zal"]),
+    ("zandi", &["zad"]),
+    ("zanru", &["zar", "zau"]),
+    ("zanxi", &[]),
+    ("zarci", &["zac", "zai"]),
+    ("zargu", &["zag", "za'u"]),
+    ("zasni", &["zas"]),
+    ("zasti", &["zat", "za'i"]),
+    ("zbabu", &["bab"]),
+    ("zbani", &[]),
+    ("zbasu", &["zba"]),
+    ("zbepi", &["zbe"]),
+    ("zdani", &["zda"]),
+    ("zdile", &["zdi"]),
+    ("ze", &["zel"]),
+    ("ze'e", &["ze'e"]),
+    ("ze'o", &["zev", "ze'o"]),
+    ("zei'e", &["zex"]),
+    ("zei'o", &["zes"]),
+    ("zejvo", &[]),
+    ("zekri", &["zer", "zei"]),
+    ("zenba", &["zen", "ze'a"]),
+    ("zepti", &["zep"]),
+    ("zergi", &[]),
+    ("zetro", &["zet"]),
+    ("zevla", &["zve"]),
+    ("zgadi", &[]),
+    ("zgana", &["zga"]),
+    ("zgike", &["gi'e", "zgi"]),
+    ("zguro", &[]),
+    ("zi'o", &["zil"]),
+    ("zifre", &["zif", "zi'e"]),
+    ("zinki", &["zin", "zi'i"]),
+    ("zirli", &[]),
+    ("zirpu", &["zir", "zi'u"]),
+    ("zivle", &["ziv", "vle"]),
+    ("zmadu", &["zma", "mau"]),
+    ("zmase", &[]),
+    ("zmico", &["zic"]),
+    ("zmiku", &["zmi"]),
+    ("zo'a", &["zon", "zo'a"]),
+    ("zo'i", &["zor", "zo'i"]),
+    ("zorti", &[]),
+    ("zozno", &[]),
+    ("zu'o", &["zum"]),
+    ("zubra", &[]),
+    ("zucna", &["zuc", "zu'a"]),
+    ("zukte", &["zuk", "zu'e"]),
+    ("zulna", &[]),
+    ("zumri", &["zmu"]),
+    ("zungi", &["zug"]),
+    ("zunle", &["zul"]),
+    ("zunti", &["zun", "zu'i"]),
+    ("zurja", &["zuj"]),
+    ("zutse", &["zut", "tse"]),
+    ("zvati", &["zva"]),
+    ("zviki", &["zvi"]),
+    ("zvomo", &["zvo"]),
+    ("zvuzu", &[]),
+];
+
+const _: () = assert!(crate::data::is_sorted_by_first(RAFSI_LIST), "RAFSI_LIST must be sorted");
+
+/// Big giant rafsi list, as a map. Only available with the `std` feature; the
+/// functions below work without it.
+#[cfg(feature = "std")]
+pub static RAFSI: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
+    LazyLock::new(|| RAFSI_LIST.iter().map(|&(valsi, rafsi)| (valsi, rafsi.to_vec())).collect());
+
+/// The rafsi of `valsi`, or `None` if it isn't in [`RAFSI_LIST`].
+#[must_use]
+pub fn rafsi_of(valsi: &str) -> Option<&'static [&'static str]> {
+    RAFSI_LIST.binary_search_by_key(&valsi, |&(v, _)| v).ok().map(|i| RAFSI_LIST[i].1)
+}
+
+/// The word in [`RAFSI_LIST`] that has `rafsi` as one of its rafsi.
+#[must_use]
+pub fn selrafsi_of(rafsi: &str) -> Option<&'static str> {
+    RAFSI_LIST.iter().find_map(|&(valsi, rl)| rl.contains(&rafsi).then_some(valsi))
+}
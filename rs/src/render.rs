@@ -0,0 +1,355 @@
+//! Renderers for showing results as plain text, ANSI-colored text, HTML, or
+//! Markdown. The CLI uses these, and they can be used anywhere else the same
+//! output is wanted.
+//! ```
+//! use latkerlo_jvotci::{
+//!     Settings,
+//!     render::{Format, render_report},
+//!     report::describe,
+//! };
+//!
+//! let report = describe("bangu girzu", &Settings::default());
+//! let html = render_report(&report, Format::Html.renderer().as_ref());
+//! assert!(html.contains(r#"<span class="rafsi green">bau</span>"#));
+//! ```
+
+use std::{
+    env,
+    io::{IsTerminal as _, stdout},
+    str::FromStr,
+    sync::LazyLock,
+};
+
+use itertools::Itertools as _;
+use regex::Regex;
+
+use crate::{
+    cli_docs::{BLACK, BOLD, CYAN, GREEN, PINK, RED, RESET},
+    data::HYPHENS,
+    report::{Action, Report, ScoredLujvo},
+    tarmi::split_cmavo_compound,
+    tools::normalize,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a piece of text is, which decides how it looks.
+pub enum Tag {
+    /// Labels and other information, like the brivla type or a score.
+    Info,
+    /// The main result, like a lujvo or a list of source words.
+    Result,
+    /// A side note, like rafsi lookups.
+    Note,
+    Error,
+    Strong,
+    /// Something less important, like a trace.
+    Dim,
+    /// A difference from the input, in a suggested lujvo.
+    Changed,
+    /// The rafsi at this index in a lujvo.
+    Rafsi(usize),
+    /// A hyphen in a lujvo.
+    Hyphen,
+}
+
+/// A way of showing text.
+pub trait Renderer {
+    /// Renders text with a tag.
+    fn span(&self, tag: Tag, text: &str) -> String;
+    /// Renders text without a tag, escaping it if needed.
+    fn text(&self, text: &str) -> String { text.to_string() }
+    /// Joins rendered lines.
+    fn lines(&self, lines: &[String]) -> String { lines.join("\n") }
+}
+
+/// Text with no formatting.
+pub struct Plain;
+
+impl Renderer for Plain {
+    fn span(&self, _: Tag, text: &str) -> String { text.to_string() }
+}
+
+/// Text colored with ANSI escape codes, for terminals.
+pub struct Ansi;
+
+impl Renderer for Ansi {
+    fn span(&self, tag: Tag, text: &str) -> String {
+        let color = match tag {
+            Tag::Info => CYAN,
+            Tag::Result | Tag::Rafsi(_) | Tag::Hyphen => GREEN,
+            Tag::Note => PINK,
+            Tag::Error | Tag::Changed => RED,
+            Tag::Strong => BOLD,
+            Tag::Dim => BLACK,
+        };
+        format!("{color}{text}{RESET}")
+    }
+}
+
+/// HTML, with each tag as a `<span>` with a class. Rafsi alternate between the
+/// classes `rafsi green` and `rafsi blue`, hyphens have the class
+/// `hyphen yellow`, and errors and changes have the class `red`, so they are
+/// colored by the demo page's stylesheet.
+pub struct Html;
+
+impl Renderer for Html {
+    fn span(&self, tag: Tag, text: &str) -> String {
+        let class = match tag {
+            Tag::Info => "info",
+            Tag::Result => "result",
+            Tag::Note => "note",
+            Tag::Error => "error red",
+            Tag::Strong => return format!("<b>{}</b>", self.text(text)),
+            Tag::Dim => "dim",
+            Tag::Changed => "changed red",
+            Tag::Rafsi(i) => ["rafsi green", "rafsi blue"][i % 2],
+            Tag::Hyphen => "hyphen yellow",
+        };
+        format!(r#"<span class="{class}">{}</span>"#, self.text(text))
+    }
+
+    fn text(&self, text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    fn lines(&self, lines: &[String]) -> String {
+        format!("<div class=\"jvotci\">\n{}\n</div>", lines.join("<br>\n"))
+    }
+}
+
+/// Markdown, with errors and changes in bold and notes in italics.
+pub struct Markdown;
+
+impl Renderer for Markdown {
+    fn span(&self, tag: Tag, text: &str) -> String {
+        let text = self.text(text);
+        match tag {
+            Tag::Error | Tag::Changed | Tag::Strong => format!("**{text}**"),
+            Tag::Note | Tag::Dim => format!("*{text}*"),
+            _ => text,
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        text.chars()
+            .flat_map(|c| "\\`*_[]<>#|".contains(c).then_some('\\').into_iter().chain([c]))
+            .collect()
+    }
+
+    fn lines(&self, lines: &[String]) -> String { lines.join("  \n") }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The built-in renderers.
+pub enum Format {
+    Plain,
+    Ansi,
+    Html,
+    Markdown,
+}
+
+impl Format {
+    /// Gets the renderer.
+    #[must_use]
+    pub fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            Format::Plain => Box::new(Plain),
+            Format::Ansi => Box::new(Ansi),
+            Format::Html => Box::new(Html),
+            Format::Markdown => Box::new(Markdown),
+        }
+    }
+
+    /// Picks `Ansi` if stdout is a terminal and
+    /// [`NO_COLOR`](https://no-color.org) isn't set, and `Plain` otherwise.
+    #[must_use]
+    pub fn for_terminal() -> Self {
+        if env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) || !stdout().is_terminal() {
+            Format::Plain
+        } else {
+            Format::Ansi
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FormatError;
+impl FromStr for Format {
+    type Err = FormatError;
+    /// Accepts `plain`, `ansi`, `html`, and `markdown` (or `md`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "ansi" => Ok(Format::Ansi),
+            "html" => Ok(Format::Html),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(FormatError),
+        }
+    }
+}
+
+static ANSI_ESCAPE: LazyLock<Regex> = LazyLock::new(|| Regex::new("\x1b\\[[0-9;]*m").unwrap());
+
+/// Removes ANSI color codes, e.g. from the help text in [`cli_docs`].
+///
+/// [`cli_docs`]: crate::cli_docs
+#[must_use]
+pub fn strip_ansi(text: &str) -> String { ANSI_ESCAPE.replace_all(text, "").to_string() }
+
+/// Splits a lujvo into rafsi and hyphens using the indices from
+/// [`get_lujvo_with_analytics`](crate::get_lujvo_with_analytics).
+#[must_use]
+pub fn lujvo_pieces(lujvo: &ScoredLujvo) -> Vec<String> {
+    let mut pieces = vec![];
+    let mut pos = 0;
+    for &[start, end] in &lujvo.indices {
+        if start > pos {
+            pieces.push(lujvo.lujvo[pos..start].to_string());
+        }
+        pieces.push(lujvo.lujvo[start..end].to_string());
+        pos = end;
+    }
+    if pos < lujvo.lujvo.len() {
+        pieces.push(lujvo.lujvo[pos..].to_string());
+    }
+    pieces
+}
+
+/// Tags rafsi and hyphens so that rafsi are numbered in order.
+#[must_use]
+pub fn tag_pieces(pieces: &[String]) -> Vec<(Tag, String)> {
+    let mut i = 0;
+    pieces
+        .iter()
+        .map(|p| {
+            if HYPHENS.contains(&p.as_str()) {
+                (Tag::Hyphen, p.clone())
+            } else {
+                i += 1;
+                (Tag::Rafsi(i - 1), p.clone())
+            }
+        })
+        .collect()
+}
+
+/// Compares the rafsi and hyphens of a lujvo with those of a better one,
+/// tagging the parts of the better one that differ as [`Tag::Changed`] and
+/// the rest as [`Tag::Result`]. A hyphen that the better one drops is shown
+/// as a changed `-`.
+#[must_use]
+pub fn diff_pieces(input: &[String], best: &[String]) -> Vec<(Tag, String)> {
+    let mut res = vec![];
+    let (mut m, mut b) = (0, 0);
+    let mut diverged = false;
+    while m < input.len() && b < best.len() {
+        let mabla_curr = input[m].as_str();
+        let best_curr = best[b].as_str();
+        if HYPHENS.contains(&mabla_curr) {
+            if !HYPHENS.contains(&best_curr) {
+                if best_curr == input.get(m + 1).map_or("", String::as_str) && !diverged {
+                    res.push((Tag::Changed, "-".to_string()));
+                }
+                m += 1;
+                continue;
+            }
+            if mabla_curr == best_curr {
+                res.push((Tag::Result, best_curr.to_string()));
+                m += 1;
+                b += 1;
+                continue;
+            }
+        } else if HYPHENS.contains(&best_curr) {
+            res.push((Tag::Changed, best_curr.to_string()));
+            b += 1;
+            diverged = true;
+            continue;
+        }
+        diverged = mabla_curr != best_curr;
+        res.push((if diverged { Tag::Changed } else { Tag::Result }, best_curr.to_string()));
+        m += 1;
+        b += 1;
+    }
+    res.extend(best[b..].iter().map(|p| (Tag::Result, p.clone())));
+    res
+}
+
+/// Renders tagged text as one line. Neighboring pieces with the same tag are
+/// rendered as one span.
+#[must_use]
+pub fn render_spans(spans: &[(Tag, String)], renderer: &dyn Renderer) -> String {
+    spans
+        .iter()
+        .chunk_by(|(tag, _)| *tag)
+        .into_iter()
+        .map(|(tag, group)| renderer.span(tag, &group.map(|(_, text)| text).join("")))
+        .join("")
+}
+
+/// Renders a [`Report`] the way the CLI shows it: rafsi lookups, then for a
+/// word its type, rafsi, score, source words, and any better lujvo (with the
+/// differences marked), or for a tanru the best lujvo. If a word couldn't be
+/// analyzed but is a cmavo compound, the cmavo are shown after the error.
+#[must_use]
+pub fn render_report(report: &Report, renderer: &dyn Renderer) -> String {
+    let r = renderer;
+    let mut lines = vec![];
+    if let Some(lookup) = &report.rafsi {
+        let word = normalize(&report.input);
+        if let Some(selrafsi) = &lookup.selrafsi {
+            lines.push(r.span(Tag::Note, &format!("{{{word}}} is a rafsi of {{{selrafsi}}}")));
+        }
+        if !lookup.rafsi.is_empty() {
+            let rafsi = lookup.rafsi.join(" ");
+            lines.push(r.span(Tag::Note, &format!("{{{word}}} has rafsi {{{rafsi}}}")));
+        }
+    }
+    if report.action == Action::Zba {
+        match (&report.best, &report.error) {
+            (_, Some(e)) => lines.push(r.span(Tag::Error, &e.to_string())),
+            (Some(best), None) => {
+                lines.push(render_spans(&tag_pieces(&lujvo_pieces(best)), r));
+            }
+            (None, None) => (),
+        }
+        return r.lines(&lines);
+    }
+    let Some(b_type) = report.brivla_type else {
+        if let Some(e) = &report.error {
+            lines.push(r.span(Tag::Error, &e.to_string()));
+        }
+        if let Ok(cmavo) = split_cmavo_compound(&normalize(&report.input))
+            && cmavo.len() > 1
+        {
+            let cmavo = cmavo.into_iter().map(|(c, _)| c).join(" ");
+            lines.push(r.span(Tag::Note, &format!("{{{cmavo}}}")));
+        }
+        return r.lines(&lines);
+    };
+    let word = normalize(&report.input);
+    lines.push(r.span(Tag::Info, &b_type.to_string().to_lowercase().replace("dl", "d l")));
+    if report.pieces.join(" ") != word {
+        let pieces = tag_pieces(&report.pieces);
+        lines.push(pieces.iter().map(|(tag, p)| r.span(*tag, p)).join(&r.text(" ")));
+    }
+    if let Some(score) = report.score {
+        lines.push(r.span(Tag::Info, &score.to_string()));
+    }
+    lines.push(r.span(Tag::Result, &report.selrafsi.join(" ")));
+    if let Some(e) = &report.error {
+        lines.push(r.span(Tag::Error, &e.to_string()));
+    } else if let Some(best) = &report.best
+        && best.lujvo != word
+    {
+        lines.push(format!(
+            "{}{}{}",
+            r.span(Tag::Info, "best: "),
+            render_spans(&diff_pieces(&report.pieces, &lujvo_pieces(best)), r),
+            r.span(Tag::Info, &format!(" ({})", best.score))
+        ));
+    }
+    r.lines(&lines)
+}
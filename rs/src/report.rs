@@ -0,0 +1,220 @@
+//! Structured summaries of everything the crate can say about an input, for
+//! tools that want something other than the CLI's colored text.
+//!
+//! With the `serde` feature (on by default), a [`Report`] serializes to JSON
+//! like this:
+//! ```json
+//! {
+//!   "input": "latkerlo",
+//!   "action": "katna",
+//!   "settings": {
+//!     "generate_cmevla": false,
+//!     "y_hyphens": "standard",
+//!     "consonants": "cluster",
+//!     "exp_rafsi": false,
+//!     "glides": false,
+//!     "allow_mz": false
+//!   },
+//!   "rafsi": { "selrafsi": null, "rafsi": [] },
+//!   "brivla_type": "lujvo",
+//!   "pieces": ["lat", "kerlo"],
+//!   "selrafsi": ["mlatu", "kerlo"],
+//!   "score": 7937,
+//!   "best": { "lujvo": "latkerlo", "score": 7937, "indices": [[0, 3], [3, 8]] },
+//!   "error": null
+//! }
+//! ```
+//! Fields that don't apply are `null` or empty. Errors look like
+//! `{ "kind": "NotBrivlaError", "message": "..." }`, where `kind` is a
+//! [`Jvonunfli`] variant.
+
+use itertools::Itertools as _;
+
+use crate::{
+    exceptions::Jvonunfli,
+    jvozba::get_lujvo_with_analytics,
+    katna::{get_veljvo, score_lujvo, search_selrafsi_from_rafsi, selrafsi_list_from_rafsi_list},
+    rafsi::RAFSI,
+    tarmi::{
+        BrivlaType::{self, Cmevla, ExtendedLujvo, Lujvo},
+        Settings,
+    },
+    tools::{analyze_brivla, normalize},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
+/// What was done with the input.
+pub enum Action {
+    /// The input was a single word, so it was analyzed.
+    Katna,
+    /// The input was a tanru, so a lujvo was made.
+    Zba,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// Rafsi information for a single word.
+pub struct RafsiLookup {
+    /// The word this is a rafsi of, if it is one.
+    pub selrafsi: Option<String>,
+    /// The rafsi of this word.
+    pub rafsi: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// A lujvo with its score and rafsi positions.
+pub struct ScoredLujvo {
+    pub lujvo: String,
+    pub score: i32,
+    pub indices: Vec<[usize; 2]>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// Everything [`describe`] found out about an input.
+pub struct Report {
+    pub input: String,
+    pub action: Action,
+    pub settings: Settings,
+    /// Only present for single words.
+    pub rafsi: Option<RafsiLookup>,
+    pub brivla_type: Option<BrivlaType>,
+    /// Rafsi and hyphens.
+    pub pieces: Vec<String>,
+    /// Source words and formatted unassigned rafsi.
+    pub selrafsi: Vec<String>,
+    pub score: Option<i32>,
+    /// The best lujvo for the input tanru, or for the analyzed lujvo's tanru.
+    pub best: Option<ScoredLujvo>,
+    pub error: Option<Jvonunfli>,
+}
+
+impl Report {
+    /// Returns `true` if something went wrong.
+    #[must_use]
+    pub fn is_err(&self) -> bool { self.error.is_some() }
+
+    /// Serializes `self` as a single line of JSON.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // .unwrap()
+    pub fn to_json(&self) -> String { serde_json::to_string(self).unwrap() }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// The score of a lujvo, from [`describe_score`].
+pub struct ScoreReport {
+    pub lujvo: String,
+    pub score: Option<i32>,
+    pub error: Option<Jvonunfli>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// A [`Settings`] with the flags that select it.
+pub struct SettingsReport {
+    /// As in [`Settings`]'s `Display`, e.g. `cA2`.
+    pub flags: String,
+    pub settings: Settings,
+}
+
+impl From<Settings> for SettingsReport {
+    fn from(settings: Settings) -> Self { SettingsReport { flags: settings.to_string(), settings } }
+}
+
+/// Scores a lujvo, keeping the error if it isn't one.
+#[must_use]
+pub fn describe_score(lujvo: &str, settings: &Settings) -> ScoreReport {
+    let lujvo = normalize(lujvo);
+    let score = score_lujvo(&lujvo, settings);
+    ScoreReport { lujvo, score: score.as_ref().ok().copied(), error: score.err() }
+}
+
+/// Gets the rafsi information for a word.
+#[must_use]
+pub fn lookup_rafsi(word: &str) -> RafsiLookup {
+    let word = normalize(word);
+    RafsiLookup {
+        selrafsi: search_selrafsi_from_rafsi(&word),
+        rafsi: RAFSI
+            .get(word.as_str())
+            .map(|r| r.iter().map(ToString::to_string).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// Analyzes the input if it's one word, or makes a lujvo if it's several, in
+/// the same way as the CLI does. Unlike the CLI, analyzing a word that isn't a
+/// lujvo (e.g. a gismu) is not an error.
+#[must_use]
+pub fn describe(input: &str, settings: &Settings) -> Report {
+    if input.split_whitespace().count() > 1 {
+        describe_tanru(input, settings)
+    } else {
+        describe_word(input, settings)
+    }
+}
+
+fn empty_report(input: &str, action: Action, settings: &Settings) -> Report {
+    Report {
+        input: input.split_whitespace().join(" "),
+        action,
+        settings: *settings,
+        rafsi: None,
+        brivla_type: None,
+        pieces: vec![],
+        selrafsi: vec![],
+        score: None,
+        best: None,
+        error: None,
+    }
+}
+
+/// Makes a lujvo from the input, like [`describe`] does for several words.
+#[must_use]
+pub fn describe_tanru(input: &str, settings: &Settings) -> Report {
+    let mut report = empty_report(input, Action::Zba, settings);
+    match get_lujvo_with_analytics(&report.input, settings) {
+        Ok((lujvo, score, indices)) => {
+            report.best = Some(ScoredLujvo { lujvo, score, indices });
+        }
+        Err(e) => report.error = Some(e),
+    }
+    report
+}
+
+/// Analyzes the input as one word, like [`describe`] does for one word.
+#[must_use]
+pub fn describe_word(input: &str, settings: &Settings) -> Report {
+    let mut report = empty_report(input, Action::Katna, settings);
+    let word = normalize(&report.input);
+    report.rafsi = Some(lookup_rafsi(&word));
+    let (b_type, pieces) = match analyze_brivla(&word, settings) {
+        Ok(res) => res,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+    report.brivla_type = Some(b_type);
+    report.score = score_lujvo(&word, settings).ok();
+    match selrafsi_list_from_rafsi_list(&pieces, settings) {
+        Ok(selrafsi) => report.selrafsi = selrafsi,
+        Err(e) => report.error = Some(e),
+    }
+    report.pieces = pieces;
+    if [Lujvo, ExtendedLujvo, Cmevla].contains(&b_type) {
+        match get_veljvo(&word, settings) {
+            Ok(veljvo) => {
+                report.best = get_lujvo_with_analytics(&veljvo.join(" "), settings)
+                    .ok()
+                    .map(|(lujvo, score, indices)| ScoredLujvo { lujvo, score, indices });
+            }
+            Err(e) => report.error = report.error.take().or(Some(e)),
+        }
+    }
+    report
+}
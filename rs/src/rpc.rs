@@ -0,0 +1,191 @@
+//! A [JSON-RPC 2.0](https://www.jsonrpc.org/specification) session over
+//! lines of text, for editor integrations that want one long-lived process.
+//! Needs the `serde` feature; the CLI runs it on stdin and stdout with
+//! `jvotci rpc`.
+//!
+//! Each request and response is one line. Since the process stays alive, the
+//! crate's lazily built tables (like [`RAFSI`](crate::RAFSI)) are only built
+//! once, and each session keeps a [`Cache`] of the words it has analyzed. The
+//! methods are:
+//!
+//! | method | params | result |
+//! | --- | --- | --- |
+//! | `analyze` | `input` | a [`Report`], like [`describe`] |
+//! | `zba` | `tanru` | a [`Report`], like [`describe_tanru`] |
+//! | `katna` | `word` | a [`Report`], like [`describe_word`] |
+//! | `score` | `lujvo` | a [`ScoreReport`](crate::report::ScoreReport) |
+//! | `rafsi` | `word` | a [`RafsiLookup`](crate::report::RafsiLookup) |
+//! | `settings` | | the session's [`SettingsReport`] |
+//! | `set_settings` | `flags` and/or long options | the new [`SettingsReport`] |
+//!
+//! Params are objects. `set_settings` takes the same names as the CLI's long
+//! options (see [`Settings::apply_option`]), with strings, booleans, or
+//! numbers as values; `flags` (e.g. `"cA2"`) replaces all the settings first.
+//! The settings last set are used for every later request in the session.
+//! Batches and notifications work as the specification says, and errors from
+//! the crate itself are part of the result, as in [`Report`].
+//! ```
+//! use latkerlo_jvotci::rpc::Session;
+//!
+//! let mut session = Session::default();
+//! session.handle(r#"{"jsonrpc":"2.0","id":1,"method":"set_settings","params":{"flags":"A"}}"#);
+//! let res = session.handle(r#"{"jsonrpc":"2.0","id":2,"method":"zba","params":{"tanru":"bangu girzu"}}"#);
+//! assert!(res.unwrap().contains(r#""lujvo":"baugri""#));
+//! ```
+
+use std::io::{self, BufRead, Write};
+
+use itertools::Itertools as _;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use crate::{
+    cache::{Cache, with_cache},
+    report::{
+        Report, SettingsReport, describe, describe_score, describe_tanru, describe_word,
+        lookup_rafsi,
+    },
+    tarmi::Settings,
+};
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// The state kept between requests.
+#[derive(Debug, Default, Clone)]
+pub struct Session {
+    pub settings: Settings,
+    /// Remembers analyses for later requests.
+    pub cache: Cache,
+}
+
+/// Serializes a result, keeping the order of its fields (which a [`Value`]
+/// wouldn't).
+fn to_json(value: &impl Serialize) -> String { serde_json::to_string(value).unwrap() }
+
+fn response(id: &Value, result: Result<String, (i32, String)>) -> String {
+    match result {
+        Ok(result) => format!(r#"{{"jsonrpc":"2.0","id":{id},"result":{result}}}"#),
+        Err((code, message)) => {
+            let error = json!({ "code": code, "message": message });
+            format!(r#"{{"jsonrpc":"2.0","id":{id},"error":{error}}}"#)
+        }
+    }
+}
+
+/// Gets a string param.
+fn param<'a>(params: &'a Map<String, Value>, name: &str) -> Result<&'a str, (i32, String)> {
+    params
+        .get(name)
+        .and_then(Value::as_str)
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| (INVALID_PARAMS, format!("needs a string {{{name}}}")))
+}
+
+impl Session {
+    /// Makes a session starting with the given settings.
+    #[must_use]
+    pub fn new(settings: Settings) -> Self { Session { settings, cache: Cache::default() } }
+
+    /// Answers one line, which may be a single request or a batch. Returns
+    /// `None` if nothing should be sent back, i.e. for notifications.
+    #[must_use]
+    pub fn handle(&mut self, line: &str) -> Option<String> {
+        match serde_json::from_str::<Value>(line) {
+            Ok(Value::Array(batch)) if !batch.is_empty() => {
+                let responses = batch.iter().filter_map(|r| self.handle_request(r)).collect_vec();
+                (!responses.is_empty()).then(|| format!("[{}]", responses.join(",")))
+            }
+            Ok(request) => self.handle_request(&request),
+            Err(e) => Some(response(&Value::Null, Err((PARSE_ERROR, e.to_string())))),
+        }
+    }
+
+    fn handle_request(&mut self, request: &Value) -> Option<String> {
+        let Some(request) = request.as_object().filter(|r| r.get("jsonrpc") == Some(&json!("2.0")))
+        else {
+            return Some(response(&Value::Null, Err((INVALID_REQUEST, "not a request".into()))));
+        };
+        let result = match (request.get("method").and_then(Value::as_str), request.get("params")) {
+            (Some(method), None) => self.call(method, &Map::new()),
+            (Some(method), Some(Value::Object(params))) => self.call(method, params),
+            (Some(_), Some(_)) => Err((INVALID_PARAMS, "params must be an object".into())),
+            (None, _) => Err((INVALID_REQUEST, "needs a string {method}".into())),
+        };
+        request.get("id").map(|id| response(id, result))
+    }
+
+    fn call(&mut self, method: &str, params: &Map<String, Value>) -> Result<String, (i32, String)> {
+        let (settings, cache) = (&self.settings, &mut self.cache);
+        let report: Report = match method {
+            "analyze" => {
+                let input = param(params, "input")?;
+                with_cache(cache, || describe(input, settings))
+            }
+            "zba" => {
+                let tanru = param(params, "tanru")?;
+                with_cache(cache, || describe_tanru(tanru, settings))
+            }
+            "katna" => {
+                let word = param(params, "word")?;
+                with_cache(cache, || describe_word(word, settings))
+            }
+            "score" => {
+                let lujvo = param(params, "lujvo")?;
+                return Ok(to_json(&with_cache(cache, || describe_score(lujvo, settings))));
+            }
+            "rafsi" => return Ok(to_json(&lookup_rafsi(param(params, "word")?))),
+            "settings" => return Ok(to_json(&SettingsReport::from(*settings))),
+            "set_settings" => {
+                self.settings = self.apply_settings(params)?;
+                return Ok(to_json(&SettingsReport::from(self.settings)));
+            }
+            _ => return Err((METHOD_NOT_FOUND, format!("no method {{{method}}}"))),
+        };
+        Ok(to_json(&report))
+    }
+
+    /// Works out the settings `set_settings` asks for, without changing
+    /// anything if they're invalid.
+    fn apply_settings(&self, params: &Map<String, Value>) -> Result<Settings, (i32, String)> {
+        let invalid = |name: &str| (INVALID_PARAMS, format!("invalid {{{name}}}"));
+        let mut settings = self.settings;
+        // like in the CLI, long options take precedence over flags
+        if let Some(flags) = params.get("flags") {
+            let flags = flags.as_str().ok_or_else(|| invalid("flags"))?;
+            settings =
+                flags.strip_prefix('-').unwrap_or(flags).parse().map_err(|_| invalid("flags"))?;
+        }
+        for (name, value) in params.iter().filter(|(n, _)| *n != "flags") {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                Value::Bool(b) => b.to_string(),
+                Value::Number(n) => n.to_string(),
+                _ => return Err(invalid(name)),
+            };
+            settings.apply_option(name, Some(&value)).ok_or_else(|| invalid(name))?;
+        }
+        Ok(settings)
+    }
+}
+
+/// Answers each line of `input` on a line of `output` until `input` ends.
+/// Blank lines are skipped.
+/// # Errors
+/// If reading or writing fails.
+pub fn run(input: impl BufRead, mut output: impl Write, settings: &Settings) -> io::Result<()> {
+    let mut session = Session::new(*settings);
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(res) = session.handle(&line) {
+            writeln!(output, "{res}")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,358 @@
+//! Functions for splitting words into syllables and finding where the stress
+//! goes.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    exceptions::Jvonunfli::{self, DecompositionError},
+    lerfu::{LerfuSettings, transliterate},
+    tarmi::{
+        BrivlaType::{Cmevla, ExtendedLujvo, Lujvo},
+        SONORANTS, Settings, check_lojban_characters, is_consonant, is_glide, is_vowel,
+        is_zihevla_initial_cluster, split_vowel_cluster,
+    },
+    tools::{analyze_brivla, is_brivla, normalize},
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// A single syllable. Apostrophes and on-glides (the *i* in *ia*) are part of
+/// the onset; the nucleus is a vowel, diphthong, *y*, or syllabic consonant.
+pub struct Syllable {
+    pub onset: String,
+    pub nucleus: String,
+    pub coda: String,
+}
+
+impl Syllable {
+    /// Returns `true` if the nucleus is a syllabic *l*, *m*, *n*, or *r*.
+    #[must_use]
+    pub fn is_syllabic_consonant(&self) -> bool { self.nucleus.chars().all(is_consonant) }
+
+    /// Returns `true` if the syllable counts when finding the penultimate
+    /// syllable. Syllables with *y* or a syllabic consonant don't.
+    #[must_use]
+    pub fn counts_for_stress(&self) -> bool { self.nucleus != "y" && !self.is_syllabic_consonant() }
+}
+
+impl fmt::Display for Syllable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.onset, self.nucleus, self.coda)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// The kinds of words that have different stress rules.
+pub enum WordClass {
+    /// Stressed on the penultimate syllable.
+    Brivla,
+    /// Stressed on the penultimate syllable unless marked otherwise.
+    Cmevla,
+    /// Not required to be stressed at all.
+    Cmavo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The result of [`syllabify`].
+pub struct Syllabification {
+    pub syllables: Vec<Syllable>,
+    /// The index of the stressed syllable, if the word has required stress.
+    pub stress: Option<usize>,
+    pub class: WordClass,
+}
+
+impl fmt::Display for Syllabification {
+    /// Shows the syllables separated by periods, with the stressed one in
+    /// uppercase (e.g. *lat.KER.lo*).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s =
+            self.syllables
+                .iter()
+                .enumerate()
+                .map(|(i, s)| {
+                    if self.stress == Some(i) {
+                        s.to_string().to_uppercase()
+                    } else {
+                        s.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(".");
+        write!(f, "{s}")
+    }
+}
+
+/// Pieces of a word before they are grouped into syllables.
+enum Piece {
+    Nucleus { glide: Option<char>, nucleus: String },
+    Consonant(char),
+    Apostrophe,
+}
+
+/// Returns the index of the stressed syllable: the penultimate one, ignoring
+/// syllables with *y* or a syllabic consonant. If only one syllable counts, it
+/// is stressed.
+#[must_use]
+pub fn penultimate(syllables: &[Syllable]) -> Option<usize> {
+    let counted =
+        (0..syllables.len()).filter(|&i| syllables[i].counts_for_stress()).collect::<Vec<_>>();
+    match counted.len() {
+        0 => None,
+        1 => Some(counted[0]),
+        n => Some(counted[n - 2]),
+    }
+}
+
+/// Splits a word into syllables and finds its stress. Brivla and cmevla are
+/// stressed on the penultimate syllable (see [`penultimate`]); cmavo have no
+/// stress. Cmevla and cmavo with uppercase letters (see
+/// [`transliterate`]) are stressed on the syllable with the first one instead.
+///
+/// *l*, *m*, *n*, and *r* become syllabic if they come after a consonant and
+/// before a consonant or the end of the word (e.g. *xrabl* → *xra.bl*).
+/// Consonants between vowels go to the following syllable if they can start a
+/// word, and to the preceding one otherwise.
+/// # Errors
+/// A [`NonLojbanCharacterError`](Jvonunfli::NonLojbanCharacterError) is
+/// returned if the word contains something other than Lojban letters.
+///
+/// A [`DecompositionError`] is returned if the word is empty, has no vowels,
+/// or contains a bad vowel sequence.
+pub fn syllabify(word: &str, settings: &Settings) -> Result<Syllabification, Jvonunfli> {
+    let marked = transliterate(word, &LerfuSettings::default());
+    let word = normalize(word);
+    if word.is_empty() {
+        return Err(DecompositionError("empty string".to_string()));
+    }
+    check_lojban_characters(&word)?;
+    let chars = word.chars().collect::<Vec<_>>();
+    let mut pieces = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if is_vowel(c) {
+            let run = chars[i..].iter().take_while(|c| is_vowel(**c)).collect::<String>();
+            i += run.len();
+            for group in split_vowel_cluster(&run)? {
+                if is_glide(&group) {
+                    pieces.push(Piece::Nucleus {
+                        glide: group.chars().next(),
+                        nucleus: group[1..].to_string(),
+                    });
+                } else {
+                    pieces.push(Piece::Nucleus { glide: None, nucleus: group });
+                }
+            }
+            continue;
+        }
+        if c == 'y' {
+            pieces.push(Piece::Nucleus { glide: None, nucleus: "y".to_string() });
+        } else if c == '\'' {
+            pieces.push(Piece::Apostrophe);
+        } else if SONORANTS.contains(c)
+            && matches!(pieces.last(), Some(Piece::Consonant(_)))
+            && chars.get(i + 1).is_none_or(|n| is_consonant(*n))
+        {
+            pieces.push(Piece::Nucleus { glide: None, nucleus: c.to_string() });
+        } else {
+            pieces.push(Piece::Consonant(c));
+        }
+        i += 1;
+    }
+    let mut syllables: Vec<Syllable> = vec![];
+    let mut pending = String::new();
+    for piece in pieces {
+        match piece {
+            Piece::Consonant(c) => pending.push(c),
+            Piece::Apostrophe => pending.push('\''),
+            Piece::Nucleus { glide, nucleus } => {
+                let mut onset = if let Some(last) = syllables.last_mut() {
+                    let split = if let Some(h) = pending.find('\'') {
+                        h
+                    } else {
+                        (0..=pending.len().min(3))
+                            .rev()
+                            .map(|k| pending.len() - k)
+                            .find(|&s| is_zihevla_initial_cluster(&pending[s..]))
+                            .unwrap_or(pending.len())
+                    };
+                    last.coda += &pending[..split];
+                    pending[split..].to_string()
+                } else {
+                    pending.clone()
+                };
+                pending.clear();
+                onset.extend(glide);
+                syllables.push(Syllable { onset, nucleus, coda: String::new() });
+            }
+        }
+    }
+    let Some(last) = syllables.last_mut() else {
+        return Err(DecompositionError(format!("{{{word}}} has no syllables")));
+    };
+    last.coda += &pending;
+    let class = if is_consonant(chars[chars.len() - 1]) {
+        WordClass::Cmevla
+    } else if is_brivla(&word, settings) {
+        WordClass::Brivla
+    } else {
+        WordClass::Cmavo
+    };
+    let stress = if let Some(&pos) = marked.stress().first()
+        && class != WordClass::Brivla
+    {
+        // normalizing only removes periods and commas
+        let pos = marked.text.chars().take(pos).filter(|c| !".,".contains(*c)).count();
+        let mut end = 0;
+        syllables.iter().position(|s| {
+            end += s.to_string().len();
+            pos < end
+        })
+    } else if class == WordClass::Cmavo {
+        None
+    } else {
+        penultimate(&syllables)
+    };
+    Ok(Syllabification { syllables, stress, class })
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+/// How much detail [`to_ipa`] shows.
+///
+/// `Broad` gives a phonemic transcription between slashes. `Narrow` gives a
+/// phonetic one between brackets, with syllable breaks, non-syllabic diphthong
+/// offglides, syllabic consonants, and the glottal stop before a word-initial
+/// vowel.
+pub enum IpaMode {
+    #[default]
+    Broad,
+    Narrow,
+}
+
+/// Gets the IPA for a single letter.
+fn ipa_letter(c: char) -> &'static str {
+    match c {
+        'e' => "ɛ",
+        'y' => "ə",
+        'c' => "ʃ",
+        'g' => "ɡ",
+        'j' => "ʒ",
+        '\'' => "h",
+        'a' => "a",
+        'b' => "b",
+        'd' => "d",
+        'f' => "f",
+        'i' => "i",
+        'k' => "k",
+        'l' => "l",
+        'm' => "m",
+        'n' => "n",
+        'o' => "o",
+        'p' => "p",
+        'r' => "r",
+        's' => "s",
+        't' => "t",
+        'u' => "u",
+        'v' => "v",
+        'x' => "x",
+        'z' => "z",
+        _ => "",
+    }
+}
+
+/// Moves syllable breaks inside consonant clusters onto rafsi boundaries, so
+/// e.g. *-kas-* + *-tru-* splits as *kas.tru* and not *ka.stru*.
+fn align_to_rafsi(syllables: &mut [Syllable], boundaries: &[usize]) {
+    let mut end = 0;
+    for i in 0..syllables.len().saturating_sub(1) {
+        end += syllables[i].to_string().len();
+        if boundaries.contains(&end) || !syllables[i + 1].onset.chars().all(is_consonant) {
+            continue;
+        }
+        let start = end - syllables[i].coda.len();
+        let stop = end + syllables[i + 1].onset.len();
+        if let Some(&b) = boundaries.iter().find(|&&b| start <= b && b <= stop) {
+            let cluster = syllables[i].coda.clone() + &syllables[i + 1].onset;
+            syllables[i].coda = cluster[..b - start].to_string();
+            syllables[i + 1].onset = cluster[b - start..].to_string();
+            end = b;
+        }
+    }
+}
+
+/// Transcribes a word into the IPA. The stressed syllable is marked with *ˈ*,
+/// *y* is a schwa, apostrophes are *h*, and *i*/*u* glides are *j*/*w*.
+///
+/// If the word is a lujvo or decomposable cmevla, syllable breaks inside
+/// consonant clusters are moved to rafsi boundaries. If `buffer_vowels` is
+/// set, a buffer vowel *ɪ* is inserted between consonants on either side of a
+/// syllable break.
+/// # Errors
+/// Errors are forwarded from [`syllabify`].
+pub fn to_ipa(
+    word: &str,
+    mode: IpaMode,
+    buffer_vowels: bool,
+    settings: &Settings,
+) -> Result<String, Jvonunfli> {
+    let Syllabification { mut syllables, stress, .. } = syllabify(word, settings)?;
+    if let Ok((b_type, parts)) = analyze_brivla(word, settings)
+        && [Lujvo, ExtendedLujvo, Cmevla].contains(&b_type)
+    {
+        let boundaries = parts
+            .iter()
+            .scan(0, |pos, p| {
+                *pos += p.len();
+                Some(*pos)
+            })
+            .collect::<Vec<_>>();
+        align_to_rafsi(&mut syllables, &boundaries);
+    }
+    let narrow = mode == IpaMode::Narrow;
+    let mut res = String::new();
+    for (i, syllable) in syllables.iter().enumerate() {
+        if i > 0
+            && buffer_vowels
+            && syllables[i - 1].coda.ends_with(is_consonant)
+            && syllable.onset.starts_with(is_consonant)
+        {
+            res += "ɪ";
+        }
+        if stress == Some(i) {
+            res += "ˈ";
+        } else if i > 0 && narrow {
+            res += ".";
+        }
+        if i == 0 && narrow && syllable.onset.is_empty() {
+            res += "ʔ";
+        }
+        for (j, c) in syllable.onset.chars().enumerate() {
+            res += match c {
+                'i' if j == syllable.onset.len() - 1 => "j",
+                'u' if j == syllable.onset.len() - 1 => "w",
+                _ => ipa_letter(c),
+            };
+        }
+        let mut nucleus = syllable.nucleus.chars();
+        res.extend(nucleus.next().map(ipa_letter));
+        if narrow && syllable.is_syllabic_consonant() {
+            res += "\u{329}";
+        }
+        for c in nucleus {
+            res += match (c, narrow) {
+                ('i', false) => "j",
+                ('u', false) => "w",
+                ('i', true) => "i\u{32f}",
+                ('u', true) => "u\u{32f}",
+                _ => ipa_letter(c),
+            };
+        }
+        res.extend(syllable.coda.chars().map(ipa_letter));
+    }
+    Ok(if narrow { format!("[{res}]") } else { format!("/{res}/") })
+}
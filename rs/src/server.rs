@@ -0,0 +1,167 @@
+//! A small local HTTP server with JSON endpoints, for calling the crate from
+//! other languages. Needs the `server` feature; the CLI runs it with
+//! `jvotci serve --port N`.
+//!
+//! Every endpoint takes `GET` requests with the input in the query string:
+//!
+//! | endpoint | parameter | result |
+//! | --- | --- | --- |
+//! | `/analyze` | `input` | a [`Report`], like [`describe`] |
+//! | `/zba` | `tanru` | a [`Report`], like [`describe_tanru`] |
+//! | `/katna` | `word` | a [`Report`], like [`describe_word`] |
+//! | `/score` | `lujvo` | a [`ScoreReport`](crate::report::ScoreReport) |
+//! | `/rafsi` | `word` | a [`RafsiLookup`](crate::report::RafsiLookup) |
+//! | `/settings` | | a [`SettingsReport`] for each of [`SETTINGS_ITERATOR`] |
+//!
+//! Settings are given with `flags` (e.g. `flags=cA2`) or the CLI's long
+//! options (e.g. `y-hyphens=allow-y`); see [`Settings::apply_option`]. Given
+//! to `/settings`, they return only the settings they select. Invalid
+//! requests get a 4xx status and `{ "error": "..." }`; errors from the crate
+//! itself are part of the result, as in [`Report`].
+//! ```
+//! use latkerlo_jvotci::server::handle;
+//!
+//! let (status, body) = handle("/zba?tanru=bangu+girzu&flags=A");
+//! assert_eq!(status, 200);
+//! assert!(body.contains(r#""lujvo":"baugri""#));
+//! ```
+
+use std::io;
+
+use serde::Serialize;
+use serde_json::json;
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::{
+    cache::{Cache, with_cache},
+    report::{
+        Report, SettingsReport, describe, describe_score, describe_tanru, describe_word,
+        lookup_rafsi,
+    },
+    tarmi::{SETTINGS_ITERATOR, Settings},
+};
+
+/// Decodes a `application/x-www-form-urlencoded` component. Returns `None` if
+/// it isn't valid UTF-8 or has a bad escape.
+fn decode(s: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                bytes.push(u8::from_str_radix(str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Splits a query string into decoded names and values.
+fn parse_query(query: &str) -> Option<Vec<(String, Option<String>)>> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| match p.split_once('=') {
+            Some((n, v)) => Some((decode(n)?, Some(decode(v)?))),
+            None => Some((decode(p)?, None)),
+        })
+        .collect()
+}
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    (status, json!({ "error": message }).to_string())
+}
+
+fn ok(value: &impl Serialize) -> (u16, String) { (200, serde_json::to_string(value).unwrap()) }
+
+/// Answers a request for `path` (with its query string) as [`serve`] would,
+/// using the default settings where the query doesn't say otherwise. Returns
+/// the HTTP status and the JSON body.
+#[must_use]
+pub fn handle(path: &str) -> (u16, String) { handle_with(path, &Settings::default()) }
+
+/// Like [`handle`], but with different default settings.
+#[must_use]
+pub fn handle_with(path: &str, defaults: &Settings) -> (u16, String) {
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    let Some(params) = parse_query(query) else {
+        return error(400, "invalid query string");
+    };
+    let mut settings = *defaults;
+    let mut input = None;
+    let mut custom = false;
+    let name = match path {
+        "/analyze" => "input",
+        "/zba" => "tanru",
+        "/katna" | "/rafsi" => "word",
+        "/score" => "lujvo",
+        _ => "",
+    };
+    // like in the CLI, long options take precedence over flags
+    if let Some((_, flags)) = params.iter().find(|(p, _)| p == "flags") {
+        let flags = flags.as_deref().unwrap_or_default();
+        match flags.strip_prefix('-').unwrap_or(flags).parse() {
+            Ok(s) => settings = s,
+            Err(_) => return error(400, &format!("invalid flags {{{flags}}}")),
+        }
+        custom = true;
+    }
+    for (param, value) in params.iter().filter(|(p, _)| p != "flags") {
+        if param == name {
+            input = value.as_deref();
+        } else if settings.apply_option(param, value.as_deref()).is_some() {
+            custom = true;
+        } else {
+            return error(400, &format!("invalid parameter {{{param}}}"));
+        }
+    }
+    if path == "/settings" {
+        return if custom {
+            ok(&SettingsReport::from(settings))
+        } else {
+            ok(&SETTINGS_ITERATOR.iter().map(|&s| SettingsReport::from(s)).collect::<Vec<_>>())
+        };
+    }
+    if name.is_empty() {
+        return error(404, &format!("no endpoint {{{path}}}"));
+    }
+    let Some(input) = input.filter(|i| !i.trim().is_empty()) else {
+        return error(400, &format!("{{{path}}} needs {{{name}}}"));
+    };
+    let report: Report = match path {
+        "/analyze" => describe(input, &settings),
+        "/zba" => describe_tanru(input, &settings),
+        "/katna" => describe_word(input, &settings),
+        "/rafsi" => return ok(&lookup_rafsi(input)),
+        _ => return ok(&describe_score(input, &settings)),
+    };
+    ok(&report)
+}
+
+/// Serves [`handle_with`] on `127.0.0.1:port` until the process is stopped.
+/// Responses allow any origin, so web pages can call the server. Analyses are
+/// remembered across requests in a [`Cache`].
+/// # Errors
+/// If the port can't be bound.
+#[allow(clippy::missing_panics_doc)] // the headers are valid
+pub fn serve(port: u16, defaults: &Settings) -> io::Result<()> {
+    let server = Server::http(("127.0.0.1", port)).map_err(io::Error::other)?;
+    let mut cache = Cache::default();
+    for request in server.incoming_requests() {
+        let (status, body) = if *request.method() == Method::Get {
+            with_cache(&mut cache, || handle_with(request.url(), defaults))
+        } else {
+            error(405, "only GET is supported")
+        };
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "application/json").unwrap())
+            .with_header(Header::from_bytes("Access-Control-Allow-Origin", "*").unwrap());
+        // a client hanging up shouldn't stop the server
+        let _ = request.respond(response);
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! Functions for checking new gismu against existing ones, using the rules in
+//! CLL section 4.14.
+
+use alloc::{format, string::String, vec::Vec};
+
+use itertools::Itertools as _;
+
+use crate::{
+    exceptions::Jvonunfli::{self, NotBrivlaError},
+    extract,
+    rafsi::RAFSI_LIST,
+    tarmi::{Settings, is_gismu, is_gismu_shape},
+    tools::normalize,
+};
+
+/// Pairs of consonants that are too similar for two gismu to differ only by
+/// them.
+pub const SIMILAR_CONSONANTS: [(char, &str); 17] = [
+    ('b', "pv"),
+    ('c', "js"),
+    ('d', "t"),
+    ('f', "pv"),
+    ('g', "kx"),
+    ('j', "cz"),
+    ('k', "cgx"),
+    ('l', "r"),
+    ('m', "n"),
+    ('n', "m"),
+    ('p', "bf"),
+    ('r', "l"),
+    ('s', "cz"),
+    ('t', "d"),
+    ('v', "bf"),
+    ('x', "gk"),
+    ('z', "js"),
+];
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+/// Why a gismu candidate conflicts with an existing gismu.
+pub enum Conflict {
+    /// The two are the same word.
+    Identical,
+    /// Only the final vowels are different.
+    FinalVowel,
+    /// Only the consonants at this index are different, and they are in
+    /// [`SIMILAR_CONSONANTS`].
+    SimilarConsonant(usize),
+}
+
+/// Returns `true` if `a` and `b` are too similar to tell apart.
+#[must_use]
+pub fn are_similar_consonants(a: char, b: char) -> bool {
+    SIMILAR_CONSONANTS
+        .iter()
+        .any(|(c, similar)| *c == a && similar.contains(b) || *c == b && similar.contains(a))
+}
+
+/// Checks whether `candidate` conflicts with `gismu`. Both should be gismu.
+#[must_use]
+pub fn gismu_conflict(candidate: &str, gismu: &str) -> Option<Conflict> {
+    if candidate == gismu {
+        return Some(Conflict::Identical);
+    }
+    let diffs =
+        candidate.chars().zip(gismu.chars()).enumerate().filter(|(_, (a, b))| a != b).collect_vec();
+    match diffs[..] {
+        [(4, _)] if candidate.len() == 5 && gismu.len() == 5 => Some(Conflict::FinalVowel),
+        [(i, (a, b))] if are_similar_consonants(a, b) => Some(Conflict::SimilarConsonant(i)),
+        _ => None,
+    }
+}
+
+/// Finds the existing gismu (the gismu-shaped words in [`RAFSI_LIST`]) that
+/// conflict with `candidate`, sorted alphabetically.
+/// # Errors
+/// See [`find_gismu_conflicts_in_list`].
+pub fn find_gismu_conflicts(
+    candidate: &str,
+    settings: &Settings,
+) -> Result<Vec<(String, Conflict)>, Jvonunfli> {
+    let gismu_list = RAFSI_LIST.iter().map(|&(v, _)| v).filter(|v| is_gismu_shape(v)).collect_vec();
+    find_gismu_conflicts_in_list(candidate, &gismu_list, settings)
+}
+
+/// Finds the words in `gismu_list` that conflict with `candidate`, sorted
+/// alphabetically.
+/// # Errors
+/// A [`NotBrivlaError`] is returned if the candidate isn't a valid gismu.
+pub fn find_gismu_conflicts_in_list(
+    candidate: &str,
+    gismu_list: &[&str],
+    settings: &Settings,
+) -> Result<Vec<(String, Conflict)>, Jvonunfli> {
+    let candidate = normalize(candidate);
+    if !is_gismu(&candidate, &extract!(settings; allow_mz)) {
+        return Err(NotBrivlaError(format!("{{{candidate}}} is not a valid gismu")));
+    }
+    Ok(gismu_list
+        .iter()
+        .map(|g| normalize(g))
+        .filter(|g| g.len() == candidate.len())
+        .filter_map(|g| gismu_conflict(&candidate, &g).map(|c| (g, c)))
+        .sorted()
+        .dedup()
+        .collect())
+}
@@ -1,19 +1,23 @@
 //! Functions for determining the shapes of rafsi and checking simple properties
 //! of characters, and `Settings`.
 
-use std::{collections::VecDeque, fmt, str::FromStr, sync::LazyLock};
-
-use itertools::{Itertools as _, iproduct};
-use regex::Regex;
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
 
 use crate::{
     data::{
-        FOLLOW_VOWEL_CLUSTERS, INITIAL, MZ_VALID, START_VOWEL_CLUSTERS, VALID, ZIHEVLA_INITIAL,
+        FOLLOW_VOWEL_CLUSTERS_LIST, INITIAL_TABLE, START_VOWEL_CLUSTERS_LIST, VALID_TABLE,
+        ZIHEVLA_INITIAL_TABLE, is_valid_cluster,
     },
-    exceptions::Jvonunfli::{self, DecompositionError},
+    exceptions::Jvonunfli::{self, DecompositionError, NonLojbanCharacterError},
     jvozba::Tosytype,
-    strin, strsl,
-    tools::regex_replace_all,
+    tools::{cut, cut_back, drop_back, letter, letter_back},
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -34,6 +38,7 @@ pub enum Tarmi {
 pub const SONORANTS: &str = "lmnr";
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
 pub enum BrivlaType {
     Gismu,
     Zihevla,
@@ -46,7 +51,8 @@ pub enum BrivlaType {
 ///
 /// Setting `AllowY` makes *'y* a valid replacement for CLL's *r*/*n* hyphens.
 /// `ForceY` requires *'y*, treating e.g. *voirli'u* as a zi'evla.
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
 pub enum YHyphenSetting {
     #[default]
     Standard,
@@ -59,7 +65,8 @@ pub enum YHyphenSetting {
 /// *nei'ynei* that cannot fall apart or combine with other words, and do not
 /// break any of Lojban's morphology. Setting `TwoConsonants` or `OneConsonant`
 /// lets these be valid lujvo.
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "snake_case"))]
 pub enum ConsonantSetting {
     #[default]
     Cluster,
@@ -70,7 +77,8 @@ use ConsonantSetting::{Cluster, OneConsonant, TwoConsonants};
 use Tarmi::{Ccv, Ccvc, Ccvcv, Cvc, Cvcc, Cvccv, Cvhv, Cvv, Hyphen, OtherRafsi};
 use YHyphenSetting::{AllowY, ForceY, Standard};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Settings {
     /// Whether the lujvo should end in a consonant. This only affects *making*
@@ -101,16 +109,33 @@ macro_rules! extract {
         }
     };
 }
-/// A list of every [`Settings`].
-pub static SETTINGS_ITERATOR: LazyLock<Vec<Settings>> = LazyLock::new(|| {
-    iproduct!(["", "c"], ["", "A", "F"], ["", "2", "1"], ["", "r"], ["", "g"], ["", "z"])
-        .map(|(generate_cmevla, y_hyphens, exp_rafsi, consonants, glides, allow_mz)| {
-            format!("{generate_cmevla}{y_hyphens}{exp_rafsi}{consonants}{glides}{allow_mz}")
-                .parse()
-                .unwrap()
-        })
-        .collect_vec()
-});
+/// A list of every [`Settings`], ordered by their flags: `""`, `"z"`, `"g"`,
+/// `"gz"`, `"r"`, …, `"cF1rgz"`.
+pub static SETTINGS_ITERATOR: [Settings; 144] = every_settings();
+
+const fn every_settings() -> [Settings; 144] {
+    let mut res = [Settings {
+        generate_cmevla: false,
+        y_hyphens: Standard,
+        consonants: Cluster,
+        exp_rafsi: false,
+        glides: false,
+        allow_mz: false,
+    }; 144];
+    let mut i = 0;
+    while i < res.len() {
+        res[i] = Settings {
+            generate_cmevla: i / 72 % 2 == 1,
+            y_hyphens: [Standard, AllowY, ForceY][i / 24 % 3],
+            consonants: [Cluster, TwoConsonants, OneConsonant][i / 8 % 3],
+            exp_rafsi: i / 4 % 2 == 1,
+            glides: i / 2 % 2 == 1,
+            allow_mz: i % 2 == 1,
+        };
+        i += 1;
+    }
+    res
+}
 
 impl fmt::Display for Settings {
     /// A representation of `self` as a string. Can be reparsed with the
@@ -201,6 +226,43 @@ impl Settings {
         }
         Some(())
     }
+
+    /// Sets one field from a long option name and value, as used by the CLI:
+    /// `cmevla`, `exp-rafsi`, `glides`, and `allow-mz` take `true` or `false`
+    /// (or nothing, meaning `true`); `y-hyphens` takes `standard`, `allow-y`,
+    /// or `force-y`; `consonants` takes `cluster`, `2`, or `1`. Returns `None`
+    /// if the name or value is invalid.
+    pub fn apply_option(&mut self, name: &str, value: Option<&str>) -> Option<()> {
+        let flag = || match value {
+            None | Some("true") => Some(true),
+            Some("false") => Some(false),
+            _ => None,
+        };
+        match name {
+            "cmevla" => self.generate_cmevla = flag()?,
+            "exp-rafsi" => self.exp_rafsi = flag()?,
+            "glides" => self.glides = flag()?,
+            "allow-mz" => self.allow_mz = flag()?,
+            "y-hyphens" => {
+                self.y_hyphens = match value? {
+                    "standard" => Standard,
+                    "allow-y" => AllowY,
+                    "force-y" => ForceY,
+                    _ => return None,
+                }
+            }
+            "consonants" => {
+                self.consonants = match value? {
+                    "cluster" => Cluster,
+                    "2" | "two" => TwoConsonants,
+                    "1" | "one" => OneConsonant,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+        Some(())
+    }
 }
 
 /// Auto-implements `Display` on an enum.
@@ -208,8 +270,8 @@ impl Settings {
 macro_rules! auto_to_string {
     ($($e:ident),*) => {
         $(
-            impl std::fmt::Display for $e {
-                fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            impl ::core::fmt::Display for $e {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     write!(f, "{self:?}")
                 }
             }
@@ -234,7 +296,7 @@ pub fn is_consonant(c: char) -> bool { "bcdfgjklmnprstvxz".contains(c) }
 /// Returns `true` if `s` starts with an on-glide / rising diphthong (*i*/*u* +
 /// vowel).
 pub fn is_glide(s: &str) -> bool {
-    s.len() >= 2 && "iu".contains(strin!(s, 0)) && is_vowel(strin!(s, 1))
+    s.len() >= 2 && "iu".contains(letter(s, 0)) && is_vowel(letter(s, 1))
 }
 
 #[must_use]
@@ -244,6 +306,20 @@ pub fn is_only_lojban_characters(s: &str) -> bool {
     !s.is_empty() && s.chars().all(|c| "aeioubcdfgjklmnprstvxz'".contains(c))
 }
 
+/// Checks that `s` only has Lojban letters, including *y* and apostrophes.
+/// Anything that passes this is ASCII.
+/// # Errors
+/// A [`NonLojbanCharacterError`] is returned if it has anything else.
+pub fn check_lojban_characters(s: &str) -> Result<(), Jvonunfli> {
+    match s.chars().find(|&c| !(is_vowel(c) || is_consonant(c) || "y'".contains(c))) {
+        None => Ok(()),
+        Some(c) => Err(NonLojbanCharacterError(format!(
+            "{{{s}}} contains unexpected character {{{c}}} (u+{:04x})",
+            c as u32
+        ))),
+    }
+}
+
 /// Returns `true` if any character in `s` is a consonant.
 pub fn contains_consonant(s: &str) -> bool { s.chars().any(is_consonant) }
 
@@ -251,35 +327,37 @@ pub fn contains_consonant(s: &str) -> bool { s.chars().any(is_consonant) }
 /// Returns `true` if `v` is CVCCV or CCVCV. Doesn't check clusters.
 pub fn is_gismu_shape(v: &str) -> bool {
     v.len() == 5
-        && is_consonant(strin!(v, 0))
-        && is_consonant(strin!(v, 3))
-        && is_vowel(strin!(v, 4))
-        && (is_vowel(strin!(v, 1)) && is_consonant(strin!(v, 2))
-            || is_consonant(strin!(v, 1)) && is_vowel(strin!(v, 2)))
+        && is_consonant(letter(v, 0))
+        && is_consonant(letter(v, 3))
+        && is_vowel(letter(v, 4))
+        && (is_vowel(letter(v, 1)) && is_consonant(letter(v, 2))
+            || is_consonant(letter(v, 1)) && is_vowel(letter(v, 2)))
 }
 
 /// Returns `true` if `v` is a valid gismu.
 pub fn is_gismu(v: &str, settings: &Settings) -> bool {
     is_gismu_shape(v)
-        && if is_vowel(strin!(v, 1)) {
-            if settings.allow_mz { &MZ_VALID } else { &VALID }.contains(&strsl!(v, 2..4))
+        && if is_vowel(letter(v, 1)) {
+            is_valid_cluster(cut(v, 2..4), settings.allow_mz)
         } else {
-            INITIAL.contains(&strsl!(v, 0..2))
+            INITIAL_TABLE.contains(cut(v, 0..2))
         }
 }
 
 /// Splits consecutive vowels into syllables.
 /// # Errors
-/// A [`DecompositionError`] is returned if given a bad vowel sequence.
+/// A [`DecompositionError`] is returned if given a bad vowel sequence, and a
+/// [`NonLojbanCharacterError`] if given something that isn't Lojban.
 pub fn split_vowel_cluster(v: &str) -> Result<Vec<String>, Jvonunfli> {
+    check_lojban_characters(v)?;
     let old_v = v;
     let mut v = v;
     let mut res = VecDeque::new();
     macro_rules! add_to_res {
         ($new_c:expr) => {
-            let new_v = strsl!(v, 0..-($new_c.len() as isize));
-            if strin!($new_c, 0) == 'i' && ["ai", "ei", "oi"].contains(&strsl!(new_v, -2..))
-                || strin!($new_c, 0) == 'u' && strsl!(new_v, -2..) == "au"
+            let new_v = drop_back(v, $new_c.len());
+            if letter($new_c, 0) == 'i' && ["ai", "ei", "oi"].contains(&cut_back(new_v, 2))
+                || letter($new_c, 0) == 'u' && cut_back(new_v, 2) == "au"
             {
                 return Err(DecompositionError(format!("{{{old_v}}} is a bad vowel sequence")));
             }
@@ -287,14 +365,14 @@ pub fn split_vowel_cluster(v: &str) -> Result<Vec<String>, Jvonunfli> {
         };
     }
     loop {
-        if v.len() > 3 && FOLLOW_VOWEL_CLUSTERS.contains(&strsl!(v, -3..)) {
-            add_to_res!(strsl!(v, -3..));
-            v = strsl!(v, 0..-3);
-        } else if v.len() > 2 && FOLLOW_VOWEL_CLUSTERS.contains(&strsl!(v, -2..)) {
-            add_to_res!(strsl!(v, -2..));
-            v = strsl!(v, 0..-2);
+        if v.len() > 3 && FOLLOW_VOWEL_CLUSTERS_LIST.contains(cut_back(v, 3)) {
+            add_to_res!(cut_back(v, 3));
+            v = drop_back(v, 3);
+        } else if v.len() > 2 && FOLLOW_VOWEL_CLUSTERS_LIST.contains(cut_back(v, 2)) {
+            add_to_res!(cut_back(v, 2));
+            v = drop_back(v, 2);
         } else {
-            if START_VOWEL_CLUSTERS.contains(&v) || FOLLOW_VOWEL_CLUSTERS.contains(&v) {
+            if START_VOWEL_CLUSTERS_LIST.contains(v) || FOLLOW_VOWEL_CLUSTERS_LIST.contains(v) {
                 res.push_front(v.to_string());
                 return Ok(res.iter().cloned().collect());
             }
@@ -311,46 +389,108 @@ pub fn is_cmavo_compound(s: &str) -> bool {
     })
 }
 
+/// Splits a cmavo compound into the individual cmavo, along with their
+/// start/end positions. A new cmavo starts at each consonant or period, so
+/// e.g. *ko'a'e* stays in one piece.
+/// # Errors
+/// A [`DecompositionError`] is returned if any part between periods isn't a
+/// cmavo compound according to [`is_cmavo_compound`].
+pub fn split_cmavo_compound(s: &str) -> Result<Vec<(String, [usize; 2])>, Jvonunfli> {
+    let mut res = vec![];
+    let mut pos = 0;
+    for part in s.split('.') {
+        if !part.is_empty() {
+            if !part.chars().all(|c| is_vowel(c) || is_consonant(c) || "y'".contains(c))
+                || !is_cmavo_compound(part)
+            {
+                return Err(DecompositionError(format!("{{{part}}} is not a cmavo compound")));
+            }
+            let mut start = 0;
+            for (i, c) in part.char_indices().skip(1) {
+                if is_consonant(c) {
+                    res.push((part[start..i].to_string(), [pos + start, pos + i]));
+                    start = i;
+                }
+            }
+            res.push((part[start..].to_string(), [pos + start, pos + part.len()]));
+        }
+        pos += part.len() + 1;
+    }
+    Ok(res)
+}
+
 /// Returns `true` if `c` can start a zi'evla.
 pub fn is_zihevla_initial_cluster(c: &str) -> bool {
     match c.len() {
+        _ if !c.is_ascii() => false,
         1 => true,
-        2 => INITIAL.contains(&c),
-        3 => INITIAL.contains(&strsl!(c, 0..2)) && ZIHEVLA_INITIAL.contains(&strsl!(c, 1..)),
+        2 => INITIAL_TABLE.contains(c),
+        3 => INITIAL_TABLE.contains(cut(c, 0..2)) && ZIHEVLA_INITIAL_TABLE.contains(cut(c, 1..)),
         _ => false,
     }
 }
 
-// i don't know how many of these parentheses are unnecessary
-static ZIHEVLA_MIDDLE_1: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new("^([bcdfgjklmnprstvxz])?((?:[bcdfgjklmnprstvxz][lmnr])*)?$").unwrap()
-});
-static ZIHEVLA_MIDDLE_2: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(
-        "^([bcdfgjklmnprstvxz])?((?:[bcdfgjklmnprstvxz][lmnr])*)(?:\
-         ([bcdfgjkpstvxz][bcdfgjklmnprstvxz]?[lmnr]?)|([bcdfgjklmnprstvxz]))$",
-    )
-    .unwrap()
-});
+/// Returns `true` if `c` starts with a consonant followed by a sonorant.
+fn starts_with_pair(c: &[u8]) -> bool {
+    c.len() >= 2 && is_consonant(c[0] as char) && SONORANTS.contains(c[1] as char)
+}
+
+/// Returns `true` if `c` is an optional consonant followed by any number of
+/// consonant + sonorant pairs.
+fn is_pairs(c: &[u8]) -> bool {
+    let c = if c.len() % 2 == 1 && is_consonant(c[0] as char) { &c[1..] } else { c };
+    c.chunks(2).all(starts_with_pair)
+}
+
 /// Returns `true` if `c` can be inside a zi'evla.
-#[allow(clippy::missing_panics_doc)] // .unwrap()
+///
+/// Unless it ends in *ml*/*mr*, `c` is split into an optional consonant, some
+/// consonant + sonorant pairs, and then either a non-sonorant with up to two
+/// more letters after it (which must be able to start a zi'evla) or any one
+/// consonant. The first split found is the one that counts, trying the
+/// optional consonant first and then as many pairs as possible.
 pub fn is_zihevla_middle_cluster(c: &str) -> bool {
+    if !c.is_ascii() {
+        return false;
+    }
     if c.len() < 3
         || c.len() == 3
-            && (SONORANTS.contains(strin!(c, 1))
-                || VALID.contains(&strsl!(c, 0..2)) && INITIAL.contains(&strsl!(c, 1..)))
+            && (SONORANTS.contains(letter(c, 1))
+                || VALID_TABLE.contains(cut(c, 0..2)) && INITIAL_TABLE.contains(cut(c, 1..)))
     {
         return true;
     }
-    let matches = if strin!(c, -2) == 'm' && INITIAL.contains(&strsl!(c, -2..)) {
-        ZIHEVLA_MIDDLE_1
-            .captures(strsl!(c, 0..-2 - is_zihevla_initial_cluster(strsl!(c, -3..)) as isize))
-    } else {
-        ZIHEVLA_MIDDLE_2.captures(c)
-    };
-    matches.is_some_and(|m| {
-        m.get(m.len() - 2).is_none() || is_zihevla_initial_cluster(&m[m.len() - 2])
-    })
+    if letter_back(c, 1) == 'm' && INITIAL_TABLE.contains(cut_back(c, 2)) {
+        let before = drop_back(c, 2 + is_zihevla_initial_cluster(cut_back(c, 3)) as usize);
+        return is_pairs(before.as_bytes());
+    }
+    let bytes = c.as_bytes();
+    for start in [1, 0] {
+        if start == 1 && !is_consonant(bytes[0] as char) {
+            continue;
+        }
+        let mut pairs = 0;
+        while starts_with_pair(&bytes[start + 2 * pairs..]) {
+            pairs += 1;
+        }
+        for pairs in (0..=pairs).rev() {
+            let end = &bytes[start + 2 * pairs..];
+            match *end {
+                [first, ref rest @ ..]
+                    if rest.len() <= 2
+                        && is_consonant(first as char)
+                        && !SONORANTS.contains(first as char)
+                        && rest.iter().all(|&r| is_consonant(r as char))
+                        && (rest.len() < 2 || SONORANTS.contains(rest[1] as char)) =>
+                {
+                    return is_zihevla_initial_cluster(&c[c.len() - end.len()..]);
+                }
+                [only] if is_consonant(only as char) => return true,
+                _ => {}
+            }
+        }
+    }
+    false
 }
 
 #[inline]
@@ -358,9 +498,9 @@ pub fn is_zihevla_middle_cluster(c: &str) -> bool {
 pub fn is_valid_rafsi(r: &str, settings: &Settings) -> bool {
     let t = rafsi_tarmi(r);
     if [Cvccv, Cvcc].contains(&t) {
-        if settings.allow_mz { &MZ_VALID } else { &VALID }.contains(&strsl!(r, 2..4))
+        is_valid_cluster(cut(r, 2..4), settings.allow_mz)
     } else if [Ccvcv, Ccvc, Ccv].contains(&t) {
-        INITIAL.contains(&strsl!(r, 0..2))
+        INITIAL_TABLE.contains(cut(r, 0..2))
     } else {
         1 <= t as i8 && t as i8 <= 8
     }
@@ -371,25 +511,27 @@ pub fn is_valid_rafsi(r: &str, settings: &Settings) -> bool {
 /// Gets the shape of a rafsi.
 pub fn rafsi_tarmi(r: &str) -> Tarmi {
     match r.len() {
-        1 if !is_vowel(strin!(r, 0)) => Hyphen,
-        _ if !is_consonant(strin!(r, 0)) => OtherRafsi,
+        // the lengths below are in bytes, so they only work for ASCII
+        2..=5 if !r.is_ascii() => OtherRafsi,
+        1 if !is_vowel(letter(r, 0)) => Hyphen,
+        _ if !is_consonant(letter(r, 0)) => OtherRafsi,
         2 if r == "'y" => Hyphen,
-        3 => match (is_vowel(strin!(r, 1)), is_vowel(strin!(r, 2))) {
-            (true, false) if is_consonant(strin!(r, 2)) => Cvc,
+        3 => match (is_vowel(letter(r, 1)), is_vowel(letter(r, 2))) {
+            (true, false) if is_consonant(letter(r, 2)) => Cvc,
             (true, true) => Cvv,
             (false, true) => Ccv,
             _ => OtherRafsi,
         },
-        4 if strin!(r, 3) != '\'' => {
-            match (is_vowel(strin!(r, 1)), strin!(r, 2), is_vowel(strin!(r, 3))) {
+        4 if letter(r, 3) != '\'' => {
+            match (is_vowel(letter(r, 1)), letter(r, 2), is_vowel(letter(r, 3))) {
                 (true, '\'', true) => Cvhv,
-                (true, _, false) if is_consonant(strin!(r, 3)) => Cvcc,
+                (true, _, false) if is_consonant(letter(r, 3)) => Cvcc,
                 (false, v, false) if is_vowel(v) => Ccvc,
                 _ => OtherRafsi,
             }
         }
         5 if is_gismu_shape(r) => {
-            if is_vowel(strin!(r, 2)) {
+            if is_vowel(letter(r, 2)) {
                 Ccvcv
             } else {
                 Cvccv
@@ -399,11 +541,10 @@ pub fn rafsi_tarmi(r: &str) -> Tarmi {
     }
 }
 
-static BOUNDARY_Y_HYPHENS: LazyLock<Regex> = LazyLock::new(|| Regex::new("^['y]+|['y]+$").unwrap());
 #[inline]
 #[must_use]
 /// Removes hyphens from a rafsi.
-pub fn strip_hyphens(r: &str) -> String { regex_replace_all(&BOUNDARY_Y_HYPHENS, r, "") }
+pub fn strip_hyphens(r: &str) -> String { r.trim_matches(['\'', 'y']).to_string() }
 
 #[must_use]
 /// Gets the rafsi's shape after hyphens are removed.
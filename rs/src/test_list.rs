@@ -15,7 +15,7 @@ use crate::{
     jvozba::get_lujvo_from_list,
     katna::selrafsi_list_from_rafsi_list,
     tarmi::is_consonant,
-    tools::{get_rafsi_indices, regex_replace_all},
+    tools::{cut, get_rafsi_indices, letter, letter_back, regex_replace_all},
     *,
 };
 
@@ -37,29 +37,29 @@ fn check_conditions(cond: &str, settings: Settings) -> bool {
     }
     let mut i = 0;
     let left_string;
-    if strin!(cond, i) == '(' {
+    if letter(cond, i) == '(' {
         let mut depth = 1;
         while depth > 0 {
             i += 1;
-            if strin!(cond, i) == '(' {
+            if letter(cond, i) == '(' {
                 depth += 1;
-            } else if strin!(cond, i) == ')' {
+            } else if letter(cond, i) == ')' {
                 depth -= 1;
             }
         }
-        left_string = strsl!(cond, 1..i);
+        left_string = cut(cond, 1..i);
         i += 1;
-        if i as usize == cond.len() {
+        if i == cond.len() {
             return check_conditions(left_string, settings);
         }
     } else {
-        while !"|&".contains(strin!(cond, i)) {
+        while !"|&".contains(letter(cond, i)) {
             i += 1;
         }
-        left_string = strsl!(cond, 0..i).trim();
+        left_string = cut(cond, 0..i).trim();
     }
-    let operator = strin!(strsl!(cond, i..).trim(), 0);
-    let right_string = strsl!(cond, i + 1..).trim();
+    let operator = letter(cut(cond, i..).trim(), 0);
+    let right_string = cut(cond, i + 1..).trim();
     let left_side = check_conditions(left_string, settings);
     let right_side = check_conditions(right_string, settings);
     match operator {
@@ -74,7 +74,7 @@ static STRIP_ANSI: LazyLock<Regex> = LazyLock::new(|| Regex::new("\x1b\\[\\d*m")
 fn both(test: &[&str]) -> i32 {
     assert!(test.len() > 1);
     let settings =
-        Settings { generate_cmevla: is_consonant(strin!(test[0], -1)), ..Settings::default() };
+        Settings { generate_cmevla: is_consonant(letter_back(test[0], 0)), ..Settings::default() };
     let lujvo = test[0];
     let expect = test[1];
     let mut output = format!("\n\x1b[1m{lujvo}\x1b[m");
@@ -324,7 +324,7 @@ fn t_basic() {
                 }
             } else if test[2] == "KATNA" {
                 let settings = Settings {
-                    generate_cmevla: is_consonant(strin!(test[0], -1)),
+                    generate_cmevla: is_consonant(letter_back(test[0], 0)),
                     ..Settings::default()
                 };
                 if test[1] == "FAIL" {
@@ -512,3 +512,551 @@ fn bloblobloblo() {
     println!("\r\x1b[Kbloti: {i}");
     file.flush().unwrap();
 }
+
+#[test]
+fn t_sance() {
+    let settings = Settings::default();
+    for (word, expect) in [
+        ("latkerlo", "lat.KER.lo"),
+        ("blanu", "BLA.nu"),
+        ("bisycla", "BI.sy.cla"),
+        ("ti'u'erfu", "ti.'u.'ER.fu"),
+        ("uajvo", "UA.jvo"),
+        ("xrabl", "XRA.bl"),
+        ("djan", "DJAN"),
+        ("loinu", "loi.nu"),
+        ("kastrula", "ka.STRU.la"),
+    ] {
+        assert_eq!(syllabify(word, &settings).unwrap().to_string(), expect);
+    }
+    assert!(syllabify("x", &settings).is_err());
+    assert!(syllabify("弱音", &settings).is_err());
+}
+
+#[test]
+fn t_ipa() {
+    use crate::sance::{
+        IpaMode::{Broad, Narrow},
+        to_ipa,
+    };
+    let settings = Settings::default();
+    for (word, broad, narrow, buffered) in [
+        ("latkerlo", "/latˈkɛrlo/", "[latˈkɛr.lo]", "[latɪˈkɛrɪ.lo]"),
+        ("jbobau", "/ˈʒbobaw/", "[ˈʒbo.bau̯]", "[ˈʒbo.bau̯]"),
+        ("ti'u'erfu", "/tihuˈhɛrfu/", "[ti.huˈhɛr.fu]", "[ti.huˈhɛrɪ.fu]"),
+        ("xrabl", "/ˈxrabl/", "[ˈxra.bl̩]", "[ˈxra.bl̩]"),
+        ("ekspi", "/ˈɛkspi/", "[ˈʔɛk.spi]", "[ˈʔɛkɪ.spi]"),
+    ] {
+        assert_eq!(to_ipa(word, Broad, false, &settings).unwrap(), broad);
+        assert_eq!(to_ipa(word, Narrow, false, &settings).unwrap(), narrow);
+        assert_eq!(to_ipa(word, Narrow, true, &settings).unwrap(), buffered);
+    }
+}
+
+#[test]
+fn t_lerfu() {
+    use crate::lerfu::{Change, LerfuSettings, transliterate};
+    let res = transliterate("ла БЛАну ко’а", &LerfuSettings::default());
+    assert_eq!(res.text, "la blanu ko'a");
+    assert_eq!(res.stress(), vec![3, 4, 5]);
+    assert!(res.changes.contains(&Change::Apostrophe(11, '’')));
+    assert_eq!(transliterate("ĭa LOJBAN", &LerfuSettings::default()).changes, vec![
+        Change::Glide(0, 'ĭ')
+    ]);
+    assert_eq!(normalize("МЛАТУ"), "mlatu");
+    // ASCII doesn't go through transliterate, but ends up the same
+    assert_eq!(normalize(".,.KO`A,Hu."), ".ko'a'u");
+    assert_eq!(normalize("ko’a.ou"), "ko'a.ou");
+    assert_eq!(syllabify("kamilas", &Settings::default()).unwrap().to_string(), "ka.MI.las");
+    assert_eq!(syllabify("KAmilas", &Settings::default()).unwrap().to_string(), "KA.mi.las");
+}
+
+#[test]
+fn t_zbalermorna() {
+    use crate::lerfu::to_zbalermorna;
+    assert_eq!(to_zbalermorna("blanu").unwrap(), "\u{ed90}\u{ed84}\u{eda1}\u{ed97}\u{eda5}");
+    assert_eq!(
+        to_zbalermorna("a'o tai").unwrap(),
+        "\u{ed89}\u{eda1}\u{ed8a}\u{eda4} \u{ed81}\u{eda8}"
+    );
+    assert_eq!(to_zbalermorna("iau").unwrap(), "\u{ed8b}\u{edab}");
+    assert_eq!(to_zbalermorna("klamyzda").unwrap().chars().nth(4), Some('\u{eda6}'));
+    assert!(to_zbalermorna("弱音").is_err());
+}
+
+#[test]
+fn t_simsa() {
+    use crate::simsa::{Conflict, find_gismu_conflicts, find_gismu_conflicts_in_list};
+    let settings = Settings::default();
+    let conflicts = find_gismu_conflicts("glama", &settings).unwrap();
+    assert!(conflicts.contains(&("klama".to_string(), Conflict::SimilarConsonant(0))));
+    assert_eq!(
+        find_gismu_conflicts_in_list("blana", &["blanu", "blabi", "plana"], &settings).unwrap(),
+        vec![
+            ("blanu".to_string(), Conflict::FinalVowel),
+            ("plana".to_string(), Conflict::SimilarConsonant(0))
+        ]
+    );
+    assert!(find_gismu_conflicts("latkerlo", &settings).is_err());
+}
+
+#[test]
+fn t_cmavo_compound() {
+    use crate::tarmi::split_cmavo_compound;
+    let split = |s| {
+        split_cmavo_compound(s)
+            .unwrap()
+            .into_iter()
+            .map(|(c, [i, j])| format!("{c}{i}-{j}"))
+            .join(" ")
+    };
+    assert_eq!(split("loinu"), "loi0-3 nu3-5");
+    assert_eq!(split("ko'a'epe'i"), "ko'a'e0-6 pe'i6-10");
+    assert_eq!(split("i.ebysy'u'u"), "i0-1 e2-3 by3-5 sy'u'u5-11");
+    assert!(split_cmavo_compound("tosmabru").is_err());
+}
+
+#[test]
+fn t_report() {
+    use crate::report::{Action, describe, describe_tanru, describe_word};
+    let report = describe("latkerlo", &Settings::default());
+    assert_eq!(report.action, Action::Katna);
+    assert_eq!(report.selrafsi, vec!["mlatu", "kerlo"]);
+    assert_eq!(report.best.unwrap().lujvo, "latkerlo");
+    let report = describe("  mlatu   kerlo ", &Settings::default());
+    assert_eq!((report.action, report.input.as_str()), (Action::Zba, "mlatu kerlo"));
+    assert!(describe("toiysmu", &Settings::default()).is_err());
+    let report = describe_tanru("latkerlo", &Settings::default());
+    assert_eq!(report.action, Action::Zba);
+    assert!(describe_word("mlatu kerlo", &Settings::default()).is_err());
+}
+
+#[test]
+fn t_apply_option() {
+    let mut settings = "c".parse::<Settings>().unwrap();
+    for (name, value) in
+        [("y-hyphens", Some("force-y")), ("consonants", Some("1")), ("glides", None)]
+    {
+        settings.apply_option(name, value).unwrap();
+    }
+    settings.apply_option("cmevla", Some("false")).unwrap();
+    assert_eq!(settings.to_string(), "F1g");
+    assert!(settings.apply_option("y-hyphens", None).is_none());
+    assert!(settings.apply_option("consonants", Some("3")).is_none());
+    assert!(settings.apply_option("glides", Some("yes")).is_none());
+    assert!(settings.apply_option("cmavo", None).is_none());
+}
+
+#[test]
+fn t_trace() {
+    use crate::trace::{is_tracing, with_trace};
+    let (res, trace) = with_trace(|| analyze_brivla("toiysmu", &Settings::default()));
+    assert!(res.is_err());
+    assert_eq!(trace.0[0].message, "analyzing {toiysmu}");
+    assert!(trace.0.iter().any(|s| s.depth > 0 && s.message == "part 1: {toi}"));
+    assert_eq!(trace.0.last().unwrap().message, "=> {toia} is just a cmavo compound");
+    // nested calls are indented
+    let (_, trace) = with_trace(|| analyze_brivla("tosmabru", &Settings::default()));
+    assert!(trace.to_string().contains("\n  analyzing {smabru}\n"));
+    assert!(!is_tracing());
+    let (_, trace) = with_trace(is_tracing);
+    assert!(trace.0.is_empty());
+    // a panic inside an inner trace leaves the outer one as it was
+    let (_, trace) = with_trace(|| {
+        let _ = analyze_brivla("bloti", &Settings::default());
+        let inner = std::panic::catch_unwind(|| with_trace(|| panic!("oops")));
+        assert!(inner.is_err() && is_tracing());
+    });
+    assert_eq!(trace.0[0].message, "analyzing {bloti}");
+    assert!(!is_tracing());
+}
+
+#[test]
+fn t_settings_matrix() {
+    use crate::matrix::{settings_matrix, summarize_settings};
+    let classes = settings_matrix("latkerlo");
+    assert_eq!(classes.iter().map(|c| c.summary()).collect_vec(), ["!c", "c"]);
+    assert_eq!(classes[1].outcome.best.as_deref(), Some("latker"));
+    let classes = settings_matrix("ia'yia");
+    assert_eq!(classes[0].summary(), "not (A|F r)");
+    assert_eq!(classes.iter().map(|c| c.settings.len()).sum::<usize>(), 144);
+    assert!(classes.iter().any(|c| c.outcome.error.is_none()));
+    assert_eq!(settings_matrix("toiysmu").len(), 1);
+    assert_eq!(summarize_settings(&SETTINGS_ITERATOR), "any");
+    assert_eq!(summarize_settings(&[]), "none");
+    let odd = ["A", "F2"].map(|s| s.parse().unwrap());
+    assert_eq!(summarize_settings(&odd), "-A, -F2");
+}
+
+#[test]
+fn t_minimal_settings() {
+    use crate::matrix::{Flag, changed_flags, minimal_settings};
+    assert!(minimal_settings("latkerlo").unwrap()[0].flags.is_empty());
+    assert_eq!(minimal_settings("latkerlo").unwrap()[0].deciding_flag, None);
+    // -A1rg, -F2rg, and -F1rg work too, but they change more
+    let accepted = minimal_settings("ia'yia").unwrap();
+    assert_eq!(accepted.len(), 1);
+    assert_eq!(accepted[0].settings.to_string(), "A2rg");
+    assert_eq!(accepted[0].deciding_flag, Some(Flag::ExpRafsi));
+    assert!(Flag::OneConsonant.permissiveness() > Flag::TwoConsonants.permissiveness());
+    assert!(minimal_settings("toiysmu").is_err());
+    assert!(minimal_settings("mlatu").is_err());
+    let flags = changed_flags(&"cF1z".parse().unwrap());
+    assert_eq!(flags, [Flag::GenerateCmevla, Flag::ForceY, Flag::OneConsonant, Flag::AllowMz]);
+    assert_eq!(flags.iter().join(" "), "generate_cmevla force_y one_consonant allow_mz");
+}
+
+#[test]
+fn t_render() {
+    use crate::{
+        render::{Format, Tag, diff_pieces, render_report, strip_ansi},
+        report::describe,
+    };
+    let report = describe("bangygirzu", &Settings::default());
+    let plain = render_report(&report, Format::Plain.renderer().as_ref());
+    assert_eq!(plain, "lujvo\nbang y girzu\n10067\nbangu girzu\nbest: baugri (5846)");
+    let ansi = render_report(&report, Format::Ansi.renderer().as_ref());
+    assert_eq!(strip_ansi(&ansi), plain);
+    let md = render_report(&report, Format::Markdown.renderer().as_ref());
+    assert!(md.ends_with("  \nbest: **baugri** (5846)"));
+    let html =
+        render_report(&describe("ga'enai", &Settings::default()), Format::Html.renderer().as_ref());
+    assert!(html.contains(r#"<span class="error red">{ga&#39;enai} is just"#));
+    let pieces = |s: &str| s.split(' ').map(ToString::to_string).collect_vec();
+    let diff = diff_pieces(&pieces("loj ban girzu"), &pieces("loj bau gri"));
+    assert_eq!(diff.iter().map(|(t, _)| *t).collect_vec(), [
+        Tag::Result,
+        Tag::Changed,
+        Tag::Changed
+    ]);
+    assert_eq!("md".parse::<Format>().unwrap(), Format::Markdown);
+    assert!("json".parse::<Format>().is_err());
+}
+
+#[test]
+#[cfg(feature = "server")]
+fn t_server() {
+    use crate::server::{handle, handle_with};
+    let (status, body) = handle("/katna?word=lat%27kerlo&y-hyphens=allow-y");
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""y_hyphens":"allow_y""#) && body.contains(r#""kind":"#));
+    assert_eq!(handle("/score?lujvo=baugri").1, r#"{"lujvo":"baugri","score":5846,"error":null}"#);
+    assert_eq!(handle("/rafsi?word=bau").1, r#"{"selrafsi":"bangu","rafsi":[]}"#);
+    // long options win over flags, like in the CLI
+    let (_, body) = handle("/settings?glides=false&flags=-gA");
+    assert_eq!(body.split(',').next().unwrap(), r#"{"flags":"A""#);
+    assert!(handle("/settings").1.matches("flags").count() == 144);
+    let (_, body) = handle_with("/zba?tanru=ia+sutra", &"F".parse().unwrap());
+    assert!(body.contains(r#""y_hyphens":"force_y""#));
+    assert_eq!(handle("/zba").0, 400);
+    assert_eq!(handle("/zba?tanru=a&flags=q").0, 400);
+    assert_eq!(handle("/zba?tanru=%zz").0, 400);
+    assert_eq!(handle("/jvozba").0, 404);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn t_rpc() {
+    use crate::rpc::{Session, run};
+    let mut session = Session::new("c".parse().unwrap());
+    let call = |s: &mut Session, id: u8, method: &str, params: &str| {
+        let line =
+            format!(r#"{{"jsonrpc":"2.0","id":{id},"method":"{method}","params":{params}}}"#);
+        s.handle(&line).unwrap()
+    };
+    assert!(
+        call(&mut session, 1, "zba", r#"{"tanru":"mlatu kerlo"}"#).contains(r#""lujvo":"latker""#)
+    );
+    let res =
+        call(&mut session, 2, "set_settings", r#"{"flags":"A","consonants":2,"glides":true}"#);
+    assert!(res.contains(r#""result":{"flags":"A2g""#));
+    assert_eq!(session.settings.to_string(), "A2g");
+    // invalid settings change nothing
+    let res = call(&mut session, 3, "set_settings", r#"{"flags":"","y-hyphens":"maybe"}"#);
+    assert!(res.contains(r#""error":{"code":-32602"#));
+    assert_eq!(session.settings.to_string(), "A2g");
+    assert_eq!(
+        call(&mut session, 4, "score", r#"{"lujvo":"baugri"}"#),
+        r#"{"jsonrpc":"2.0","id":4,"result":{"lujvo":"baugri","score":5846,"error":null}}"#
+    );
+    // the session remembers what it analyzed
+    let katna = call(&mut session, 5, "katna", r#"{"word":"baugri"}"#);
+    assert!(!session.cache.is_empty());
+    let hits = session.cache.hits();
+    assert_eq!(call(&mut session, 5, "katna", r#"{"word":"baugri"}"#), katna);
+    assert!(session.cache.hits() > hits);
+    assert!(call(&mut session, 5, "jvozba", "{}").contains("-32601"));
+    assert!(session.handle(r#"{"jsonrpc":"2.0","method":"settings"}"#).is_none());
+    assert!(session.handle("{").unwrap().contains(r#""id":null,"error":{"code":-32700"#));
+    let batch = r#"[{"jsonrpc":"2.0","id":"a","method":"rafsi","params":{"word":"bau"}},1]"#;
+    let res = session.handle(batch).unwrap();
+    assert!(res.starts_with(r#"[{"jsonrpc":"2.0","id":"a","result":{"selrafsi":"bangu""#));
+    assert!(res.ends_with(r#""error":{"code":-32600,"message":"not a request"}}]"#));
+    let mut out = vec![];
+    let input = "\n{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"settings\"}\n";
+    run(input.as_bytes(), &mut out, &Settings::default()).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().lines().count(), 1);
+}
+
+#[test]
+fn t_lint() {
+    use crate::lint::{LintKind, hover, lint, tokenize};
+    let text = "coi .djan. mi nelci lo ,bangygirzu, .e lo toiysmu\n弱音ハク lo tcanyrgu'e";
+    let words = tokenize(text).into_iter().map(|t| &text[t.start..t.end]).collect_vec();
+    assert_eq!(words[..3], ["coi", "djan", "mi"]);
+    assert!(words.contains(&"bangygirzu") && words.contains(&"弱音ハク"));
+    let lints = lint(text, &Settings::default());
+    let kinds = lints.iter().map(|l| (l.kind, l.token.text.as_str())).collect_vec();
+    assert_eq!(kinds, [
+        (LintKind::SuboptimalLujvo, "bangygirzu"),
+        (LintKind::MalformedLujvo, "toiysmu"),
+        (LintKind::InvalidBrivla, "tcanyrgu'e")
+    ]);
+    assert_eq!(lints[1].replacement.as_deref(), Some("toismu"));
+    assert!(lints[2].replacement.is_none());
+    // gismu, cmavo, and good lujvo are fine
+    assert!(lint("mi dunda lo latkerlo", &Settings::default()).is_empty());
+    assert_eq!(
+        hover("latkerlo", &Settings::default()).unwrap(),
+        "lujvo  \nlat kerlo  \n7937  \nmlatu kerlo"
+    );
+    assert_eq!(hover("coi", &Settings::default()).unwrap(), "*{coi} is a rafsi of {condi}*");
+    assert!(hover("djan", &Settings::default()).is_none());
+}
+
+#[test]
+fn t_unicode() {
+    use crate::{
+        Jvonunfli::{NonLojbanCharacterError, NotBrivlaError},
+        katna::jvokaha,
+        tarmi::{is_valid_rafsi, is_zihevla_middle_cluster},
+    };
+    // each of these used to panic somewhere, as did *joлklo*
+    let words = ["jéü.Yzl", "loi'y 🙂a", "Uĭ'y'aei", "bloti\u{301}lonyrczA", "弱音ハク"];
+    for (word, settings) in words.iter().cartesian_product(SETTINGS_ITERATOR.iter()) {
+        for res in [
+            analyze_brivla(word, settings).map(|_| ()),
+            get_veljvo(word, settings).map(|_| ()),
+            score_lujvo(word, settings).map(|_| ()),
+            get_lujvo(word, settings).map(|_| ()),
+        ] {
+            assert!(res.is_err(), "{word} -{settings}");
+        }
+    }
+    let settings = Settings::default();
+    assert_eq!(
+        analyze_brivla("弱音ハク", &settings),
+        Err(NonLojbanCharacterError(
+            "{弱音ハク} contains unexpected character {弱} (u+5f31)".to_string()
+        ))
+    );
+    assert!(matches!(get_veljvo("jéü.yzl", &settings), Err(NonLojbanCharacterError(_))));
+    // Cyrillic is transliterated, so this is *jolklo*
+    assert_eq!(score_lujvo("joлklo", &settings), score_lujvo("jolklo", &settings));
+    // the tiebreak looks at the word as given, even where it isn't ASCII
+    assert_eq!(score_lujvo("BAUGRI", &settings), score_lujvo("baugri", &settings).map(|s| s + 1));
+    assert_eq!(score_lujvo("baуgri", &settings), score_lujvo("baugri", &settings).map(|s| s + 1));
+    assert!(matches!(analyze_brivla("uiy'aei", &settings), Err(NotBrivlaError(_))));
+    assert!(jvokaha("", &settings).is_err());
+    assert!(!is_valid_rafsi("lўi", &settings) && !is_zihevla_middle_cluster("ucўmr"));
+}
+
+#[test]
+fn t_cache() {
+    use crate::{
+        cache::{Cache, with_cache},
+        katna::jvokaha,
+        tarmi::BrivlaType,
+        trace::with_trace,
+    };
+    let words = ["blazda", "blazda", "toiysmu", "zor,iiirauaueRARauKre", "pasmabru", "latkerlo"];
+    let mut cache = Cache::new(50);
+    for (word, settings) in words.iter().cartesian_product(SETTINGS_ITERATOR.iter()) {
+        let cached =
+            with_cache(&mut cache, || (analyze_brivla(word, settings), jvokaha(word, settings)));
+        assert_eq!(
+            cached,
+            (analyze_brivla(word, settings), jvokaha(word, settings)),
+            "{word} -{settings}"
+        );
+        assert!(cache.len() <= 50);
+    }
+    assert!(cache.hits() > 0);
+    // traces are never cut short by a cached result
+    let settings = Settings::default();
+    with_cache(&mut cache, || analyze_brivla("toiysmu", &settings)).unwrap_err();
+    let (_, trace) = with_cache(&mut cache, || with_trace(|| analyze_brivla("toiysmu", &settings)));
+    assert_eq!(trace, with_trace(|| analyze_brivla("toiysmu", &settings)).1);
+    cache.clear();
+    assert!(cache.is_empty() && cache.hits() == 0 && cache.capacity() == 50);
+    let mut none = Cache::new(0);
+    with_cache(&mut none, || get_veljvo("blazda", &settings)).unwrap();
+    assert!(none.is_empty());
+    // the cache can be passed in directly
+    assert_eq!(cache.get_veljvo("blazda", &settings), get_veljvo("blazda", &settings));
+    assert_eq!(cache.analyze_brivla("blazda", &settings), analyze_brivla("blazda", &settings));
+    assert_eq!(cache.jvokaha("blazda", &settings), jvokaha("blazda", &settings));
+    assert!(cache.hits() > 0);
+    // and is given back even if something panics while it's in use
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        with_cache(&mut cache, || panic!("oops"));
+    }));
+    assert!(res.is_err() && cache.hits() > 0 && cache.capacity() == 50);
+    assert_eq!(
+        with_cache(&mut none, || cache.analyze_brivla("bloti", &settings)).unwrap().0,
+        BrivlaType::Gismu
+    );
+    assert!(none.is_empty() && cache.len() > 1);
+}
+
+// ported from test_analyse_brivla_dictionary in py/tests/test_other.py
+#[test]
+#[cfg(feature = "serde")]
+fn t_dictionary() {
+    use std::thread;
+
+    use crate::{
+        bulk::{analyze_many, get_lujvo_many, get_veljvo_many},
+        cache::Cache,
+        tarmi::BrivlaType::{Cmevla, ExtendedLujvo, Gismu, Lujvo, Zihevla},
+    };
+    let all_words: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string("../tests/jvs_words.json").unwrap()).unwrap();
+    let settings = Settings::default();
+    for (b_type, words) in &all_words {
+        let words = words
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|w| w.as_str())
+            .filter(|w| !["posytmo", "gudjrati"].contains(w))
+            .collect_vec();
+        for (word, res) in words.iter().zip(analyze_many(&words, &settings, None)) {
+            match (b_type.as_str(), res) {
+                ("GISMU", Ok((Gismu, _)))
+                | ("LUJVO", Ok((Lujvo | ExtendedLujvo, _)))
+                | ("ZIhEVLA", Ok((Zihevla, _)))
+                | ("OTHER", Err(_) | Ok((Cmevla, _))) => (),
+                (_, res) => panic!("expected {b_type}, got {res:?} (input: {word})"),
+            }
+        }
+    }
+    // the same answers come back in the same order from several threads at once
+    let lujvo =
+        all_words["LUJVO"].as_array().unwrap().iter().filter_map(|w| w.as_str()).collect_vec();
+    let veljvo = get_veljvo_many(&lujvo, &settings, None);
+    let tanru = veljvo.iter().filter_map(|v| v.as_ref().ok().map(|v| v.join(" "))).collect_vec();
+    let zbasu = get_lujvo_many(&tanru, &settings, None);
+    thread::scope(|s| {
+        for chunk in [0, 1, 2, 3].map(|i| i * lujvo.len() / 4..(i + 1) * lujvo.len() / 4) {
+            let (lujvo, veljvo) = (&lujvo, &veljvo);
+            s.spawn(move || {
+                assert_eq!(get_veljvo_many(&lujvo[chunk.clone()], &settings, None), veljvo[chunk])
+            });
+        }
+        s.spawn(|| assert_eq!(get_lujvo_many(&tanru, &settings, None), zbasu));
+    });
+    assert_eq!(zbasu, tanru.iter().map(|t| get_lujvo(t, &settings)).collect_vec());
+    // a cache gives the same answers, and learns from every thread
+    let mut cache = Cache::new(100_000);
+    assert_eq!(get_veljvo_many(&lujvo, &settings, Some(&mut cache)), veljvo);
+    let misses = cache.misses();
+    assert!(misses >= lujvo.len() && cache.len() <= misses);
+    assert_eq!(get_veljvo_many(&lujvo, &settings, Some(&mut cache)), veljvo);
+    assert_eq!(cache.misses(), misses);
+}
+
+#[test]
+#[allow(deprecated)]
+fn t_combine() {
+    use crate::jvozba::{Tosytype::Tosynone, combine, score, update_current_best};
+    let settings = Settings::default();
+    let res = combine("bau", "gri", 0, 2, score("bau"), &[[0, 3]], Tosynone, 2, &settings);
+    let (lujvo, score, indices) = get_lujvo_with_analytics("bangu girzu", &settings).unwrap();
+    assert_eq!(res, Some((Tosynone, 2, score, lujvo, indices)));
+    let best = update_current_best(res, Default::default());
+    assert_eq!(best[0][2][&'i'].0, "baugri");
+    assert!(combine("bau", "gri", 0, 2, 0, &[], Tosynone, 2, &settings).is_none());
+}
+
+#[test]
+fn t_long_tanru() {
+    let settings = Settings::default();
+    // the letters are only put together at the end, so the indices have to
+    // line up with the hyphens
+    assert_eq!(
+        get_lujvo_with_analytics("mlatu -kerl- spageti bloti", &settings).unwrap(),
+        ("latkerlyspageti'yblo".to_string(), 20054, vec![[0, 3], [3, 7], [8, 15], [17, 20]])
+    );
+    let (lujvo, score, indices) =
+        get_lujvo_with_analytics(&"bloti ".repeat(60), &settings).unwrap();
+    assert_eq!(lujvo, "blo".repeat(60));
+    assert_eq!(score, score_lujvo(&lujvo, &settings).unwrap());
+    assert_eq!(indices, (0..60).map(|i| [3 * i, 3 * i + 3]).collect_vec());
+}
+
+#[test]
+fn t_const_tables() {
+    use crate::{
+        data::{
+            BANNED_TRIPLES, BANNED_TRIPLES_TABLE, CONSONANTS, INITIAL, INITIAL_TABLE, MZ_VALID,
+            VALID, VALID_LIST, VALID_TABLE, ZIHEVLA_INITIAL, ZIHEVLA_INITIAL_TABLE,
+            is_valid_cluster, is_valid_pair,
+        },
+        jvozba::{HYPHEN_CHOICES, HYPHEN_SCORES, score},
+        katna::search_selrafsi_from_rafsi,
+        rafsi::{RAFSI_LIST, rafsi_of},
+        tarmi::{is_zihevla_middle_cluster, strip_hyphens},
+    };
+    // the order the settings were listed in before they were built at compile
+    // time
+    let flags = ["", "c"]
+        .into_iter()
+        .cartesian_product(["", "A", "F"])
+        .cartesian_product(["", "2", "1"])
+        .cartesian_product(["", "r"])
+        .cartesian_product(["", "g"])
+        .cartesian_product(["", "z"])
+        .map(|(((((c, y), n), r), g), z)| format!("{c}{y}{n}{r}{g}{z}"))
+        .collect_vec();
+    assert_eq!(SETTINGS_ITERATOR.map(|s| s.to_string()).to_vec(), flags);
+    assert_eq!(HYPHEN_CHOICES.map(score), HYPHEN_SCORES);
+    // the sets are views of the lists
+    assert_eq!(VALID.len(), VALID_LIST.len());
+    assert!(VALID_LIST.iter().all(|c| VALID.contains(c)));
+    assert!(MZ_VALID.iter().all(|c| is_valid_cluster(c, true)));
+    assert!(!is_valid_cluster("mz", false) && MZ_VALID.len() == VALID.len() + 1);
+    // the tables agree with the sets on every pair and triple
+    for (a, b) in CONSONANTS.chars().cartesian_product(CONSONANTS.chars()) {
+        let pair = format!("{a}{b}");
+        for (table, set) in [
+            (VALID_TABLE, &VALID),
+            (INITIAL_TABLE, &INITIAL),
+            (ZIHEVLA_INITIAL_TABLE, &ZIHEVLA_INITIAL),
+        ] {
+            assert_eq!(table.contains_pair(a, b), set.contains(pair.as_str()), "{pair}");
+            assert_eq!(table.contains(&pair), set.contains(pair.as_str()), "{pair}");
+        }
+        assert_eq!(is_valid_pair(a, b, true), MZ_VALID.contains(pair.as_str()), "{pair}");
+        for c in CONSONANTS.chars() {
+            let triple = format!("{pair}{c}");
+            let banned = BANNED_TRIPLES.contains(triple.as_str());
+            assert_eq!(BANNED_TRIPLES_TABLE.contains_triple(a, b, c), banned, "{triple}");
+            assert_eq!(BANNED_TRIPLES_TABLE.contains(&triple), banned, "{triple}");
+        }
+    }
+    const { assert!(is_valid_pair('m', 'z', true) && !VALID_TABLE.contains_pair('m', 'z')) };
+    assert!(
+        !VALID_TABLE.contains("bd'") && !INITIAL_TABLE.contains("b") && !VALID_TABLE.contains("ŭb")
+    );
+    assert_eq!(RAFSI.len(), RAFSI_LIST.len());
+    assert_eq!(rafsi_of("zbasu"), Some(&["zba"][..]));
+    assert_eq!(rafsi_of("zbasu"), RAFSI.get("zbasu").map(Vec::as_slice));
+    assert_eq!(rafsi_of("zbas"), None);
+    assert_eq!(search_selrafsi_from_rafsi("zba").as_deref(), Some("zbasu"));
+    // these replaced regexes
+    assert!(is_zihevla_middle_cluster("ndr") && is_zihevla_middle_cluster("mbrl"));
+    assert!(!is_zihevla_middle_cluster("rkst") && !is_zihevla_middle_cluster("ktkt"));
+    assert_eq!(strip_hyphens("'y'ba'y"), "ba");
+    assert_eq!(strip_hyphens("y'y"), "");
+}
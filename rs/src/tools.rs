@@ -1,34 +1,45 @@
 //! Functions for analyzing brivla in general (such as `analyze_brivla`!)
 
-use std::ops::{Bound, RangeBounds};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::ops::{Add, Bound, Range, RangeBounds};
 
 use itertools::Itertools as _;
+#[cfg(feature = "std")]
 use regex::Regex;
 
+#[cfg(feature = "std")]
+use crate::{cache, trace::scope};
 #[allow(unused_imports)] // YHyphenSetting::self, needed for docs
 use crate::{
     data::{
-        BANNED_TRIPLES, FOLLOW_VOWEL_CLUSTERS, HYPHENS, INITIAL, MZ_VALID, START_VOWEL_CLUSTERS,
-        VALID,
+        BANNED_TRIPLES_TABLE, FOLLOW_VOWEL_CLUSTERS_LIST, HYPHENS_LIST, INITIAL_TABLE,
+        START_VOWEL_CLUSTERS_LIST, is_valid_cluster,
     },
     exceptions::Jvonunfli::{
-        self, DecompositionError, FakeTypeError, InvalidClusterError, NotBrivlaError,
-        NotZihevlaError,
+        self, DecompositionError, FakeTypeError, InvalidClusterError, NonLojbanCharacterError,
+        NotBrivlaError, NotZihevlaError,
     },
     extract,
     katna::{jvokaha, jvokaha2},
+    lerfu::{LerfuSettings, transliterate},
     tarmi::{
         BrivlaType::{self, Cmevla, ExtendedLujvo, Gismu, Lujvo, Rafsi, Zihevla},
         ConsonantSetting::{Cluster, OneConsonant, TwoConsonants},
         Settings,
         Tarmi::{self, Ccv, Cvc, Cvhv, Cvv, OtherRafsi},
         YHyphenSetting::{self, Standard},
-        is_cmavo_compound, is_consonant, is_gismu, is_glide, is_valid_rafsi, is_vowel,
-        is_zihevla_initial_cluster, is_zihevla_middle_cluster, rafsi_tarmi, split_vowel_cluster,
-        strip_hyphens,
+        check_lojban_characters, is_cmavo_compound, is_consonant, is_gismu, is_glide,
+        is_valid_rafsi, is_vowel, is_zihevla_initial_cluster, is_zihevla_middle_cluster, rafsi_tarmi,
+        split_vowel_cluster, strip_hyphens,
     },
 };
 
+#[cfg(feature = "std")]
 #[inline]
 #[must_use = "does not mutate the string"]
 /// Replaces all matches of a regex in a string with another string.
@@ -37,23 +48,23 @@ pub fn regex_replace_all(regex: &Regex, from: &str, with: &str) -> String {
 }
 
 #[macro_export]
-/// Pythonic `str`ing `in`dexing.
+#[deprecated(note = "walks the string by character; use `str::as_bytes` or `chars` instead")]
+/// Pythonic `str`ing `in`dexing. Only walks as far as the index, so the first
+/// and last letters are cheap.
 macro_rules! strin {
     ($s:expr, $i:expr) => {{
-        let mut chars = ($s).chars();
-        let len = chars.clone().count();
-        let positive = |i| -> Option<usize> {
-            let j = if i < 0 { len as isize + i } else { i } as usize;
-            (j < len).then_some(j)
-        };
-        positive($i).map(|i| (&mut chars).nth(i).unwrap()).unwrap_or_default()
+        let s: &str = &($s);
+        let i: isize = $i;
+        if i < 0 { s.chars().rev().nth((-i - 1) as usize) } else { s.chars().nth(i as usize) }
+            .unwrap_or_default()
     }};
 }
+#[deprecated(note = "only used by `strsl!`")]
 /// Turns a range into a tuple with its endpoints.
 pub fn bounds<S, T, R>(str: S, range: R) -> (isize, isize)
 where
     S: AsRef<str>,
-    T: Clone + From<isize> + Into<isize> + std::ops::Add<T, Output = T>,
+    T: Clone + From<isize> + Into<isize> + Add<T, Output = T>,
     R: RangeBounds<T>,
 {
     let (start, end) = (range.start_bound(), range.end_bound());
@@ -70,10 +81,12 @@ where
     (start.into(), end.into())
 }
 #[macro_export]
+#[deprecated(note = "panics on bad ranges; use `str::get` instead")]
 /// Pythonic `str`ing `sl`icing.
 macro_rules! strsl {
     ($s:expr, $r:expr) => {{
         let len = ($s).len();
+        #[allow(deprecated)]
         let (start, end) = $crate::tools::bounds($s, $r);
         let positive = |i: isize| -> usize {
             if i < 0 { len.saturating_sub((-i) as usize) } else { i as usize }.min(len)
@@ -84,10 +97,112 @@ macro_rules! strsl {
     }};
 }
 
+/// A position in an ASCII word, for going through it letter by letter.
+/// Looking at or slicing the word doesn't count the characters before the
+/// position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cursor<'a> {
+    word: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Starts at the beginning of the ASCII part of `word` that comes before
+    /// any other characters.
+    pub(crate) fn prefix(word: &'a str) -> Self {
+        let end = word.find(|c: char| !c.is_ascii()).unwrap_or(word.len());
+        Cursor { word: &word[..end], pos: 0 }
+    }
+
+    /// How far into the word the cursor is, in bytes (or letters).
+    pub(crate) fn pos(&self) -> usize { self.pos }
+
+    /// The part of the word from the cursor on.
+    pub(crate) fn rest(&self) -> &'a str { &self.word[self.pos..] }
+
+    pub(crate) fn is_empty(&self) -> bool { self.pos == self.word.len() }
+
+    /// The letter `i` places after the cursor, or `'\0'` past the end, like
+    /// [`letter`].
+    pub(crate) fn at(&self, i: usize) -> char {
+        self.word.as_bytes().get(self.pos + i).map_or('\0', |&b| b as char)
+    }
+
+    /// Part of the rest of the word, cut short at the end like [`cut`].
+    pub(crate) fn get(&self, range: Range<usize>) -> &'a str {
+        let rest = self.rest();
+        &rest[range.start.min(rest.len())..range.end.min(rest.len())]
+    }
+
+    /// Moves past the next `n` letters (or to the end), returning them.
+    pub(crate) fn take(&mut self, n: usize) -> &'a str {
+        let taken = self.get(0..n);
+        self.pos += taken.len();
+        taken
+    }
+}
+
+/// The letter at byte `i` of `word`, or `'\0'` past the end. Lojban words are
+/// ASCII, so this is the `i`th letter; any other byte gives `'\u{fffd}'`,
+/// which isn't a letter of anything.
+pub(crate) fn letter(word: &str, i: usize) -> char {
+    word.as_bytes().get(i).map_or('\0', |&b| if b.is_ascii() { b as char } else { '\u{fffd}' })
+}
+
+/// Like [`letter`], counting from the end: `letter_back(word, 0)` is the last
+/// letter.
+pub(crate) fn letter_back(word: &str, i: usize) -> char {
+    word.len().checked_sub(i + 1).map_or('\0', |i| letter(word, i))
+}
+
+/// Bytes `range` of `word`, cut short at its end. Empty if the range is
+/// backwards or would split a character.
+pub(crate) fn cut(word: &str, range: impl RangeBounds<usize>) -> &str {
+    let len = word.len();
+    let start = match range.start_bound() {
+        Bound::Included(&b) => b,
+        Bound::Excluded(&b) => b + 1,
+        Bound::Unbounded => 0,
+    }
+    .min(len);
+    let end = match range.end_bound() {
+        Bound::Included(&b) => b + 1,
+        Bound::Excluded(&b) => b,
+        Bound::Unbounded => len,
+    }
+    .min(len);
+    word.get(start..end).unwrap_or_default()
+}
+
+/// The last `n` bytes of `word`, or all of it if it's shorter.
+pub(crate) fn cut_back(word: &str, n: usize) -> &str { cut(word, word.len().saturating_sub(n)..) }
+
+/// `word` without its last `n` bytes.
+pub(crate) fn drop_back(word: &str, n: usize) -> &str { cut(word, ..word.len().saturating_sub(n)) }
+
 /// Converts a word to standard form (*h* → *'*, no periods/commas, lowercase).
+/// Cyrillic letters, marked glides, and typographic apostrophes are converted
+/// too; use [`transliterate`] directly to see what was changed.
 #[must_use = "does not mutate the string"]
 pub fn normalize(word: &str) -> String {
-    word.to_lowercase().trim_matches('.').replace('h', "'").replace(',', "")
+    if word.is_ascii() {
+        // only the case and backticks would be transliterated, so skip
+        // recording the changes
+        return word
+            .trim_matches('.')
+            .chars()
+            .filter_map(|c| match c.to_ascii_lowercase() {
+                ',' => None,
+                'h' | '`' => Some('\''),
+                c => Some(c),
+            })
+            .collect();
+    }
+    transliterate(word, &LerfuSettings::default())
+        .text
+        .trim_matches('.')
+        .replace('h', "'")
+        .replace(',', "")
 }
 
 /// Returns `true` if given a gismu or lujvo.
@@ -95,7 +210,7 @@ pub fn normalize(word: &str) -> String {
 /// Errors besides [`DecompositionError`] and [`InvalidClusterError`] are
 /// forwarded from [`jvokaha`].
 pub fn is_gismu_or_lujvo(s: &str, settings: &Settings) -> Result<bool, Jvonunfli> {
-    if s.len() < 5 || !is_vowel(strin!(s, -1)) {
+    if s.len() < 5 || !is_vowel(letter_back(s, 0)) {
         Ok(false)
     } else if is_gismu(s, &extract!(settings; allow_mz)) {
         Ok(true)
@@ -115,16 +230,19 @@ pub fn is_gismu_or_lujvo(s: &str, settings: &Settings) -> Result<bool, Jvonunfli
 /// Errors besides [`DecompositionError`] and [`InvalidClusterError`] are
 /// forwarded from [`jvokaha`].
 pub fn is_slinkuhi(s: &str, settings: &Settings) -> Result<bool, Jvonunfli> {
-    if is_vowel(strin!(s, 0)) {
+    if is_vowel(letter(s, 0)) {
         // words starting with vowels have an invisible . at the start
         Ok(false)
-    } else if let Err(e) = jvokaha(&format!("pa{s}"), &extract!(settings; y_hyphens, allow_mz)) {
-        match e {
-            DecompositionError(_) | InvalidClusterError(_) => Ok(false),
-            _ => Err(e),
-        }
     } else {
-        Ok(true)
+        trace!("checking whether {{pa{s}}} is a lujvo, making it a slinku'i");
+        if let Err(e) = jvokaha(&format!("pa{s}"), &extract!(settings; y_hyphens, allow_mz)) {
+            match e {
+                DecompositionError(_) | InvalidClusterError(_) => Ok(false),
+                _ => Err(e),
+            }
+        } else {
+            Ok(true)
+        }
     }
 }
 
@@ -139,7 +257,7 @@ pub fn is_slinkuhi(s: &str, settings: &Settings) -> Result<bool, Jvonunfli> {
 /// - contains apostrophes in illegal places
 #[allow(clippy::missing_panics_doc)] // .unwrap()
 pub fn check_zihevla_or_rafsi(
-    mut valsi: &str,
+    valsi: &str,
     settings: &Settings,
     require_zihevla: bool,
 ) -> Result<BrivlaType, Jvonunfli> {
@@ -147,6 +265,9 @@ pub fn check_zihevla_or_rafsi(
     if require_zihevla && valsi.len() < 4 {
         return Err(NotZihevlaError(format!("{{{valsi}}} is too short to be a zi'evla")));
     }
+    // anything after this is an unexpected character
+    let mut valsi = Cursor::prefix(valsi_);
+    let word = valsi;
     let (
         mut chunk,
         mut pos,
@@ -155,28 +276,23 @@ pub fn check_zihevla_or_rafsi(
         mut num_consonants,
         mut final_consonant_pos,
     ) = (String::new(), 0, 0, None, 0, 0);
-    while !valsi.is_empty() {
-        if is_consonant(strin!(valsi, 0)) {
-            while !valsi.is_empty() && is_consonant(strin!(valsi, 0)) {
-                chunk += strsl!(valsi, 0..1);
-                valsi = strsl!(valsi, 1..);
+    while pos < valsi_.len() {
+        if is_consonant(valsi.at(0)) {
+            while is_consonant(valsi.at(0)) {
+                chunk += valsi.take(1);
             }
             if chunk.len() >= 2 && cluster_pos.is_none() {
                 if num_consonants > 1 {
+                    trace!("checking whether a cmavo compound comes before a brivla (a tosmabru)");
                     // find where the lujvo really starts
                     let pos_ = (1..=pos).find(|p| {
-                        let to_part = strsl!(valsi_, ..*p);
-                        let smabru_part = strsl!(valsi_, *p..);
-                        (is_vowel(strin!(to_part, -1)) || strin!(to_part, -1) == 'y')
+                        let (to_part, smabru_part) = valsi_.split_at(*p);
+                        (is_vowel(word.at(p - 1)) || word.at(p - 1) == 'y')
                             && is_cmavo_compound(to_part)
                             && (is_glide(smabru_part)
-                                || !START_VOWEL_CLUSTERS.iter().any(|v| {
-                                    *v == format!(
-                                        "{}{}",
-                                        strin!(to_part, -1),
-                                        strin!(smabru_part, 0)
-                                    )
-                                }))
+                                || !START_VOWEL_CLUSTERS_LIST
+                                    .iter()
+                                    .any(|v| *v == format!("{}{}", word.at(p - 1), word.at(*p))))
                             && analyze_brivla(
                                 smabru_part,
                                 &extract!(
@@ -189,31 +305,30 @@ pub fn check_zihevla_or_rafsi(
                     if let Some(pos_) = pos_ {
                         return Err(NotZihevlaError(format!(
                             "{{{valsi_}}} is a tosmabru: {{{} {}}}",
-                            strsl!(valsi_, ..pos_),
-                            strsl!(valsi_, pos_..)
+                            &valsi_[..pos_],
+                            &valsi_[pos_..]
                         )));
                     }
                 }
                 cluster_pos = Some(pos);
             }
-            if num_syllables == 0 && chunk.len() >= 2 && !INITIAL.contains(&strsl!(&chunk, 0..2)) {
+            if num_syllables == 0 && chunk.len() >= 2 && !INITIAL_TABLE.contains(cut(&chunk, 0..2))
+            {
                 return Err(NotZihevlaError(format!(
                     "{{{valsi_}}} starts with an invalid cluster"
                 )));
             }
             for i in 0..chunk.len().saturating_sub(1) {
-                let i = i as isize;
-                let cluster = strsl!(&chunk, i..i + 2);
-                if !if settings.allow_mz { &MZ_VALID } else { &VALID }.contains(&cluster) {
+                let cluster = &chunk[i..i + 2];
+                if !is_valid_cluster(cluster, settings.allow_mz) {
                     return Err(NotZihevlaError(format!(
                         "{{{valsi_}}} contains an invalid cluster"
                     )));
                 }
             }
             for i in 0..chunk.len().saturating_sub(2) {
-                let i = i as isize;
-                let cluster = strsl!(&chunk, i..i + 3);
-                if BANNED_TRIPLES.contains(&cluster) {
+                let cluster = &chunk[i..i + 3];
+                if BANNED_TRIPLES_TABLE.contains(cluster) {
                     return Err(NotZihevlaError(format!(
                         "{{{valsi_}}} contains a banned triple (nts/ntc/ndz/ndj)"
                     )));
@@ -230,10 +345,9 @@ pub fn check_zihevla_or_rafsi(
             }
             final_consonant_pos = pos;
             num_consonants += chunk.len();
-        } else if is_vowel(strin!(valsi, 0)) {
-            while !valsi.is_empty() && is_vowel(strin!(valsi, 0)) {
-                chunk += strsl!(valsi, 0..1);
-                valsi = strsl!(valsi, 1..);
+        } else if is_vowel(valsi.at(0)) {
+            while is_vowel(valsi.at(0)) {
+                chunk += valsi.take(1);
             }
             let syllables = split_vowel_cluster(&chunk);
             if let Err(e) = syllables {
@@ -246,15 +360,15 @@ pub fn check_zihevla_or_rafsi(
                     _ => return Err(e),
                 }
             } else if cluster_pos.is_none()
-                && strin!(valsi_, pos - 1) != '\''
+                && (if pos == 0 { letter_back(valsi_, 0) } else { word.at(pos - 1) }) != '\''
                 && let Ok(v @ [_, _, ..]) = syllables.as_deref()
-                && pos as usize + v.concat().len() == valsi_.len()
+                && pos + v.concat().len() == valsi_.len()
             {
                 return Err(NotZihevlaError(format!("{{{valsi_}}} is just a cmavo compound")));
             }
             if pos != 0
                 && let Ok([first, ..]) = syllables.as_deref()
-                && FOLLOW_VOWEL_CLUSTERS.contains(&first.as_str())
+                && FOLLOW_VOWEL_CLUSTERS_LIST.contains(first)
             {
                 return Err(NotZihevlaError(format!(
                     "{{{valsi_}}} contains a glide after a non-vowel"
@@ -262,52 +376,41 @@ pub fn check_zihevla_or_rafsi(
             }
 
             num_syllables += syllables.unwrap().len();
-        } else if strin!(valsi, 0) == '\'' {
-            chunk = "'".to_string();
-            valsi = strsl!(valsi, 1..);
-            if pos < 1 || !is_vowel(strin!(valsi_, pos - 1)) {
+        } else if valsi.at(0) == '\'' {
+            chunk = valsi.take(1).to_string();
+            if pos < 1 || !is_vowel(word.at(pos - 1)) {
                 return Err(NotZihevlaError(format!(
                     "{{{valsi_}}} contains an apostrophe not preceded by a vowel"
                 )));
             }
-            if valsi.is_empty() || !is_vowel(strin!(valsi_, pos + 1)) {
+            if !is_vowel(valsi.at(0)) {
                 return Err(NotZihevlaError(format!(
                     "{{{valsi_}}} contains an apostrophe not followed by a vowel"
                 )));
             }
         } else {
+            let c = valsi_[pos..].chars().next().unwrap();
             return Err(NotZihevlaError(format!(
-                "{{{valsi_}}} contains unexpected character {{{}}} (u+{:04x})",
-                strin!(valsi, 0),
-                strin!(valsi, 0) as u32
+                "{{{valsi_}}} contains unexpected character {{{c}}} (u+{:04x})",
+                c as u32
             )));
         }
-        pos += chunk.len() as isize;
+        pos += chunk.len();
         chunk = String::new();
     }
     if num_syllables < 2 && (require_zihevla || !settings.exp_rafsi) {
         return Err(NotZihevlaError(format!("{{{valsi_}}} doesn't have enough syllables")));
     }
     if num_syllables > 2 && cluster_pos > Some(0) {
-        if is_brivla(strsl!(valsi_, cluster_pos.unwrap()..), &extract!(settings; y_hyphens)) {
-            return Err(NotZihevlaError(format!(
-                "{{{valsi_}}} is a tosmabru: {{{} {}}}",
-                strsl!(valsi_, 0..cluster_pos.unwrap()),
-                strsl!(valsi_, cluster_pos.unwrap()..)
-            )));
-        }
-        for i in 1..cluster_pos.unwrap() {
-            if (is_consonant(strin!(valsi_, cluster_pos.unwrap() - i))
-                || is_glide(strsl!(valsi_, cluster_pos.unwrap() - i..)))
-                && is_brivla(
-                    strsl!(valsi_, cluster_pos.unwrap() - i..),
-                    &extract!(settings; y_hyphens),
-                )
+        trace!("checking whether a brivla starts at or before the first cluster (a tosmabru)");
+        let cluster_pos = cluster_pos.unwrap();
+        for i in 0..cluster_pos {
+            let (to_part, smabru_part) = valsi_.split_at(cluster_pos - i);
+            if (i == 0 || is_consonant(word.at(cluster_pos - i)) || is_glide(smabru_part))
+                && is_brivla(smabru_part, &extract!(settings; y_hyphens))
             {
                 return Err(NotZihevlaError(format!(
-                    "{{{valsi_}}} is a tosmabru: {{{} {}}}",
-                    strsl!(valsi_, 0..cluster_pos.unwrap() - i),
-                    strsl!(valsi_, cluster_pos.unwrap() - i..)
+                    "{{{valsi_}}} is a tosmabru: {{{to_part} {smabru_part}}}"
                 )));
             }
         }
@@ -316,7 +419,7 @@ pub fn check_zihevla_or_rafsi(
         if require_zihevla {
             return Err(NotZihevlaError(format!("{{{valsi_}}} is just a cmavo or cmavo compound")));
         }
-        if !is_consonant(strin!(valsi_, 0)) && !settings.exp_rafsi {
+        if !is_consonant(word.at(0)) && !settings.exp_rafsi {
             return Err(NotZihevlaError(format!("{{{valsi_}}} is an invalid rafsi")));
         }
         if num_consonants > 1 {
@@ -325,7 +428,7 @@ pub fn check_zihevla_or_rafsi(
         if final_consonant_pos > 0 {
             return Err(NotZihevlaError(format!("{{{valsi_}}} lacks a consonant cluster")));
         }
-    } else if !(is_vowel(strin!(valsi_, 0)) && is_consonant(strin!(valsi_, 1)))
+    } else if !(is_vowel(word.at(0)) && is_consonant(word.at(1)))
         && is_slinkuhi(valsi_, &extract!(settings; y_hyphens, allow_mz))?
     {
         return Err(NotZihevlaError(format!("{{{valsi_}}} is a slinku'i")));
@@ -346,6 +449,9 @@ pub fn is_brivla(valsi: &str, settings: &Settings) -> bool {
 /// Returns the type and decomposition of any brivla or decomposable cmevla.
 /// Doesn't check the cmevla morphology rules.
 /// # Errors
+/// A [`NonLojbanCharacterError`] is returned if the input, once normalized,
+/// has anything besides Lojban letters, *y*, and apostrophes.
+///
 /// A [`NotBrivlaError`] is returned if the input:
 /// - is empty
 /// - starts or ends with *y* or apostrophe
@@ -369,30 +475,68 @@ pub fn is_brivla(valsi: &str, settings: &Settings) -> bool {
 ///
 /// Otherwise errors are forwarded from `check_zihevla_or_rafsi`, `jvokaha2`,
 /// etc.
-#[allow(clippy::missing_panics_doc)] // .unwrap()
+///
+/// The steps taken can be recorded with [`with_trace`], and results can be
+/// remembered between calls with [`with_cache`].
+///
+/// [`with_trace`]: crate::trace::with_trace
+/// [`with_cache`]: crate::cache::with_cache
 pub fn analyze_brivla(
     valsi: &str,
     settings: &Settings,
 ) -> Result<(BrivlaType, Vec<String>), Jvonunfli> {
+    trace!(
+        "analyzing {{{}}}{}",
+        normalize(valsi),
+        if *settings == Settings::default() { String::new() } else { format!(" with -{settings}") }
+    );
+    #[cfg(feature = "std")]
+    let res = {
+        let _scope = scope();
+        cache::analysis(valsi, settings, || analyze(valsi, settings))
+    };
+    #[cfg(not(feature = "std"))]
+    let res = analyze(valsi, settings);
+    match &res {
+        Ok((b_type, parts)) => {
+            trace!("=> {}: {{{}}}", b_type.to_string().to_lowercase(), parts.join(" "));
+        }
+        Err(e) => trace!("=> {e}"),
+    }
+    res
+}
+
+#[allow(clippy::missing_panics_doc)] // .unwrap()
+fn analyze(valsi: &str, settings: &Settings) -> Result<(BrivlaType, Vec<String>), Jvonunfli> {
     let valsi = normalize(valsi);
     let mut is_cmetai = false;
     if valsi.is_empty() {
         return Err(NotBrivlaError("empty string".to_string()));
     }
-    if is_consonant(strin!(&valsi, -1)) {
+    check_lojban_characters(&valsi)?;
+    if is_consonant(letter_back(&valsi, 0)) {
+        trace!("ends in a consonant, so it can only be a cmevla");
         is_cmetai = true;
-    } else if !is_vowel(strin!(&valsi, -1)) {
+    } else if !is_vowel(letter_back(&valsi, 0)) {
         return Err(NotBrivlaError(format!("{{{valsi}}} doesn't end in a consonant or vowel")));
     }
     if is_cmetai {
         if is_gismu(&format!("{valsi}a"), &extract!(settings; allow_mz)) {
+            trace!("{{{valsi}a}} would be a gismu");
             return Err(NotBrivlaError(format!("{{{valsi}}} is a non-decomposable cmevla")));
         }
     } else if is_gismu(&valsi, &extract!(settings; allow_mz)) {
+        trace!("it has the shape of a gismu");
         return Ok((Gismu, vec![valsi]));
     }
-    let res_parts = jvokaha(&valsi, &extract!(settings; y_hyphens, consonants, glides, allow_mz));
+    trace!("trying to split it into rafsi");
+    let res_parts = {
+        #[cfg(feature = "std")]
+        let _scope = scope();
+        jvokaha(&valsi, &extract!(settings; y_hyphens, consonants, glides, allow_mz))
+    };
     if let Err(e) = res_parts {
+        trace!("it isn't made of rafsi: {e}");
         match e {
             DecompositionError(_) | InvalidClusterError(_) | FakeTypeError(_) => (),
             _ => return Err(e), // NotBrivlaError for CCV'y
@@ -401,7 +545,7 @@ pub fn analyze_brivla(
         let res_parts = res_parts.unwrap();
         return Ok((if is_cmetai { Cmevla } else { Lujvo }, res_parts));
     }
-    if !is_vowel(strin!(&valsi, 0)) && !is_consonant(strin!(&valsi, 0)) {
+    if !is_vowel(letter(&valsi, 0)) && !is_consonant(letter(&valsi, 0)) {
         return Err(NotBrivlaError(format!("{{{valsi}}} doesn't start with a consonant or vowel")));
     }
     let y_parts = valsi.split('y').collect_vec();
@@ -409,6 +553,7 @@ pub fn analyze_brivla(
         if is_cmetai {
             return Err(NotBrivlaError(format!("{{{valsi}}} is a non-decomposable cmevla")));
         }
+        trace!("it has no {{y}}, so it must be a zi'evla");
         if let Err(e) = check_zihevla_or_rafsi(
             &valsi,
             &extract!(settings; y_hyphens, exp_rafsi, allow_mz),
@@ -429,17 +574,21 @@ pub fn analyze_brivla(
         mut consonant_before_break,
         mut num_consonants,
     ) = (vec![], String::new(), false, true, false, 0);
+    trace!("splitting it at each {{y}}: {{{}}}", y_parts.join(" "));
     for i in 0..y_parts.len() {
         if i != 0 {
             next_hyphen += "y";
         }
         let mut part = y_parts[i];
+        trace!("part {}: {{{part}}}", i + 1);
+        #[cfg(feature = "std")]
+        let _scope = scope();
         let mut part_ = part;
         if part.is_empty() {
             return Err(NotBrivlaError(format!("{{{valsi}}} contains two consecutive {{y}}s")));
         }
-        if strin!(part, 0) == '\'' {
-            part = strsl!(part, 1..);
+        if letter(part, 0) == '\'' {
+            part = cut(part, 1..);
             part_ = part;
             next_hyphen += "'";
             if part.is_empty() {
@@ -447,20 +596,19 @@ pub fn analyze_brivla(
                     "{{{valsi}}} has a part consisting of just an apostrophe"
                 )));
             }
-            if !is_vowel(strin!(part, 0))
-                || FOLLOW_VOWEL_CLUSTERS.contains(
-                    &split_vowel_cluster(
-                        &part.chars().take_while(|c| is_vowel(*c)).collect::<String>(),
-                    )
-                    .unwrap()[0]
-                        .as_str(),
+            let vowels = part.chars().take_while(|c| is_vowel(*c)).collect::<String>();
+            if vowels.is_empty()
+                || FOLLOW_VOWEL_CLUSTERS_LIST.contains(
+                    &split_vowel_cluster(&vowels).map_err(|_| {
+                        NotBrivlaError(format!("{{{valsi}}} contains a bad vowel sequence"))
+                    })?[0],
                 )
             {
                 return Err(NotBrivlaError(format!(
                     "{{{valsi}}} contains an apostrophe not followed by a non-glide vowel"
                 )));
             }
-        } else if i > 0 && is_vowel(strin!(part, 0)) && !is_glide(part) {
+        } else if i > 0 && is_vowel(letter(part, 0)) && !is_glide(part) {
             return Err(NotBrivlaError(format!(
                 "{{{valsi}}} contains a {{y}} not followed by an apostrophe or glide"
             )));
@@ -470,6 +618,7 @@ pub fn analyze_brivla(
             next_hyphen = String::new();
         }
         if rafsi_tarmi(part) == Cvc {
+            trace!("{{{part}}} is a CVC rafsi");
             res_parts.push(part.to_string());
             consonant_before_break = true;
             num_consonants += 2;
@@ -480,35 +629,35 @@ pub fn analyze_brivla(
                 "{{{valsi}}} contains a CCV rafsi without a vowel"
             )));
         }
-        if i > 0 && (is_consonant(strin!(part, 0)) || is_glide(part)) {
+        if i > 0 && (is_consonant(letter(part, 0)) || is_glide(part)) {
             is_mahortai = false;
         }
         if consonant_before_break
-            && (is_consonant(strin!(part, 0)) || settings.glides && is_glide(part))
+            && (is_consonant(letter(part, 0)) || settings.glides && is_glide(part))
         {
             has_cluster = true;
         }
         let mut can_be_rafsi = true;
         let mut require_cluster @ mut added_a = false;
         let part_a = &format!("{part}a");
-        if strin!(part, -1) == '\'' {
+        if letter_back(part, 0) == '\'' {
             if settings.y_hyphens == Standard
                 && !has_cluster
                 && i < y_parts.len() - 1
-                && strin!(y_parts[i + 1], 0) != '\''
+                && letter(y_parts[i + 1], 0) != '\''
             {
                 require_cluster = true;
             }
-            part = strsl!(part, 0..-1);
+            part = drop_back(part, 1);
             part_ = part;
             next_hyphen += "'";
-            if !is_vowel(strin!(part, -1)) {
+            if !is_vowel(letter_back(part, 0)) {
                 return Err(NotBrivlaError(format!(
                     "{{{part}}} contains an apostrophe not preceded by a vowel"
                 )));
             }
         } else if i < y_parts.len() - 1 || is_cmetai {
-            if is_vowel(strin!(part, -1)) {
+            if is_vowel(letter_back(part, 0)) {
                 can_be_rafsi = false;
             }
             part = part_a;
@@ -518,7 +667,7 @@ pub fn analyze_brivla(
         let mut katnad = false;
         if can_be_rafsi {
             if !part_a.ends_with("'a")
-                && !is_gismu(strsl!(part_a, -5..), &extract!(settings; allow_mz))
+                && !is_gismu(cut_back(part_a, 5), &extract!(settings; allow_mz))
                 && let Ok(decomp) = analyze_brivla(part_a, &extract!(settings; y_hyphens, allow_mz))
                 && decomp.0 == Lujvo
             {
@@ -526,6 +675,7 @@ pub fn analyze_brivla(
             }
             let mut found_parts = jvokaha2(part_, &extract!(settings; y_hyphens, allow_mz));
             if let Err(ref e) = found_parts {
+                trace!("{{{part_}}} isn't made of rafsi: {e}");
                 match e {
                     DecompositionError(_) | InvalidClusterError(_) | FakeTypeError(_) => {
                         found_parts = Ok(vec![part.to_string()]);
@@ -534,6 +684,7 @@ pub fn analyze_brivla(
                 }
             } else {
                 let found_parts = found_parts.clone().unwrap();
+                trace!("{{{part_}}} splits into rafsi: {{{}}}", found_parts.join(" "));
                 if found_parts.len() < 2
                     && !is_valid_rafsi(&found_parts[0], &extract!(settings; allow_mz))
                 {
@@ -560,26 +711,27 @@ pub fn analyze_brivla(
             if i == 0 {
                 let mut to_part = "";
                 let mut smabru_part = "";
-                if rafsi_tarmi(strsl!(part, 0..4)) == Cvhv {
-                    to_part = strsl!(part, 0..4);
-                    smabru_part = strsl!(part, 4..);
-                } else if rafsi_tarmi(strsl!(part, 0..3)) == Cvv {
-                    to_part = strsl!(part, 0..3);
-                    smabru_part = strsl!(part, 3..);
-                } else if is_consonant(strin!(part, 0)) && is_vowel(strin!(part, 1)) {
-                    to_part = strsl!(part, 0..2);
-                    smabru_part = strsl!(part, 2..);
+                if rafsi_tarmi(cut(part, 0..4)) == Cvhv {
+                    to_part = cut(part, 0..4);
+                    smabru_part = cut(part, 4..);
+                } else if rafsi_tarmi(cut(part, 0..3)) == Cvv {
+                    to_part = cut(part, 0..3);
+                    smabru_part = cut(part, 3..);
+                } else if is_consonant(letter(part, 0)) && is_vowel(letter(part, 1)) {
+                    to_part = cut(part, 0..2);
+                    smabru_part = cut(part, 2..);
                 }
                 if !smabru_part.is_empty() {
+                    trace!("checking for a tosmabru: {{{to_part}}} + {{{smabru_part}}}");
                     let hyphenless = strip_hyphens(smabru_part);
                     if added_a {
-                        smabru_part = strsl!(smabru_part, 0..-1);
+                        smabru_part = drop_back(smabru_part, 1);
                     } else {
                         smabru_part = &hyphenless;
                     }
                     if is_valid_rafsi(smabru_part, &Settings::default())
                         && !(rafsi_tarmi(smabru_part) == Ccv
-                            && strin!(strsl!(y_parts[i], to_part.len() as isize..), 3) == '\'')
+                            && letter(y_parts[i], to_part.len() + 3) == '\'')
                     {
                         return Err(NotBrivlaError(format!(
                             "{{{part}}} is a tosmabru: {{{to_part} {smabru_part}{}}}",
@@ -602,7 +754,8 @@ pub fn analyze_brivla(
                     && !to_part.is_empty()
                     && !y_parts[i + 1].starts_with('\'')
                 {
-                    let rest = strsl!(&valsi, to_part.len() as isize + 2..);
+                    let rest = cut(&valsi, to_part.len() + 2..);
+                    trace!("{{{to_part}'y}} may fall off, leaving {{{rest}}}");
                     if is_cmavo_compound(rest) {
                         return Err(NotBrivlaError(format!(
                             "{{{valsi}}} is just a cmavo compound"
@@ -620,6 +773,10 @@ pub fn analyze_brivla(
             }
         } else {
             let require_zihevla = require_cluster || !settings.exp_rafsi;
+            trace!(
+                "checking whether {{{part}}} is a {}",
+                if require_zihevla { "zi'evla" } else { "zi'evla or rafsi" }
+            );
             let shape_type = check_zihevla_or_rafsi(
                 part,
                 &extract!(settings; y_hyphens, exp_rafsi, allow_mz),
@@ -632,16 +789,21 @@ pub fn analyze_brivla(
                 }
             }
             let shape_type = shape_type.unwrap();
+            trace!("{{{part}}} is a {}", shape_type.to_string().to_lowercase());
             if shape_type == Zihevla {
                 has_cluster = true;
             }
-            if is_consonant(strin!(part, 0)) || settings.glides && is_glide(part) {
+            if is_consonant(letter(part, 0)) || settings.glides && is_glide(part) {
                 num_consonants += 1;
             }
             res_parts.push(part_.to_string());
         }
         consonant_before_break = false;
     }
+    trace!(
+        "found {num_consonants} consonants{}",
+        if has_cluster { " and a cluster" } else { " and no cluster" }
+    );
     if !has_cluster && !is_cmetai {
         if settings.consonants == Cluster {
             return Err(NotBrivlaError(format!("{{{valsi}}} lacks a consonant cluster")));
@@ -655,9 +817,9 @@ pub fn analyze_brivla(
             return Err(NotBrivlaError(format!("{{{valsi}}} is just a cmavo or cmavo compound")));
         }
     }
-    if !(is_vowel(strin!(&valsi, 0))
-        && (is_consonant(strin!(&valsi, 1)) || strin!(&valsi, 1) == 'y'))
-        && is_slinkuhi(&valsi, &extract!(settings; y_hyphens, allow_mz)).unwrap()
+    if !(is_vowel(letter(&valsi, 0))
+        && (is_consonant(letter(&valsi, 1)) || letter(&valsi, 1) == 'y'))
+        && is_slinkuhi(&valsi, &extract!(settings; y_hyphens, allow_mz))?
     {
         Err(NotBrivlaError(format!("{{{valsi}}} is a slinku'i")))
     } else {
@@ -670,7 +832,7 @@ pub fn get_rafsi_indices(rl: &[&str]) -> Vec<[usize; 2]> {
     let mut pos = 0;
     let mut indices = vec![];
     for r in rl {
-        if !HYPHENS.contains(r) {
+        if !HYPHENS_LIST.contains(r) {
             indices.push([pos, pos + r.len()]);
         }
         pos += r.len();
@@ -0,0 +1,114 @@
+//! An opt-in record of the decisions [`analyze_brivla`] makes, for explaining
+//! why a word is or isn't a brivla.
+//!
+//! Nothing is recorded unless the analysis is run inside [`with_trace`], so
+//! the calls that record steps cost almost nothing otherwise.
+//! ```
+//! use latkerlo_jvotci::{Settings, analyze_brivla, trace::with_trace};
+//!
+//! let (res, trace) = with_trace(|| analyze_brivla("toiysmu", &Settings::default()));
+//! assert!(res.is_err());
+//! println!("{trace}");
+//! ```
+//!
+//! [`analyze_brivla`]: crate::tools::analyze_brivla
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+thread_local! {
+    static STEPS: RefCell<Option<Vec<Step>>> = const { RefCell::new(None) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// One recorded decision.
+pub struct Step {
+    /// How deeply nested the call that made this step was.
+    pub depth: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+/// Everything recorded by [`with_trace`]. Displays as an indented tree.
+pub struct Trace(pub Vec<Step>);
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, step) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}{}", "  ".repeat(step.depth), step.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f`, recording every step taken on this thread. Traces can be
+/// nested; the inner one gets its own steps and the outer one doesn't see
+/// them.
+pub fn with_trace<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+    let outer = STEPS.with_borrow_mut(|steps| steps.replace(vec![]));
+    let guard = Restore { outer, depth: DEPTH.replace(0) };
+    let res = f();
+    let steps = STEPS.with_borrow_mut(Option::take);
+    drop(guard);
+    (res, Trace(steps.unwrap_or_default()))
+}
+
+/// Puts back the outer trace when [`with_trace`] finishes, even if `f`
+/// panics.
+struct Restore {
+    outer: Option<Vec<Step>>,
+    depth: usize,
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let outer = self.outer.take();
+        STEPS.with_borrow_mut(|steps| *steps = outer);
+        DEPTH.set(self.depth);
+    }
+}
+
+/// Returns `true` if steps are being recorded on this thread.
+#[inline]
+#[must_use]
+pub fn is_tracing() -> bool { STEPS.with_borrow(Option::is_some) }
+
+/// Records a step. Check [`is_tracing`] first to avoid formatting messages
+/// that won't be recorded.
+pub fn record(message: String) {
+    let depth = DEPTH.get();
+    STEPS.with_borrow_mut(|steps| {
+        if let Some(steps) = steps {
+            steps.push(Step { depth, message });
+        }
+    });
+}
+
+/// Nests the steps recorded while this is alive one level deeper.
+pub struct Scope(bool);
+
+/// Starts a [`Scope`].
+#[must_use]
+pub fn scope() -> Scope {
+    let tracing = is_tracing();
+    if tracing {
+        DEPTH.set(DEPTH.get() + 1);
+    }
+    Scope(tracing)
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        if self.0 {
+            DEPTH.set(DEPTH.get().saturating_sub(1));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Runs the `jvotci` binary.
+
+use std::{
+    io::Write as _,
+    process::{Command, Output, Stdio},
+};
+
+fn jvotci(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_jvotci")).args(args).output().unwrap()
+}
+
+fn jvotci_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_jvotci"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String { String::from_utf8_lossy(&output.stdout).into_owned() }
+
+#[test]
+fn t_command_words() {
+    // without input, a command that is also a word is analyzed
+    let katna = jvotci(&["katna"]);
+    assert!(stdout(&katna).starts_with("{katna} has rafsi {ka'a}\ngismu\n"), "{katna:?}");
+    assert!(!stdout(&katna).contains("needs input"));
+    assert!(stdout(&jvotci(&["zba", "-A"])).starts_with("{zba} is a rafsi of {zbasu}"));
+    // with input, it's a command
+    let zba = jvotci(&["zba", "bangu", "girzu"]);
+    assert_eq!(stdout(&zba), "baugri\n");
+    assert!(zba.status.success());
+    assert!(stdout(&jvotci(&["katna", "-A", "latkerlo"])).starts_with("lujvo\nlat kerlo\n"));
+    // and some never take any
+    assert!(stdout(&jvotci(&["settings"])).starts_with("flags: -\n"));
+}
+
+#[test]
+fn t_json_stdin() {
+    let ok = jvotci_stdin(&["--json"], "latkerlo\n\nbangu girzu\n");
+    assert_eq!(stdout(&ok).lines().count(), 2);
+    assert!(ok.status.success());
+    // any line with an error fails the whole run
+    let err = jvotci_stdin(&["--json"], "qqq\nlatkerlo\n");
+    assert_eq!(stdout(&err).lines().count(), 2);
+    assert_eq!(err.status.code(), Some(1));
+    assert_eq!(jvotci_stdin(&["--json"], "qqq\n/q\nlatkerlo\n").status.code(), Some(1));
+    // input on the command line never reads stdin
+    let one = jvotci_stdin(&["--json", "latkerlo"], "qqq\n");
+    assert_eq!(stdout(&one).lines().count(), 1);
+    assert!(one.status.success());
+}
+
+#[test]
+fn t_bad_options() {
+    let format = jvotci(&["-o", "bogus", "latkerlo"]);
+    assert_eq!(stdout(&format), "");
+    assert!(String::from_utf8_lossy(&format.stderr).contains("-o must be followed by"));
+    assert_eq!(format.status.code(), Some(1));
+    assert_eq!(jvotci(&["latkerlo", "-o"]).status.code(), Some(1));
+    let port = jvotci(&["serve", "--port"]);
+    assert!(String::from_utf8_lossy(&port.stderr).contains("--port must be followed by"));
+    assert_eq!(port.status.code(), Some(1));
+}